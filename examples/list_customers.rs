@@ -1,4 +1,4 @@
-use stripe::{Customer, ListCustomers, RangeBounds, RangeQuery};
+use stripe::{Customer, ListCustomers, RangeBounds, RangeQuery, Timestamp};
 
 fn main() {
     // Create a new client
@@ -10,11 +10,12 @@ fn main() {
         &client,
         ListCustomers {
             limit: Some(3),
-            created: Some(RangeQuery::gte(1501598702)),
+            created: Some(RangeQuery::gte(Timestamp::from(1501598702))),
             starting_after: None,
             ending_before: None,
             email: None,
             expand: &[],
+            test_clock: None,
         },
     )
     .unwrap();
@@ -35,9 +36,9 @@ fn main() {
     let mut params = ListCustomers::new();
     params.created = Some(RangeQuery::Bounds(RangeBounds {
         gt: None,
-        gte: customers.data[0].created.map(|x| x as i64),
+        gte: customers.data[0].created,
         lt: None,
-        lte: customers2.data.last().and_then(|cust| cust.created.map(|x| x as i64)),
+        lte: customers2.data.last().and_then(|cust| cust.created),
     }));
     let customers3 = Customer::list(&client, params).unwrap();
 
@@ -11,6 +11,8 @@ use std::fs;
 fn main() {
     let raw = fs::read_to_string("openapi/spec3.json").unwrap();
     let spec: Json = serde_json::from_str(&raw).unwrap();
+    fs::create_dir_all("openapi/out").unwrap();
+    fs::create_dir_all("openapi/cache").unwrap();
 
     let id_renames = mappings::id_renames();
     let object_mappings = mappings::object_mappings();
@@ -477,7 +479,9 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
                 // TODO: Handle these unusual params
                 "bank_account" | "card" | "destination" | "usage" => continue,
 
-                "product" => {
+                // N.B. Only some endpoints accept the parameters of a new product inline,
+                //      the others take a plain product id.
+                "product" if param["schema"]["anyOf"].is_array() => {
                     print_doc(&mut out);
                     initializers.push((
                         "product".into(),
@@ -922,8 +926,10 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
         out.push_str("pub enum ");
         out.push_str(&enum_name);
         out.push_str(" {\n");
+        // N.B. `serde(other)` must be on the last variant, so an `other` value is always moved last
+        let has_other = enum_.options.iter().any(|wire_name| wire_name == "other");
         for wire_name in &enum_.options {
-            if wire_name.trim().is_empty() {
+            if wire_name.trim().is_empty() || (has_other && wire_name == "other") {
                 continue;
             }
             let variant_name = match wire_name.as_str() {
@@ -948,6 +954,16 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
             out.push_str(&variant_name);
             out.push_str(",\n");
         }
+        if has_other {
+            out.push_str("    #[serde(other)]\n");
+            out.push_str("    Other,\n");
+        } else {
+            out.push('\n');
+            out.push_str("    /// A variant not yet supported by the library.\n");
+            out.push_str("    /// It is an error to send `Other` as part of a request.\n");
+            out.push_str("    #[serde(other, skip_serializing)]\n");
+            out.push_str("    Other,\n");
+        }
         out.push_str("}\n");
         out.push('\n');
         out.push_str("impl ");
@@ -977,6 +993,11 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
             out.push_str(&format!("{:?}", wire_name));
             out.push_str(",\n");
         }
+        if !has_other {
+            out.push_str("            ");
+            out.push_str(&enum_name);
+            out.push_str("::Other => \"other\",\n");
+        }
         out.push_str("        }\n");
         out.push_str("    }\n");
         out.push_str("}\n");
@@ -301,6 +301,11 @@ pub fn field_mappings() -> FieldMap {
             ("issuing_transaction", "type"),
             ("IssuingTransactionType", "IssuingTransactionType"),
         ),
+        (
+            ("country_spec", "supported_bank_account_currencies"),
+            ("Currency", "std::collections::HashMap<Currency, Vec<String>>"),
+        ),
+        (("exchange_rate", "rates"), ("Currency", "std::collections::HashMap<Currency, f64>")),
         (("file", "purpose"), ("", "FilePurpose")),
         (("order", "status"), ("", "OrderStatus")),
         (("person", "dob"), ("Dob", "Option<Dob>")),
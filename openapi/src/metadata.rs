@@ -16,6 +16,7 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 		("setup_intent", "core"),
 		("payout", "core"),
 		("platform_tax_fee", "core"),
+		("price", "core"),
 		("product", "core"),
 		("refund", "core"),
 		("reserve_transaction", "core"),
@@ -32,25 +33,39 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 		("checkout_session", "checkout"),
 
 		// Billing (aka. Subscriptions)
+		("billing.alert", "billing"),
+		("billing.meter", "billing"),
+		("billing.meter_event", "billing"),
+		("billing.meter_event_adjustment", "billing"),
+		("billing.meter_event_summary", "billing"),
 		("coupon", "billing"),
 		("discount", "billing"),
+		("entitlements.active_entitlement", "billing"),
+		("entitlements.feature", "billing"),
 		("invoice", "billing"),
 		("invoiceitem", "billing"),
+		("invoice_rendering_template", "billing"),
         ("line_item", "billing"),
 		("plan", "billing"),
+		("product_feature", "billing"),
 		("subscription", "billing"),
 		("subscription_item", "billing"),
 		("subscription_schedule", "billing"),
  		("subscription_schedule_revision", "billing"),
         ("tax_id", "billing"),
 		("tax_rate", "billing"),
+		("test_helpers.test_clock", "billing"),
 
 		// Connect
 		("account", "connect"),
+		("account_session", "connect"),
 		("application", "connect"),
 		("application_fee", "connect"),
+		("capability", "connect"),
 		("connect_collection_transfer", "connect"),
+		("country_spec", "connect"),
 		("fee_refund", "connect"),
+		("login_link", "connect"),
 		("person", "connect"),
 		("recipient", "connect"),
 		("topup", "connect"),
@@ -76,6 +91,10 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 		// Sigma
 		("scheduled_query_run", "sigma"),
 
+		// Tax
+		("tax.registration", "tax"),
+		("tax.settings", "tax"),
+
 		// Webhooks Endpoints
 		("webhook_endpoint", "webhook-endpoints"),
 	]
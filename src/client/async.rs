@@ -1,40 +1,39 @@
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use futures_util::future;
 use http::header::{HeaderMap, HeaderName, HeaderValue};
 use http::request::Builder as RequestBuilder;
-use hyper::client::connect::dns::GaiResolver;
-use hyper::client::HttpConnector;
 use serde::de::DeserializeOwned;
+use serde_derive::Serialize;
 
-use crate::error::{Error, ErrorResponse, RequestError};
-use crate::params::{AppInfo, Headers};
+use crate::client::middleware::{CaptureHeaders, Middleware};
+use crate::client::throttle::Throttle;
+use crate::client::transport::{DefaultHttpClient, HttpClient};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::client::transport::{HttpConfig, HyperClient};
+use crate::error::{Error, ErrorResponse, OAuthError, RequestError};
+use crate::params::{AppInfo, Headers, RequestOptions, RequestStrategy, ResponseEnvelope};
 use crate::resources::ApiVersion;
 
-#[cfg(feature = "rustls-tls")]
-use hyper_rustls::HttpsConnector;
-#[cfg(feature = "default-tls")]
-use hyper_tls::HttpsConnector;
-#[cfg(all(feature = "default-tls", feature = "rustls-tls"))]
-compile_error!("You must enable only one TLS implementation");
-#[cfg(not(any(feature = "default-tls", feature = "rustls-tls")))]
-compile_error!("You must enable at least one TLS implementation; add `features = [\"default-tls\"]` to your Cargo.toml");
-
-#[cfg(feature = "rustls-tls")]
-fn new_connector() -> hyper_rustls::HttpsConnector<HttpConnector<GaiResolver>> {
-    hyper_rustls::HttpsConnector::with_native_roots()
-}
+/// The host serving the Stripe Connect OAuth endpoints.
+const CONNECT_HOST: &str = "https://connect.stripe.com";
 
-#[cfg(feature = "default-tls")]
-fn new_connector() -> hyper_tls::HttpsConnector<HttpConnector<GaiResolver>> {
-    hyper_tls::HttpsConnector::new()
-}
+/// The host serving file uploads.
+const FILES_HOST: &str = "https://files.stripe.com";
 
-type HttpClient = hyper::Client<HttpsConnector<hyper::client::HttpConnector>, hyper::Body>;
+/// The api version sent with requests to the v2 api, which only accepts versions from 2024-09-30 on.
+const V2_API_VERSION: &str = "2024-09-30.acacia";
 
+#[cfg(not(target_arch = "wasm32"))]
 pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send>>;
 
+#[cfg(target_arch = "wasm32")]
+pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, Error>>>>;
+
 #[allow(dead_code)]
 #[inline(always)]
 pub(crate) fn ok<T: Send + 'static>(ok: T) -> Response<T> {
@@ -50,10 +49,42 @@ pub(crate) fn err<T: Send + 'static>(err: Error) -> Response<T> {
 #[derive(Clone)]
 pub struct Client {
     host: String,
-    client: HttpClient,
+    connect_host: String,
+    files_host: String,
+    client: Arc<dyn HttpClient>,
     secret_key: String,
     headers: Headers,
     app_info: Option<AppInfo>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    telemetry: Option<Telemetry>,
+    strategy: RequestStrategy,
+    rate_limited: Arc<AtomicU64>,
+    throttle: Option<Arc<Throttle>>,
+}
+
+/// The metrics of the last completed request, shared by clones of a `Client`.
+type Telemetry = Arc<Mutex<Option<RequestMetrics>>>;
+
+/// Metrics about a completed request, reported to Stripe in the `X-Stripe-Client-Telemetry`
+/// header of the following request.
+#[derive(Serialize)]
+struct RequestMetrics {
+    request_id: String,
+    request_duration_ms: u64,
+}
+
+#[derive(Serialize)]
+struct ClientTelemetry<'a> {
+    last_request_metrics: &'a RequestMetrics,
+}
+
+/// Describes this library in the `X-Stripe-Client-User-Agent` header.
+#[derive(Serialize)]
+struct ClientUserAgent<'a> {
+    bindings_version: &'static str,
+    lang: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    application: Option<&'a AppInfo>,
 }
 
 impl Client {
@@ -64,19 +95,44 @@ impl Client {
 
     /// Creates a new client posted to a custom `scheme://host/`
     pub fn from_url(scheme_host: impl Into<String>, secret_key: impl Into<String>) -> Client {
+        Client::from_http_client(scheme_host, secret_key, DefaultHttpClient::default())
+    }
+
+    /// Creates a new client pointed to `https://api.stripe.com/` with custom timeouts and pooling
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_config(secret_key: impl Into<String>, config: HttpConfig) -> Client {
+        Client::from_http_client(
+            "https://api.stripe.com/",
+            secret_key,
+            HyperClient::with_config(config),
+        )
+    }
+
+    /// Creates a new client posted to a custom `scheme://host/` which sends requests with `client`
+    pub fn from_http_client(
+        scheme_host: impl Into<String>,
+        secret_key: impl Into<String>,
+        client: impl HttpClient + 'static,
+    ) -> Client {
         let url = scheme_host.into();
         let host = if url.ends_with('/') { format!("{}v1", url) } else { format!("{}/v1", url) };
-        let https = new_connector();
-        let client = hyper::Client::builder().pool_max_idle_per_host(0).build(https);
         let mut headers = Headers::default();
         // TODO: Automatically determine the latest supported api version in codegen?
         headers.stripe_version = Some(ApiVersion::V2019_09_09);
         Client {
             host,
-            client,
+            connect_host: CONNECT_HOST.to_string(),
+            files_host: format!("{}/v1", FILES_HOST),
+            client: Arc::new(client),
             secret_key: secret_key.into(),
             headers,
             app_info: Some(AppInfo::default()),
+            middleware: Vec::new(),
+            // N.B. `Instant` is unavailable on wasm32, so telemetry is never collected there.
+            telemetry: if cfg!(target_arch = "wasm32") { None } else { Some(Telemetry::default()) },
+            strategy: RequestStrategy::Once,
+            rate_limited: Arc::new(AtomicU64::new(0)),
+            throttle: None,
         }
     }
 
@@ -90,10 +146,123 @@ impl Client {
         client
     }
 
+    /// Clones a new client with per-request options layered over the current headers.
+    ///
+    /// Cloned clients share the same connection pool, so this is cheap enough to call for
+    /// every request when a single client serves many connected accounts concurrently, e.g.
+    /// `Customer::create(&client.with_options(options), params)`.
+    pub fn with_options(&self, mut options: RequestOptions) -> Client {
+        let mut client = self.clone();
+        if let Some(strategy) = options.strategy.take() {
+            client.strategy = strategy;
+        }
+        client.headers = options.apply(client.headers);
+        client
+    }
+
+    /// Clones a new client which pins requests to the given api version.
+    ///
+    /// The version is sent in the `Stripe-Version` header, overriding the account's default
+    /// version so responses match the structs defined by this library.
+    pub fn with_api_version(&self, version: ApiVersion) -> Client {
+        let mut client = self.clone();
+        client.headers.stripe_version = Some(version);
+        client
+    }
+
+    /// Clones a new client which retries failed requests according to `strategy`.
+    ///
+    /// By default requests are sent once, e.g. a batch job might instead use
+    /// `client.with_strategy(RequestStrategy::Idempotent { max_retries: 3 })`.
+    pub fn with_strategy(&self, strategy: RequestStrategy) -> Client {
+        let mut client = self.clone();
+        client.strategy = strategy;
+        client
+    }
+
+    /// Makes a request, returning the response along with its headers (e.g. the request id).
+    ///
+    /// If `request` sends more than one request, the headers are those of the last response.
+    ///
+    /// ```rust,ignore
+    /// let customer = client.with_envelope(|client| Customer::retrieve(client, &id, &[])).await?;
+    /// println!("{:?}", customer.request_id());
+    /// ```
+    // N.B. With `blocking`, this client is private and `blocking::Client` has its own version.
+    #[cfg_attr(feature = "blocking", allow(dead_code))]
+    pub fn with_envelope<T, F>(&self, request: F) -> Response<ResponseEnvelope<T>>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Client) -> Response<T>,
+    {
+        let capture = CaptureHeaders::default();
+        let mut client = self.clone();
+        client.add_middleware(capture.clone());
+        let response = request(&client);
+        Box::pin(async move { Ok(capture.envelope(response.await?)) })
+    }
+
+    /// Identifies a plugin or platform built on this library to Stripe.
+    ///
+    /// The app info is appended to the `User-Agent` and `X-Stripe-Client-User-Agent` headers.
     pub fn set_app_info(&mut self, name: String, version: Option<String>, url: Option<String>) {
         self.app_info = Some(AppInfo { name, url, version });
     }
 
+    /// Points the Stripe Connect OAuth endpoints at a custom `scheme://host/`
+    ///
+    /// By default these are served from `https://connect.stripe.com/` even when the client
+    /// was created with `Client::from_url`, so tests using a local proxy must set both.
+    pub fn set_connect_url(&mut self, scheme_host: impl Into<String>) {
+        self.connect_host = scheme_host.into().trim_end_matches('/').to_string();
+    }
+
+    /// Points file uploads at a custom `scheme://host/`
+    ///
+    /// By default files are uploaded to `https://files.stripe.com/` even when the client
+    /// was created with `Client::from_url`, so tests using a local proxy must set both.
+    pub fn set_files_url(&mut self, scheme_host: impl Into<String>) {
+        self.files_host = format!("{}/v1", scheme_host.into().trim_end_matches('/'));
+    }
+
+    /// Adds a middleware which is run around every request sent by this client.
+    pub fn add_middleware(&mut self, middleware: impl Middleware + 'static) {
+        self.middleware.push(Arc::new(middleware));
+    }
+
+    /// Enables or disables reporting request latency to Stripe, which is enabled by default.
+    ///
+    /// When enabled, each request includes the duration and request id of the previous request
+    /// in the `X-Stripe-Client-Telemetry` header, matching the official Stripe libraries.
+    pub fn set_telemetry(&mut self, enabled: bool) {
+        self.telemetry = if enabled && !cfg!(target_arch = "wasm32") {
+            Some(self.telemetry.take().unwrap_or_default())
+        } else {
+            None
+        };
+    }
+
+    /// Limits the number of requests sent concurrently by this client and its clones.
+    ///
+    /// The limit starts at `max_concurrency` and is halved whenever Stripe responds with
+    /// `429 Too Many Requests`, then raised by one again after as many successful requests
+    /// as the current limit, keeping bulk jobs below Stripe's rate limits.
+    /// `None` disables throttling, which is the default.
+    pub fn set_throttle(&mut self, max_concurrency: Option<usize>) {
+        self.throttle = max_concurrency.map(|max| Arc::new(Throttle::new(max)));
+    }
+
+    /// The current number of requests which may be sent concurrently, if throttling is enabled.
+    pub fn concurrency_limit(&self) -> Option<usize> {
+        self.throttle.as_ref().map(|throttle| throttle.limit())
+    }
+
+    /// The number of requests rate limited by Stripe (`429 Too Many Requests`),
+    /// counted across this client and its clones.
+    pub fn rate_limited_count(&self) -> u64 {
+        self.rate_limited.load(Ordering::Relaxed)
+    }
+
     /// Sets a value for the Stripe-Account header
     ///
     /// This is recommended if you are acting as only one Account for the lifetime of the client.
@@ -105,10 +274,21 @@ impl Client {
     /// Make a `GET` http request with just a path
     pub fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
         let url = self.url(path);
-        let mut req =
-            RequestBuilder::new().method("GET").uri(url).body(hyper::Body::empty()).unwrap();
+        let mut req = RequestBuilder::new().method("GET").uri(url).body(Vec::new()).unwrap();
+        *req.headers_mut() = self.headers();
+        self.send(req)
+    }
+
+    /// Make a `GET` http request to an absolute url, returning the raw response body.
+    ///
+    /// Redirects are followed. The client's credentials are only sent to urls on its own host.
+    pub fn get_bytes(&self, url: &str) -> Response<Vec<u8>> {
+        let mut req = RequestBuilder::new().method("GET").uri(url).body(Vec::new()).unwrap();
         *req.headers_mut() = self.headers();
-        send(&self.client, req)
+        if !url.starts_with(&format!("{}/", self.host)) {
+            strip_credentials(req.headers_mut());
+        }
+        self.send_with(req, Ok)
     }
 
     /// Make a `GET` http request with url query parameters
@@ -121,19 +301,17 @@ impl Client {
             Err(err) => return Box::pin(future::ready(Err(err))),
             Ok(ok) => ok,
         };
-        let mut req =
-            RequestBuilder::new().method("GET").uri(url).body(hyper::Body::empty()).unwrap();
+        let mut req = RequestBuilder::new().method("GET").uri(url).body(Vec::new()).unwrap();
         *req.headers_mut() = self.headers();
-        send(&self.client, req)
+        self.send(req)
     }
 
     /// Make a `DELETE` http request with just a path
     pub fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
         let url = self.url(path);
-        let mut req =
-            RequestBuilder::new().method("DELETE").uri(url).body(hyper::Body::empty()).unwrap();
+        let mut req = RequestBuilder::new().method("DELETE").uri(url).body(Vec::new()).unwrap();
         *req.headers_mut() = self.headers();
-        send(&self.client, req)
+        self.send(req)
     }
 
     /// Make a `DELETE` http request with url query parameters
@@ -146,19 +324,17 @@ impl Client {
             Err(err) => return Box::pin(future::ready(Err(err))),
             Ok(ok) => ok,
         };
-        let mut req =
-            RequestBuilder::new().method("DELETE").uri(url).body(hyper::Body::empty()).unwrap();
+        let mut req = RequestBuilder::new().method("DELETE").uri(url).body(Vec::new()).unwrap();
         *req.headers_mut() = self.headers();
-        send(&self.client, req)
+        self.send(req)
     }
 
     /// Make a `POST` http request with just a path
     pub fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
         let url = self.url(path);
-        let mut req =
-            RequestBuilder::new().method("POST").uri(url).body(hyper::Body::empty()).unwrap();
+        let mut req = RequestBuilder::new().method("POST").uri(url).body(Vec::new()).unwrap();
         *req.headers_mut() = self.headers();
-        send(&self.client, req)
+        self.send(req)
     }
 
     /// Make a `POST` http request with urlencoded body
@@ -167,13 +343,113 @@ impl Client {
         path: &str,
         form: F,
     ) -> Response<T> {
-        let url = self.url(path);
+        self.post_form_url(self.url(path), form)
+    }
+
+    /// Make a `POST` http request with urlencoded body to the Stripe Connect host
+    pub fn post_form_connect<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Response<T> {
+        let url = format!("{}/{}", self.connect_host, path.trim_start_matches('/'));
+        self.post_form_url(url, form)
+    }
+
+    /// Make a `POST` http request with a multipart body to the Stripe files host
+    ///
+    /// The fields of `form` are sent as text parts, followed by `contents` as the `file` part.
+    pub fn post_file<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
+        &self,
+        path: &str,
+        form: F,
+        filename: &str,
+        contents: &[u8],
+    ) -> Response<T> {
+        let url = format!("{}/{}", self.files_host, path.trim_start_matches('/'));
+        let form = match serde_qs::to_string(&form) {
+            Err(err) => return Box::pin(future::ready(Err(Error::serialize(err)))),
+            Ok(form) => form,
+        };
+        let boundary = generate_idempotency_key();
+        let mut req = RequestBuilder::new()
+            .method("POST")
+            .uri(url)
+            .body(multipart_body(&boundary, &form, filename, contents))
+            .unwrap();
+        *req.headers_mut() = self.headers();
+        req.headers_mut().insert(
+            HeaderName::from_static("content-type"),
+            HeaderValue::from_str(&format!("multipart/form-data; boundary={}", boundary)).unwrap(),
+        );
+        self.send(req)
+    }
+
+    /// Make a `GET` http request to the v2 api with just a path
+    pub fn get_v2<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        let url = self.url_v2(path);
+        let mut req = RequestBuilder::new().method("GET").uri(url).body(Vec::new()).unwrap();
+        *req.headers_mut() = self.headers_v2();
+        self.send(req)
+    }
+
+    /// Make a `GET` http request to the v2 api with url query parameters
+    pub fn get_query_v2<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Response<T> {
+        let url = match serde_qs::to_string(&params) {
+            Err(err) => return Box::pin(future::ready(Err(Error::serialize(err)))),
+            Ok(params) if params.is_empty() => self.url_v2(path),
+            Ok(params) => format!("{}?{}", self.url_v2(path), params),
+        };
+        let mut req = RequestBuilder::new().method("GET").uri(url).body(Vec::new()).unwrap();
+        *req.headers_mut() = self.headers_v2();
+        self.send(req)
+    }
+
+    /// Make a `DELETE` http request to the v2 api with just a path
+    pub fn delete_v2<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        let url = self.url_v2(path);
+        let mut req = RequestBuilder::new().method("DELETE").uri(url).body(Vec::new()).unwrap();
+        *req.headers_mut() = self.headers_v2();
+        self.send(req)
+    }
+
+    /// Make a `POST` http request to the v2 api with a json body
+    pub fn post_json_v2<T: DeserializeOwned + Send + 'static, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: B,
+    ) -> Response<T> {
+        let mut req = RequestBuilder::new()
+            .method("POST")
+            .uri(self.url_v2(path))
+            .body(match serde_json::to_vec(&body) {
+                Err(err) => return Box::pin(future::ready(Err(Error::serialize(err)))),
+                Ok(body) => body,
+            })
+            .unwrap();
+        *req.headers_mut() = self.headers_v2();
+        req.headers_mut().insert(
+            HeaderName::from_static("content-type"),
+            HeaderValue::from_str("application/json").unwrap(),
+        );
+        self.send(req)
+    }
+
+    fn post_form_url<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
+        &self,
+        url: String,
+        form: F,
+    ) -> Response<T> {
         let mut req = RequestBuilder::new()
             .method("POST")
             .uri(url)
             .body(match serde_qs::to_string(&form) {
                 Err(err) => return Box::pin(future::ready(Err(Error::serialize(err)))),
-                Ok(body) => hyper::Body::from(body),
+                Ok(body) => body.into_bytes(),
             })
             .unwrap();
         *req.headers_mut() = self.headers();
@@ -181,13 +457,60 @@ impl Client {
             HeaderName::from_static("content-type"),
             HeaderValue::from_str("application/x-www-form-urlencoded").unwrap(),
         );
-        send(&self.client, req)
+        self.send(req)
+    }
+
+    fn send<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Response<T> {
+        self.send_with(request, |bytes| serde_json::from_slice(&bytes).map_err(Error::deserialize))
+    }
+
+    fn send_with<T: Send + 'static>(
+        &self,
+        mut request: http::Request<Vec<u8>>,
+        decode: fn(Vec<u8>) -> Result<T, Error>,
+    ) -> Response<T> {
+        if let Some(telemetry) = &self.telemetry {
+            if let Some(metrics) = telemetry.lock().unwrap().take() {
+                let header =
+                    serde_json::to_string(&ClientTelemetry { last_request_metrics: &metrics });
+                if let Some(value) = header.ok().and_then(|h| HeaderValue::from_str(&h).ok()) {
+                    request
+                        .headers_mut()
+                        .insert(HeaderName::from_static("x-stripe-client-telemetry"), value);
+                }
+            }
+        }
+        if self.strategy.max_retries() > 0
+            && request.method() == http::Method::POST
+            && !request.headers().contains_key("idempotency-key")
+        {
+            request.headers_mut().insert(
+                HeaderName::from_static("idempotency-key"),
+                HeaderValue::from_str(&generate_idempotency_key()).unwrap(),
+            );
+        }
+        for middleware in &self.middleware {
+            middleware.on_request(&mut request);
+        }
+        let telemetry = self.telemetry.clone().map(|telemetry| (telemetry, Instant::now()));
+        let limits =
+            RateLimits { rate_limited: self.rate_limited.clone(), throttle: self.throttle.clone() };
+        let strategy = self.strategy.clone();
+        let client = self.client.clone();
+        send(client, self.middleware.clone(), telemetry, limits, strategy, request, decode)
     }
 
     fn url(&self, path: &str) -> String {
         format!("{}/{}", self.host, path.trim_start_matches('/'))
     }
 
+    fn url_v2(&self, path: &str) -> String {
+        format!("{}/v2/{}", self.host.trim_end_matches("/v1"), path.trim_start_matches('/'))
+    }
+
     fn url_with_params<P: serde::Serialize>(&self, path: &str, params: P) -> Result<String, Error> {
         let params = serde_qs::to_string(&params).map_err(Error::serialize)?;
         Ok(format!("{}/{}?{}", self.host, &path[1..], params))
@@ -211,6 +534,12 @@ impl Client {
                 HeaderValue::from_str(client_id).unwrap(),
             );
         }
+        if let Some(idempotency_key) = &self.headers.idempotency_key {
+            headers.insert(
+                HeaderName::from_static("idempotency-key"),
+                HeaderValue::from_str(idempotency_key).unwrap(),
+            );
+        }
         if let Some(stripe_version) = &self.headers.stripe_version {
             headers.insert(
                 HeaderName::from_static("stripe-version"),
@@ -233,20 +562,119 @@ impl Client {
                 HeaderValue::from_str(user_agent.as_str()).unwrap(),
             );
         };
+        let client_user_agent = ClientUserAgent {
+            bindings_version: CRATE_VERSION,
+            lang: "rust",
+            application: self.app_info.as_ref().filter(|app_info| !app_info.name.is_empty()),
+        };
+        let header = serde_json::to_string(&client_user_agent);
+        if let Some(value) = header.ok().and_then(|h| HeaderValue::from_str(&h).ok()) {
+            headers.insert(HeaderName::from_static("x-stripe-client-user-agent"), value);
+        }
+        headers
+    }
+
+    /// The headers for a request to the v2 api, which overrides the client's api version.
+    fn headers_v2(&self) -> HeaderMap {
+        let mut headers = self.headers();
+        headers.insert(
+            HeaderName::from_static("stripe-version"),
+            HeaderValue::from_static(V2_API_VERSION),
+        );
         headers
     }
 }
 
-fn send<T: DeserializeOwned + Send + 'static>(
-    client: &HttpClient,
-    request: hyper::Request<hyper::Body>,
+/// The number of redirects followed before a response is treated as an error.
+const MAX_REDIRECTS: u32 = 5;
+
+fn send<T: Send + 'static>(
+    client: Arc<dyn HttpClient>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    telemetry: Option<(Telemetry, Instant)>,
+    limits: RateLimits,
+    strategy: RequestStrategy,
+    mut request: http::Request<Vec<u8>>,
+    decode: fn(Vec<u8>) -> Result<T, Error>,
 ) -> Response<T> {
-    let client = client.clone(); // N.B. Client is send sync;  cloned clients share the same pool.
-    Box::pin(async move {
-        let response = client.request(request).await?;
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!(
+        "stripe_request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        status = tracing::field::Empty,
+        request_id = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    );
+    #[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+    let started = std::time::Instant::now();
+    let response = async move {
+        let mut retries = 0;
+        let mut redirects = 0;
+        let response = loop {
+            let retry = strategy.retry_delay(retries).map(|delay| (delay, clone_request(&request)));
+            let redirect = (request.method() == http::Method::GET && redirects < MAX_REDIRECTS)
+                .then(|| clone_request(&request));
+            let permit = match &limits.throttle {
+                Some(throttle) => Some(throttle.acquire().await),
+                None => None,
+            };
+            let response = client.execute(request).await;
+            let rate_limited = matches!(&response, Ok(response) if response.status() == 429);
+            if rate_limited {
+                limits.rate_limited.fetch_add(1, Ordering::Relaxed);
+            }
+            if let (Some(throttle), Some(permit)) = (&limits.throttle, permit) {
+                throttle.release(permit, rate_limited);
+            }
+            if let (Some(next), Ok(response)) = (redirect, &response) {
+                if let Some(next) = follow_redirect(response, next) {
+                    request = next;
+                    redirects += 1;
+                    continue;
+                }
+            }
+            match retry {
+                Some((delay, next)) if should_retry(&response) => {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    tokio::time::sleep(delay).await;
+                    #[cfg(target_arch = "wasm32")]
+                    let _ = delay;
+                    request = next;
+                    retries += 1;
+                }
+                _ => break response?,
+            }
+        };
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("status", response.status().as_u16());
+            if let Some(request_id) = response.headers().get("request-id") {
+                span.record("request_id", request_id.to_str().unwrap_or_default());
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            span.record("latency_ms", started.elapsed().as_millis() as u64);
+        }
+        if let Some((telemetry, started)) = telemetry {
+            let request_id = response.headers().get("request-id").and_then(|id| id.to_str().ok());
+            if let Some(request_id) = request_id {
+                let request_duration_ms = started.elapsed().as_millis() as u64;
+                let request_id = request_id.to_string();
+                *telemetry.lock().unwrap() =
+                    Some(RequestMetrics { request_id, request_duration_ms });
+            }
+        }
+        for middleware in &middleware {
+            middleware.on_response(&response);
+        }
         let status = response.status();
-        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+        let bytes = response.into_body();
         if !status.is_success() {
+            if let Ok(mut err) = serde_json::from_slice::<OAuthError>(&bytes) {
+                err.http_status = status.as_u16();
+                Err(Error::from(err))?;
+            }
             let mut err = serde_json::from_slice(&bytes).unwrap_or_else(|err| {
                 let mut req = ErrorResponse { error: RequestError::default() };
                 req.error.message = Some(format!("failed to deserialize error: {}", err));
@@ -255,8 +683,136 @@ fn send<T: DeserializeOwned + Send + 'static>(
             err.error.http_status = status.as_u16();
             Err(Error::from(err.error))?;
         }
-        serde_json::from_slice(&bytes).map_err(Error::deserialize)
-    })
+        decode(bytes)
+    };
+    #[cfg(feature = "tracing")]
+    let response = tracing::Instrument::instrument(response, span);
+    Box::pin(response)
+}
+
+/// The rate limiting state shared by clones of a `Client`.
+struct RateLimits {
+    rate_limited: Arc<AtomicU64>,
+    throttle: Option<Arc<Throttle>>,
+}
+
+/// Whether a request which failed with `response` may succeed if it is sent again.
+fn should_retry(response: &Result<http::Response<Vec<u8>>, Error>) -> bool {
+    let response = match response {
+        Ok(response) => response,
+        Err(err) => return matches!(err, Error::Http(_)),
+    };
+    match response.headers().get("stripe-should-retry").and_then(|value| value.to_str().ok()) {
+        Some("true") => true,
+        Some("false") => false,
+        // N.B. 409 Conflict is returned when a request is blocked by a concurrent one (a lock timeout).
+        _ => matches!(response.status().as_u16(), 409 | 429 | 500..=599),
+    }
+}
+
+/// Returns the request to send next if `response` redirects `request` elsewhere.
+///
+/// Redirects from `https` to plain `http` aren't followed.
+fn follow_redirect(
+    response: &http::Response<Vec<u8>>,
+    mut request: http::Request<Vec<u8>>,
+) -> Option<http::Request<Vec<u8>>> {
+    if !response.status().is_redirection() {
+        return None;
+    }
+    let location = response.headers().get("location")?.to_str().ok()?;
+    let mut uri: http::Uri = location.parse().ok()?;
+    if uri.authority().is_none() {
+        let mut parts = uri.into_parts();
+        parts.scheme = request.uri().scheme().cloned();
+        parts.authority = request.uri().authority().cloned();
+        uri = http::Uri::from_parts(parts).ok()?;
+    } else if uri.scheme() != request.uri().scheme() || uri.authority() != request.uri().authority()
+    {
+        let https = Some(&http::uri::Scheme::HTTPS);
+        if request.uri().scheme() == https && uri.scheme() != https {
+            return None;
+        }
+        strip_credentials(request.headers_mut());
+    }
+    *request.uri_mut() = uri;
+    Some(request)
+}
+
+/// Removes the headers which identify the Stripe account from a request to another origin.
+fn strip_credentials(headers: &mut HeaderMap) {
+    headers.remove("authorization");
+    headers.remove("stripe-account");
+}
+
+fn clone_request(request: &http::Request<Vec<u8>>) -> http::Request<Vec<u8>> {
+    let mut clone = http::Request::new(request.body().clone());
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    clone
+}
+
+/// Generates a random key identifying a request and its retries.
+fn generate_idempotency_key() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    // N.B. Every `RandomState` is seeded with different random keys, which avoids depending
+    //      on a random number generator for a value that only needs to be unique.
+    let random = || RandomState::new().build_hasher().finish();
+    format!("stripe-rs-{:016x}{:016x}", random(), random())
+}
+
+/// Encodes a urlencoded `form` and a file as `multipart/form-data`.
+fn multipart_body(boundary: &str, form: &str, filename: &str, contents: &[u8]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (name, value) in form.split('&').filter_map(|pair| pair.split_once('=')) {
+        body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+        body.extend_from_slice(
+            format!(
+                "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                String::from_utf8_lossy(&urldecode(name))
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(&urldecode(value));
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+    body.extend_from_slice(
+        format!(
+            "Content-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\n",
+            filename.replace('"', "%22")
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(b"Content-Type: application/octet-stream\r\n\r\n");
+    body.extend_from_slice(contents);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    body
+}
+
+/// Reverses the percent-encoding applied by `serde_qs`.
+fn urldecode(encoded: &str) -> Vec<u8> {
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok());
+        match (bytes[i], hex.and_then(|hex| u8::from_str_radix(hex, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+            (b'+', _) => decoded.push(b' '),
+            (byte, _) => decoded.push(byte),
+        }
+        i += 1;
+    }
+    decoded
 }
 
 /// Formats a plugin's 'App Info' into a string that can be added to the end of an User-Agent string.
@@ -1,6 +1,9 @@
+use crate::client::middleware::{CaptureHeaders, Middleware};
 use crate::client::r#async::Client as AsyncClient;
+use crate::client::transport::{HttpClient, HttpConfig};
 use crate::error::Error;
-use crate::params::Headers;
+use crate::params::{Headers, RequestOptions, RequestStrategy, ResponseEnvelope};
+use crate::resources::ApiVersion;
 use serde::de::DeserializeOwned;
 use std::sync::Arc;
 use std::time::Duration;
@@ -24,6 +27,7 @@ pub(crate) fn err<T>(err: crate::Error) -> Response<T> {
 pub struct Client {
     inner: AsyncClient,
     runtime: Arc<tokio::runtime::Runtime>,
+    timeout: Option<Duration>,
 }
 
 impl Client {
@@ -37,13 +41,28 @@ impl Client {
         Client::from_async(AsyncClient::from_url(scheme_host, secret_key))
     }
 
+    /// Creates a new client pointed to `https://api.stripe.com/` with custom timeouts and pooling
+    pub fn from_config(secret_key: impl Into<String>, config: HttpConfig) -> Client {
+        let timeout = config.request_timeout;
+        Client { timeout, ..Client::from_async(AsyncClient::from_config(secret_key, config)) }
+    }
+
+    /// Creates a new client posted to a custom `scheme://host/` which sends requests with `client`
+    pub fn from_http_client(
+        scheme_host: impl Into<String>,
+        secret_key: impl Into<String>,
+        client: impl HttpClient + 'static,
+    ) -> Client {
+        Client::from_async(AsyncClient::from_http_client(scheme_host, secret_key, client))
+    }
+
     fn from_async(inner: AsyncClient) -> Client {
         let runtime = tokio::runtime::Builder::new_current_thread()
             .enable_io()
             .enable_time() // use separate `io/time` instead of `all` to ensure `tokio/time` is enabled
             .build()
             .unwrap();
-        Client { inner, runtime: Arc::new(runtime) }
+        Client { inner, runtime: Arc::new(runtime), timeout: Some(DEFAULT_TIMEOUT) }
     }
 
     /// Clones a new client with different headers.
@@ -51,13 +70,123 @@ impl Client {
     /// This is the recommended way to send requests for many different Stripe accounts
     /// or with different Meta, Extra, and Expand headers while using the same secret key.
     pub fn with_headers(&self, headers: Headers) -> Client {
-        Client { inner: self.inner.with_headers(headers), runtime: self.runtime.clone() }
+        Client {
+            inner: self.inner.with_headers(headers),
+            runtime: self.runtime.clone(),
+            timeout: self.timeout,
+        }
+    }
+
+    /// Clones a new client with per-request options layered over the current headers.
+    ///
+    /// Cloned clients share the same connection pool and runtime, so this is cheap enough to
+    /// call for every request, e.g. `Customer::create(&client.with_options(options), params)`.
+    pub fn with_options(&self, options: RequestOptions) -> Client {
+        Client {
+            inner: self.inner.with_options(options),
+            runtime: self.runtime.clone(),
+            timeout: self.timeout,
+        }
+    }
+
+    /// Clones a new client which pins requests to the given api version.
+    ///
+    /// The version is sent in the `Stripe-Version` header, overriding the account's default
+    /// version so responses match the structs defined by this library.
+    pub fn with_api_version(&self, version: ApiVersion) -> Client {
+        Client {
+            inner: self.inner.with_api_version(version),
+            runtime: self.runtime.clone(),
+            timeout: self.timeout,
+        }
+    }
+
+    /// Clones a new client which retries failed requests according to `strategy`.
+    ///
+    /// N.B. The client's timeout applies to the request as a whole, including any retries.
+    pub fn with_strategy(&self, strategy: RequestStrategy) -> Client {
+        Client {
+            inner: self.inner.with_strategy(strategy),
+            runtime: self.runtime.clone(),
+            timeout: self.timeout,
+        }
+    }
+
+    /// Makes a request, returning the response along with its headers (e.g. the request id).
+    ///
+    /// If `request` sends more than one request, the headers are those of the last response.
+    ///
+    /// ```rust,ignore
+    /// let customer = client.with_envelope(|client| Customer::retrieve(client, &id, &[]))?;
+    /// println!("{:?}", customer.request_id());
+    /// ```
+    pub fn with_envelope<T, F>(&self, request: F) -> Response<ResponseEnvelope<T>>
+    where
+        F: FnOnce(&Client) -> Response<T>,
+    {
+        let capture = CaptureHeaders::default();
+        let mut client = self.clone();
+        client.add_middleware(capture.clone());
+        Ok(capture.envelope(request(&client)?))
     }
 
+    /// Identifies a plugin or platform built on this library to Stripe.
+    ///
+    /// The app info is appended to the `User-Agent` and `X-Stripe-Client-User-Agent` headers.
     pub fn set_app_info(&mut self, name: String, version: Option<String>, url: Option<String>) {
         self.inner.set_app_info(name, version, url);
     }
 
+    /// Points the Stripe Connect OAuth endpoints at a custom `scheme://host/`
+    ///
+    /// By default these are served from `https://connect.stripe.com/` even when the client
+    /// was created with `Client::from_url`, so tests using a local proxy must set both.
+    pub fn set_connect_url(&mut self, scheme_host: impl Into<String>) {
+        self.inner.set_connect_url(scheme_host)
+    }
+
+    /// Points file uploads at a custom `scheme://host/`
+    ///
+    /// By default files are uploaded to `https://files.stripe.com/` even when the client
+    /// was created with `Client::from_url`, so tests using a local proxy must set both.
+    pub fn set_files_url(&mut self, scheme_host: impl Into<String>) {
+        self.inner.set_files_url(scheme_host)
+    }
+
+    /// Adds a middleware which is run around every request sent by this client.
+    pub fn add_middleware(&mut self, middleware: impl Middleware + 'static) {
+        self.inner.add_middleware(middleware)
+    }
+
+    /// Enables or disables reporting request latency to Stripe, which is enabled by default.
+    ///
+    /// When enabled, each request includes the duration and request id of the previous request
+    /// in the `X-Stripe-Client-Telemetry` header, matching the official Stripe libraries.
+    pub fn set_telemetry(&mut self, enabled: bool) {
+        self.inner.set_telemetry(enabled)
+    }
+
+    /// Limits the number of requests sent concurrently by this client and its clones.
+    ///
+    /// The limit starts at `max_concurrency` and is halved whenever Stripe responds with
+    /// `429 Too Many Requests`, then raised by one again after as many successful requests
+    /// as the current limit, keeping bulk jobs below Stripe's rate limits.
+    /// `None` disables throttling, which is the default.
+    pub fn set_throttle(&mut self, max_concurrency: Option<usize>) {
+        self.inner.set_throttle(max_concurrency)
+    }
+
+    /// The current number of requests which may be sent concurrently, if throttling is enabled.
+    pub fn concurrency_limit(&self) -> Option<usize> {
+        self.inner.concurrency_limit()
+    }
+
+    /// The number of requests rate limited by Stripe (`429 Too Many Requests`),
+    /// counted across this client and its clones.
+    pub fn rate_limited_count(&self) -> u64 {
+        self.inner.rate_limited_count()
+    }
+
     /// Sets a value for the Stripe-Account header
     ///
     /// This is recommended if you are acting as only one Account for the lifetime of the client.
@@ -71,6 +200,13 @@ impl Client {
         self.send_blocking(self.inner.get(path))
     }
 
+    /// Make a `GET` http request to an absolute url, returning the raw response body.
+    ///
+    /// Redirects are followed. The client's credentials are only sent to urls on its own host.
+    pub fn get_bytes(&self, url: &str) -> Response<Vec<u8>> {
+        self.send_blocking(self.inner.get_bytes(url))
+    }
+
     /// Make a `GET` http request with url query parameters
     pub fn get_query<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
         &self,
@@ -108,14 +244,68 @@ impl Client {
         self.send_blocking(self.inner.post_form(path, form))
     }
 
+    /// Make a `POST` http request with urlencoded body to the Stripe Connect host
+    pub fn post_form_connect<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Response<T> {
+        self.send_blocking(self.inner.post_form_connect(path, form))
+    }
+
+    /// Make a `POST` http request with a multipart body to the Stripe files host
+    ///
+    /// The fields of `form` are sent as text parts, followed by `contents` as the `file` part.
+    pub fn post_file<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
+        &self,
+        path: &str,
+        form: F,
+        filename: &str,
+        contents: &[u8],
+    ) -> Response<T> {
+        self.send_blocking(self.inner.post_file(path, form, filename, contents))
+    }
+
+    /// Make a `GET` http request to the v2 api with just a path
+    pub fn get_v2<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        self.send_blocking(self.inner.get_v2(path))
+    }
+
+    /// Make a `GET` http request to the v2 api with url query parameters
+    pub fn get_query_v2<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Response<T> {
+        self.send_blocking(self.inner.get_query_v2(path, params))
+    }
+
+    /// Make a `DELETE` http request to the v2 api with just a path
+    pub fn delete_v2<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        self.send_blocking(self.inner.delete_v2(path))
+    }
+
+    /// Make a `POST` http request to the v2 api with a json body
+    pub fn post_json_v2<T: DeserializeOwned + Send + 'static, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: B,
+    ) -> Response<T> {
+        self.send_blocking(self.inner.post_json_v2(path, body))
+    }
+
     fn send_blocking<T: DeserializeOwned + Send + 'static>(
         &self,
         request: super::r#async::Response<T>,
     ) -> Response<T> {
+        let timeout = match self.timeout {
+            Some(timeout) => timeout,
+            None => return self.runtime.block_on(request),
+        };
         match self.runtime.block_on(async {
             // N.B. The `tokio::time::timeout` must be called from within a running async
             //      context or else it will panic (it registers with the thread-local timer).
-            tokio::time::timeout(DEFAULT_TIMEOUT, request).await
+            tokio::time::timeout(timeout, request).await
         }) {
             Ok(finished) => finished,
             Err(_) => Err(Error::timeout()),
@@ -0,0 +1,32 @@
+use std::sync::{Arc, Mutex};
+
+use crate::params::ResponseEnvelope;
+
+/// Hooks run by a `Client` around every request it sends.
+///
+/// Register with `Client::add_middleware` to inject logging, custom headers, or metrics.
+/// Middleware runs in the order it was added.
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent, e.g. to add headers.
+    fn on_request(&self, _request: &mut http::Request<Vec<u8>>) {}
+
+    /// Called when a response is received, before it is deserialized.
+    fn on_response(&self, _response: &http::Response<Vec<u8>>) {}
+}
+
+/// Records the headers of the last response, to build a `ResponseEnvelope`.
+#[derive(Clone, Default)]
+pub(crate) struct CaptureHeaders(Arc<Mutex<http::HeaderMap>>);
+
+impl CaptureHeaders {
+    pub(crate) fn envelope<T>(&self, data: T) -> ResponseEnvelope<T> {
+        let headers = std::mem::take(&mut *self.0.lock().unwrap());
+        ResponseEnvelope { data, headers }
+    }
+}
+
+impl Middleware for CaptureHeaders {
+    fn on_response(&self, response: &http::Response<Vec<u8>>) {
+        *self.0.lock().unwrap() = response.headers().clone();
+    }
+}
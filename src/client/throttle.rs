@@ -0,0 +1,67 @@
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Limits the number of concurrent requests sent by a `Client` and its clones.
+///
+/// The limit is halved whenever Stripe rate limits a request and raised by one again
+/// after as many successful requests as the current limit, up to `max_concurrency`.
+pub(crate) struct Throttle {
+    semaphore: Arc<Semaphore>,
+    max_concurrency: usize,
+    state: Mutex<ThrottleState>,
+}
+
+struct ThrottleState {
+    limit: usize,
+    successes: usize,
+    /// Permits to discard as they are released, after the limit was lowered.
+    excess: usize,
+}
+
+impl Throttle {
+    pub(crate) fn new(max_concurrency: usize) -> Throttle {
+        let max_concurrency = max_concurrency.max(1);
+        Throttle {
+            semaphore: Arc::new(Semaphore::new(max_concurrency)),
+            max_concurrency,
+            state: Mutex::new(ThrottleState { limit: max_concurrency, successes: 0, excess: 0 }),
+        }
+    }
+
+    /// The current number of requests which may be sent concurrently.
+    pub(crate) fn limit(&self) -> usize {
+        self.state.lock().unwrap().limit
+    }
+
+    /// Waits until another request may be sent.
+    pub(crate) async fn acquire(&self) -> OwnedSemaphorePermit {
+        self.semaphore.clone().acquire_owned().await.expect("the semaphore is never closed")
+    }
+
+    /// Returns the permit of a completed request, adjusting the limit to its outcome.
+    pub(crate) fn release(&self, permit: OwnedSemaphorePermit, rate_limited: bool) {
+        let mut state = self.state.lock().unwrap();
+        if rate_limited {
+            let limit = (state.limit / 2).max(1);
+            state.excess += state.limit - limit;
+            state.limit = limit;
+            state.successes = 0;
+        } else if state.limit < self.max_concurrency {
+            state.successes += 1;
+            if state.successes >= state.limit {
+                state.limit += 1;
+                state.successes = 0;
+                if state.excess > 0 {
+                    state.excess -= 1;
+                } else {
+                    self.semaphore.add_permits(1);
+                }
+            }
+        }
+        if state.excess > 0 {
+            state.excess -= 1;
+            permit.forget();
+        }
+    }
+}
@@ -7,6 +7,8 @@ use std::num::ParseIntError;
 pub enum Error {
     /// An error reported by Stripe in the response body.
     Stripe(RequestError),
+    /// An error reported by Stripe Connect's OAuth endpoints.
+    OAuth(OAuthError),
     /// An http or networking error communicating with the Stripe server.
     Http(HttpError),
     /// An error reading the response body.
@@ -48,6 +50,7 @@ impl std::fmt::Display for Error {
         f.write_str(std::error::Error::description(self))?;
         match *self {
             Error::Stripe(ref err) => write!(f, ": {}", err),
+            Error::OAuth(ref err) => write!(f, ": {}", err),
             Error::Http(ref err) => write!(f, ": {}", err),
             Error::Io(ref err) => write!(f, ": {}", err),
             Error::Serialize(ref err) => write!(f, ": {}", err),
@@ -62,6 +65,7 @@ impl std::error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Stripe(_) => "error reported by stripe",
+            Error::OAuth(_) => "error reported by stripe oauth",
             Error::Http(_) => "error communicating with stripe",
             Error::Io(_) => "error reading response from stripe",
             Error::Serialize(_) => "error serializing a request",
@@ -74,6 +78,7 @@ impl std::error::Error for Error {
     fn cause(&self) -> Option<&dyn std::error::Error> {
         match *self {
             Error::Stripe(ref err) => Some(err),
+            Error::OAuth(ref err) => Some(err),
             Error::Http(ref err) => Some(err),
             Error::Io(ref err) => Some(err),
             Error::Serialize(ref err) => Some(&**err),
@@ -90,6 +95,13 @@ impl From<RequestError> for Error {
     }
 }
 
+impl From<OAuthError> for Error {
+    fn from(err: OAuthError) -> Error {
+        Error::OAuth(err)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
 impl From<hyper::Error> for Error {
     fn from(err: hyper::Error) -> Error {
         Error::Http(HttpError::Stream(err))
@@ -105,16 +117,31 @@ impl From<std::io::Error> for Error {
 #[derive(Debug)]
 pub enum HttpError {
     /// An error handling HTTP streams.
+    #[cfg(not(target_arch = "wasm32"))]
     Stream(hyper::Error),
+    /// An error reported by a custom `HttpClient`.
+    Transport(Box<dyn std::error::Error + Send>),
     /// The request timed out.
     Timeout,
 }
 
+impl HttpError {
+    /// Wraps an error from a custom `HttpClient` for it to return.
+    pub fn transport<T>(err: T) -> Error
+    where
+        T: std::error::Error + Send + 'static,
+    {
+        Error::Http(HttpError::Transport(Box::new(err)))
+    }
+}
+
 impl std::fmt::Display for HttpError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         #[allow(deprecated)]
         match *self {
+            #[cfg(not(target_arch = "wasm32"))]
             HttpError::Stream(ref err) => err.fmt(f),
+            HttpError::Transport(ref err) => err.fmt(f),
             HttpError::Timeout => f.write_str(std::error::Error::description(self)),
         }
     }
@@ -124,14 +151,18 @@ impl std::error::Error for HttpError {
     fn description(&self) -> &str {
         #[allow(deprecated)]
         match *self {
+            #[cfg(not(target_arch = "wasm32"))]
             HttpError::Stream(ref err) => err.description(),
+            HttpError::Transport(ref err) => err.description(),
             HttpError::Timeout => "request timed out",
         }
     }
 
     fn cause(&self) -> Option<&dyn std::error::Error> {
         match *self {
+            #[cfg(not(target_arch = "wasm32"))]
             HttpError::Stream(ref err) => Some(err),
+            HttpError::Transport(ref err) => Some(&**err),
             HttpError::Timeout => None,
         }
     }
@@ -140,9 +171,6 @@ impl std::error::Error for HttpError {
 /// The list of possible values for a RequestError's type.
 #[derive(Debug, PartialEq, Deserialize)]
 pub enum ErrorType {
-    #[serde(skip_deserializing)]
-    Unknown,
-
     #[serde(rename = "api_error")]
     Api,
     #[serde(rename = "api_connection_error")]
@@ -157,6 +185,10 @@ pub enum ErrorType {
     RateLimit,
     #[serde(rename = "validation_error")]
     Validation,
+
+    /// Also used for error types not yet supported by the library.
+    #[serde(other)]
+    Unknown,
 }
 
 impl Default for ErrorType {
@@ -183,22 +215,26 @@ pub enum ErrorCode {
     AmountTooLarge,
     AmountTooSmall,
     ApiKeyExpired,
+    AuthenticationRequired,
     BalanceInsufficient,
     BankAccountExists,
     BankAccountUnusable,
     BankAccountUnverified,
     BankAccountVerificationFailed,
     BitcoinUpgradeRequired,
+    CardDeclineRateLimitExceeded,
     CardDeclined,
     ChargeAlreadyCaptured,
     ChargeAlreadyRefunded,
     ChargeDisputed,
+    ChargeExceedsSourceLimit,
     ChargeExpiredForCapture,
     CountryUnsupported,
     CouponExpired,
     CustomerMaxSubscriptions,
     EmailInvalid,
     ExpiredCard,
+    IdempotencyKeyInUse,
     IncorrectAddress,
     IncorrectCvc,
     IncorrectNumber,
@@ -216,6 +252,7 @@ pub enum ErrorCode {
     InvoiceNotEditable,
     InvoiceUpcomingNone,
     LivemodeMismatch,
+    LockTimeout,
     Missing,
     OrderCreationFailed,
     OrderRequiredSettings,
@@ -228,7 +265,13 @@ pub enum ErrorCode {
     ParameterInvalidStringEmpty,
     ParameterMissing,
     ParameterUnknown,
+    PaymentIntentActionRequired,
+    PaymentIntentAuthenticationFailure,
+    PaymentIntentIncompatiblePaymentMethod,
+    PaymentIntentPaymentAttemptFailed,
+    PaymentIntentUnexpectedState,
     PaymentMethodUnactivated,
+    PaymentMethodUnexpectedState,
     PayoutsNotAllowed,
     PlatformApiKeyExpired,
     PostalCodeInvalid,
@@ -240,6 +283,8 @@ pub enum ErrorCode {
     RoutingNumberInvalid,
     SecretKeyRequired,
     SepaUnsupportedAccount,
+    SetupIntentAuthenticationFailure,
+    SetupIntentUnexpectedState,
     ShippingCalculationFailed,
     SkuInactive,
     StateUnsupported,
@@ -254,6 +299,11 @@ pub enum ErrorCode {
     UrlInvalid,
     #[doc(hidden)]
     __NonExhaustive,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -262,6 +312,72 @@ impl std::fmt::Display for ErrorCode {
     }
 }
 
+/// The list of possible values for a card issuer's reason to decline a payment.
+///
+/// For more details see https://stripe.com/docs/declines/codes.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclineCode {
+    ApproveWithId,
+    AuthenticationRequired,
+    CallIssuer,
+    CardNotSupported,
+    CardVelocityExceeded,
+    CurrencyNotSupported,
+    DoNotHonor,
+    DoNotTryAgain,
+    DuplicateTransaction,
+    ExpiredCard,
+    Fraudulent,
+    GenericDecline,
+    IncorrectCvc,
+    IncorrectNumber,
+    IncorrectPin,
+    IncorrectZip,
+    InsufficientFunds,
+    InvalidAccount,
+    InvalidAmount,
+    InvalidCvc,
+    InvalidExpiryMonth,
+    InvalidExpiryYear,
+    InvalidNumber,
+    InvalidPin,
+    IssuerNotAvailable,
+    LostCard,
+    MerchantBlacklist,
+    NewAccountInformationAvailable,
+    NoActionTaken,
+    NotPermitted,
+    OfflinePinRequired,
+    OnlineOrOfflinePinRequired,
+    PickupCard,
+    PinTryExceeded,
+    ProcessingError,
+    ReenterTransaction,
+    RestrictedCard,
+    RevocationOfAllAuthorizations,
+    RevocationOfAuthorization,
+    SecurityViolation,
+    ServiceNotAllowed,
+    StolenCard,
+    StopPaymentOrder,
+    TestmodeDecline,
+    TransactionNotAllowed,
+    TryAgainLater,
+    WithdrawalCountLimitExceeded,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl std::fmt::Display for DeclineCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+    }
+}
+
 /// An error reported by stripe in a request's response.
 ///
 /// For more details see https://stripe.com/docs/api#errors.
@@ -283,9 +399,9 @@ pub struct RequestError {
     /// For card errors, a value describing the kind of card error that occured.
     pub code: Option<ErrorCode>,
 
-    /// For card errors resulting from a bank decline, a string indicating the
-    /// bank's reason for the decline if they provide one.
-    pub decline_code: Option<String>,
+    /// For card errors resulting from a bank decline, the bank's reason for
+    /// the decline if they provide one.
+    pub decline_code: Option<DeclineCode>,
 
     /// The ID of the failed charge, if applicable.
     pub charge: Option<String>,
@@ -314,6 +430,39 @@ pub struct ErrorResponse {
     pub error: RequestError,
 }
 
+/// An error reported by the Stripe Connect OAuth endpoints.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-token-errors.
+#[derive(Debug, Default, Deserialize)]
+pub struct OAuthError {
+    /// The HTTP status in the response.
+    #[serde(skip_deserializing)]
+    pub http_status: u16,
+
+    /// The error code (e.g. `invalid_grant` or `invalid_client`).
+    pub error: String,
+
+    /// A human-readable message providing more details about the error.
+    #[serde(default)]
+    pub error_description: Option<String>,
+}
+
+impl std::fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.error, self.http_status)?;
+        if let Some(ref description) = self.error_description {
+            write!(f, ": {}", description)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for OAuthError {
+    fn description(&self) -> &str {
+        self.error_description.as_deref().unwrap_or(self.error.as_str())
+    }
+}
+
 /// An error encountered when communicating with the Stripe API webhooks.
 #[derive(Debug)]
 pub enum WebhookError {
@@ -365,3 +514,77 @@ impl std::error::Error for WebhookError {
         }
     }
 }
+
+/// An error returned when `Metadata` exceeds the limits enforced by Stripe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MetadataError {
+    /// There are more than 50 keys.
+    TooManyKeys(usize),
+    /// A key is longer than 40 characters.
+    KeyTooLong(String),
+    /// The value of a key is longer than 500 characters.
+    ValueTooLong(String),
+}
+
+impl std::fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[allow(deprecated)]
+        f.write_str(std::error::Error::description(self))?;
+        match *self {
+            MetadataError::TooManyKeys(count) => write!(f, ": {}", count),
+            MetadataError::KeyTooLong(ref key) => write!(f, ": {}", key),
+            MetadataError::ValueTooLong(ref key) => write!(f, ": {}", key),
+        }
+    }
+}
+
+impl std::error::Error for MetadataError {
+    fn description(&self) -> &str {
+        match *self {
+            MetadataError::TooManyKeys(_) => "metadata has more than 50 keys",
+            MetadataError::KeyTooLong(_) => "metadata key is longer than 40 characters",
+            MetadataError::ValueTooLong(_) => "metadata value is longer than 500 characters",
+        }
+    }
+}
+
+/// An error returned when a `StatementDescriptor` breaks the rules enforced by Stripe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatementDescriptorError {
+    /// The descriptor is shorter than the minimum length.
+    TooShort(usize),
+    /// The descriptor is longer than 22 characters.
+    TooLong(usize),
+    /// The descriptor contains one of `<`, `>`, `\`, `'`, `"` or `*`.
+    InvalidCharacter(char),
+    /// The descriptor does not contain a single letter.
+    NoLetters,
+}
+
+impl std::fmt::Display for StatementDescriptorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[allow(deprecated)]
+        f.write_str(std::error::Error::description(self))?;
+        match *self {
+            StatementDescriptorError::TooShort(len) => write!(f, ": {}", len),
+            StatementDescriptorError::TooLong(len) => write!(f, ": {}", len),
+            StatementDescriptorError::InvalidCharacter(c) => write!(f, ": {:?}", c),
+            StatementDescriptorError::NoLetters => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for StatementDescriptorError {
+    fn description(&self) -> &str {
+        match *self {
+            StatementDescriptorError::TooShort(_) => "statement descriptor is too short",
+            StatementDescriptorError::TooLong(_) => {
+                "statement descriptor is longer than 22 characters"
+            }
+            StatementDescriptorError::InvalidCharacter(_) => {
+                "statement descriptor contains an invalid character"
+            }
+            StatementDescriptorError::NoLetters => "statement descriptor contains no letters",
+        }
+    }
+}
@@ -458,6 +458,7 @@ impl std::error::Error for ParseIdError {
 
 def_id!(AccountId, "acct_");
 def_id!(AlipayAccountId, "aliacc_");
+def_id!(ApplePayDomainId, "apwc_");
 def_id!(ApplicationId, "ca_");
 def_id!(ApplicationFeeId, "fee_");
 def_id!(ApplicationFeeRefundId, "fr_");
@@ -480,24 +481,39 @@ def_id!(
         TransferReversal(TransferReversalId),
     }
 );
+def_id!(BillingAlertId, "alrt_");
+def_id!(BillingMeterId, "mtr_");
+def_id!(BillingMeterEventSummaryId: String); // N.B. Event summary ids are not documented to have a stable prefix
 def_id!(BillingPortalSessionId, "bps_");
 def_id!(BillingPortalConfigurationId, "bpc_");
+def_id!(CapabilityId: String); // N.B. A capability id is the name of the capability, e.g. `card_payments`
 def_id!(CardId, "card_");
 def_id!(CardTokenId, "tok_");
 def_id!(ChargeId, "ch_" | "py_"); // TODO: Understand (and then document) why "py_" is a valid charge id
 def_id!(CheckoutSessionId, "cs_");
-def_id!(CheckoutSessionItemId: String); // TODO: Figure out what prefix this id has
+def_id!(CheckoutSessionItemId, "li_");
+def_id!(ConfirmationTokenId, "ctoken_");
+def_id!(CountrySpecId: String); // N.B. A country spec id is the ISO country code
 def_id!(CouponId: String); // N.B. A coupon id can be user-provided so can be any arbitrary string
 def_id!(CustomerId, "cus_");
+def_id!(CustomerCashBalanceTransactionId, "ccsbtxn_");
 def_id!(DisputeId, "dp_" | "du_");
+def_id!(EntitlementsActiveEntitlementId, "ent_");
+def_id!(EntitlementsFeatureId, "feat_");
+def_id!(EventDestinationId, "ed_");
 def_id!(EventId, "evt_");
+def_id!(ExchangeRateId: String); // N.B. An exchange rate id is the lowercase ISO currency code
 def_id!(FileId, "file_");
 def_id!(FileLinkId, "link_");
+def_id!(ForwardingRequestId, "fwdreq_");
 def_id!(InvoiceId, "in_", { _ });
 def_id!(InvoiceItemId, "ii_");
+def_id!(InvoiceLineId, "il_");
+def_id!(InvoiceRenderingTemplateId, "inrtem_");
 def_id!(
     enum InvoiceLineItemId {
         Item(InvoiceItemId),
+        Line(InvoiceLineId),
         Subscription(SubscriptionLineId),
     }
 );
@@ -508,9 +524,11 @@ def_id!(IssuingDisputeId, "idp_");
 def_id!(IssuingTransactionId, "ipi_");
 def_id!(OrderId, "or_");
 def_id!(OrderReturnId, "orret_");
-def_id!(MandateId: String); // TODO: Figure out what prefix this id has
+def_id!(MandateId, "mandate_");
 def_id!(PaymentIntentId, "pi_");
 def_id!(PaymentMethodId, "pm");
+def_id!(PaymentMethodConfigurationId, "pmc_");
+def_id!(PaymentMethodDomainId, "pmd_");
 def_id!(
     enum PaymentSourceId {
         Account(AccountId),
@@ -529,21 +547,27 @@ def_id!(
 );
 def_id!(PersonId, "person_");
 def_id!(PlanId: String); // N.B. A plan id can be user-provided so can be any arbitrary string
-def_id!(PriceId: String); // TODO: Figure out what prefix this id has
+def_id!(PriceId: String); // N.B. A price id may be a user-provided plan id so can be any arbitrary string
 def_id!(ProductId: String); // N.B. A product id can be user-provided so can be any arbitrary string
+def_id!(ProductFeatureId, "prodft_");
 def_id!(RecipientId: String); // FIXME: This doesn't seem to be documented yet
 def_id!(RefundId, "re_" | "pyr_");
 def_id!(ReviewId, "prv_");
 def_id!(ScheduledQueryRunId, "sqr_");
+def_id!(SetupAttemptId, "setatt_");
 def_id!(SetupIntentId, "seti_");
+def_id!(ShippingRateId, "shr_");
 def_id!(SkuId, "sku_");
 def_id!(SourceId, "src_");
+def_id!(SourceTransactionId, "srctxn_");
 def_id!(SubscriptionId, "sub_");
 def_id!(SubscriptionItemId, "si_");
 def_id!(SubscriptionLineId, "sli_");
 def_id!(SubscriptionScheduleId, "sub_sched_");
 def_id!(TaxIdId, "txi_");
 def_id!(TaxRateId, "txr_");
+def_id!(TaxRegistrationId, "taxreg_");
+def_id!(TestHelpersTestClockId, "clock_");
 def_id!(
     enum TokenId {
         Card(CardTokenId),
@@ -593,6 +617,12 @@ impl<'de> serde::Deserialize<'de> for InvoiceId {
     }
 }
 
+impl From<crate::resources::Currency> for ExchangeRateId {
+    fn from(currency: crate::resources::Currency) -> Self {
+        Self(currency.to_string().into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -623,4 +653,12 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_parse_payment_intent() {
+        assert!("pi_123".parse::<PaymentIntentId>().is_ok());
+        assert!("cus_123".parse::<PaymentIntentId>().is_err());
+        assert!("mandate_123".parse::<MandateId>().is_ok());
+        assert!("li_123".parse::<CheckoutSessionItemId>().is_ok());
+    }
 }
@@ -0,0 +1,96 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::error::{ErrorBadRequest, ErrorInternalServerError};
+use actix_web::{web, FromRequest, HttpRequest};
+
+use crate::resources::{StripeEvent, Webhook, WebhookSecret};
+
+/// Verifies the request body against the app's `web::Data<WebhookSecret>`.
+///
+/// ```rust,ignore
+/// #[actix_web::post("/webhook")]
+/// async fn handle_webhook(stripe::StripeEvent(event): stripe::StripeEvent) -> &'static str {
+///     println!("received {:?}", event.event_type);
+///     "ok"
+/// }
+///
+/// let app = actix_web::App::new()
+///     .app_data(actix_web::web::Data::new(stripe::WebhookSecret("whsec_...".into())))
+///     .service(handle_webhook);
+/// ```
+impl FromRequest for StripeEvent {
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let secret = req.app_data::<web::Data<WebhookSecret>>().cloned();
+        let signature = req
+            .headers()
+            .get("stripe-signature")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let body = String::from_request(req, payload);
+        Box::pin(async move {
+            let secret =
+                secret.ok_or_else(|| ErrorInternalServerError("missing WebhookSecret app data"))?;
+            let signature =
+                signature.ok_or_else(|| ErrorBadRequest("missing Stripe-Signature header"))?;
+            let payload = body.await?;
+            Webhook::construct_event(&payload, &signature, &secret.0)
+                .map(StripeEvent)
+                .map_err(ErrorBadRequest)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::{web, FromRequest};
+
+    use crate::resources::{StripeEvent, Webhook, WebhookSecret};
+
+    const SECRET: &str = "whsec_123";
+    const PAYLOAD: &str = r#"{"id": "evt_123", "object": "event", "type": "product.deleted", "data": {"object": {"id": "prod_123", "object": "product", "deleted": true}}}"#;
+
+    async fn extract(signature: Option<&str>) -> Result<StripeEvent, StatusCode> {
+        let mut request = TestRequest::post()
+            .uri("/webhook")
+            .app_data(web::Data::new(WebhookSecret(SECRET.into())))
+            .set_payload(PAYLOAD);
+        if let Some(signature) = signature {
+            request = request.insert_header(("stripe-signature", signature));
+        }
+        let (request, mut payload) = request.to_http_parts();
+        StripeEvent::from_request(&request, &mut payload)
+            .await
+            .map_err(|err| err.as_response_error().status_code())
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future)
+    }
+
+    #[test]
+    fn test_extract_verified_event() {
+        let now = chrono::Utc::now().timestamp();
+        let signature = Webhook::generate_test_signature(PAYLOAD, SECRET, now).unwrap();
+        let StripeEvent(event) = block_on(extract(Some(&signature))).unwrap();
+        assert_eq!(event.id.as_str(), "evt_123");
+    }
+
+    #[test]
+    fn test_reject_bad_signature() {
+        let now = chrono::Utc::now().timestamp();
+        let signature = Webhook::generate_test_signature(PAYLOAD, "whsec_other", now).unwrap();
+        assert_eq!(block_on(extract(Some(&signature))).unwrap_err(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_reject_missing_signature() {
+        assert_eq!(block_on(extract(None)).unwrap_err(), StatusCode::BAD_REQUEST);
+    }
+}
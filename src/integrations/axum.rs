@@ -0,0 +1,90 @@
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use crate::resources::{StripeEvent, Webhook, WebhookSecret};
+
+/// Verifies the request body against the `WebhookSecret` in the router's state.
+///
+/// Because it consumes the request body, `StripeEvent` must be the last extractor of a handler.
+///
+/// ```rust,ignore
+/// async fn handle_webhook(stripe::StripeEvent(event): stripe::StripeEvent) {
+///     println!("received {:?}", event.event_type);
+/// }
+///
+/// let app = axum::Router::new()
+///     .route("/webhook", axum::routing::post(handle_webhook))
+///     .with_state(stripe::WebhookSecret("whsec_...".into()));
+/// ```
+#[axum::async_trait]
+impl<S> FromRequest<S> for StripeEvent
+where
+    WebhookSecret: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let signature = req
+            .headers()
+            .get("stripe-signature")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                (StatusCode::BAD_REQUEST, "missing Stripe-Signature header").into_response()
+            })?;
+        let payload =
+            String::from_request(req, state).await.map_err(IntoResponse::into_response)?;
+        let secret = WebhookSecret::from_ref(state);
+        Webhook::construct_event(&payload, &signature, &secret.0)
+            .map(StripeEvent)
+            .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()).into_response())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::{FromRequest, Request};
+    use axum::http::StatusCode;
+
+    use crate::resources::{StripeEvent, Webhook, WebhookSecret};
+
+    const SECRET: &str = "whsec_123";
+    const PAYLOAD: &str = r#"{"id": "evt_123", "object": "event", "type": "product.deleted", "data": {"object": {"id": "prod_123", "object": "product", "deleted": true}}}"#;
+
+    async fn extract(signature: Option<&str>) -> Result<StripeEvent, StatusCode> {
+        let mut request = Request::builder().method("POST").uri("/webhook");
+        if let Some(signature) = signature {
+            request = request.header("stripe-signature", signature);
+        }
+        let request = request.body(Body::from(PAYLOAD)).unwrap();
+        let state = WebhookSecret(SECRET.into());
+        StripeEvent::from_request(request, &state).await.map_err(|response| response.status())
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future)
+    }
+
+    #[test]
+    fn test_extract_verified_event() {
+        let now = chrono::Utc::now().timestamp();
+        let signature = Webhook::generate_test_signature(PAYLOAD, SECRET, now).unwrap();
+        let StripeEvent(event) = block_on(extract(Some(&signature))).unwrap();
+        assert_eq!(event.id.as_str(), "evt_123");
+    }
+
+    #[test]
+    fn test_reject_bad_signature() {
+        let now = chrono::Utc::now().timestamp();
+        let signature = Webhook::generate_test_signature(PAYLOAD, "whsec_other", now).unwrap();
+        assert_eq!(block_on(extract(Some(&signature))).unwrap_err(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_reject_missing_signature() {
+        assert_eq!(block_on(extract(None)).unwrap_err(), StatusCode::BAD_REQUEST);
+    }
+}
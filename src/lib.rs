@@ -57,10 +57,16 @@
 #![allow(clippy::needless_pass_by_value)]
 #![allow(clippy::large_enum_variant)]
 
+#[cfg(all(target_arch = "wasm32", feature = "blocking"))]
+compile_error!("The `blocking` client is not supported on wasm32; use the async client instead");
+
 mod client {
     pub mod r#async;
     #[cfg(feature = "blocking")]
     pub mod blocking;
+    pub mod middleware;
+    pub mod throttle;
+    pub mod transport;
 }
 
 mod error;
@@ -68,6 +74,13 @@ mod ids;
 mod params;
 mod resources;
 
+mod integrations {
+    #[cfg(feature = "actix")]
+    pub mod actix;
+    #[cfg(feature = "axum")]
+    pub mod axum;
+}
+
 // N.B. Ideally we would support both a blocking client and
 //      an async client without a feature flag, but the originally
 //      discussed solution requires Generic Associated Types--
@@ -75,10 +88,27 @@ mod resources;
 //
 // See https://github.com/wyyerd/stripe-rs/issues/24#issuecomment-451514187
 // See https://github.com/rust-lang/rust/issues/44265
-pub use crate::error::{Error, ErrorCode, ErrorType, RequestError, WebhookError};
+pub use crate::client::middleware::Middleware;
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub use crate::client::transport::FetchClient;
+#[cfg(all(not(target_arch = "wasm32"), feature = "proxy"))]
+pub use crate::client::transport::ProxyConfig;
+#[cfg(all(not(target_arch = "wasm32"), feature = "reqwest"))]
+pub use crate::client::transport::ReqwestClient;
+pub use crate::client::transport::{HttpClient, HttpResponse};
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::client::transport::{HttpConfig, HyperClient};
+pub use crate::error::{
+    DeclineCode, Error, ErrorCode, ErrorType, HttpError, MetadataError, OAuthError, RequestError,
+    StatementDescriptorError, WebhookError,
+};
 pub use crate::ids::*;
+#[cfg(not(feature = "blocking"))]
+pub use crate::params::ListStream;
 pub use crate::params::{
-    Expandable, Headers, IdOrCreate, List, Metadata, Object, RangeBounds, RangeQuery, Timestamp,
+    Expandable, Headers, IdOrCreate, List, Metadata, Object, RangeBounds, RangeQuery,
+    RequestOptions, RequestStrategy, ResponseEnvelope, SearchList, StatementDescriptor, Timestamp,
+    UpdateField, V2List,
 };
 pub use crate::resources::*;
 
@@ -1,14 +1,17 @@
 use crate::config::{err, ok, Client, Response};
-use crate::error::Error;
+use crate::error::{Error, MetadataError, StatementDescriptorError};
 use crate::resources::ApiVersion;
 use serde::de::DeserializeOwned;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize)]
 pub struct AppInfo {
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
 
@@ -18,6 +21,96 @@ pub struct Headers {
     pub stripe_version: Option<ApiVersion>,
     pub stripe_account: Option<String>,
     pub user_agent: Option<String>,
+    pub idempotency_key: Option<String>,
+}
+
+/// Options which apply to a single request rather than to every request made by a client.
+///
+/// Unset fields fall back to the headers of the client the options are applied to,
+/// see `Client::with_options`.
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    /// The connected account to make the request on behalf of (the `Stripe-Account` header).
+    pub stripe_account: Option<String>,
+    /// A key used to safely retry the request without performing the operation twice.
+    pub idempotency_key: Option<String>,
+    /// The api version to use for the request instead of the client's (the `Stripe-Version` header).
+    pub api_version: Option<ApiVersion>,
+    /// Whether and how to retry the request if it fails, instead of the client's strategy.
+    pub strategy: Option<RequestStrategy>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        RequestOptions {
+            stripe_account: Default::default(),
+            idempotency_key: Default::default(),
+            api_version: Default::default(),
+            strategy: Default::default(),
+        }
+    }
+
+    /// Returns `headers` with any options that are set taking precedence.
+    pub(crate) fn apply(self, mut headers: Headers) -> Headers {
+        if let Some(stripe_account) = self.stripe_account {
+            headers.stripe_account = Some(stripe_account);
+        }
+        if let Some(idempotency_key) = self.idempotency_key {
+            headers.idempotency_key = Some(idempotency_key);
+        }
+        if let Some(api_version) = self.api_version {
+            headers.stripe_version = Some(api_version);
+        }
+        headers
+    }
+}
+
+/// Whether and how a client retries requests which fail with a network error
+/// or an error Stripe reports as retryable (a lock timeout, rate limit, or server error).
+///
+/// Retried `POST` requests are sent with an idempotency key, generated for the request
+/// unless one was set with `RequestOptions`, so the operation is never performed twice.
+///
+/// See `Client::with_strategy`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum RequestStrategy {
+    /// Send the request once, returning the first error.
+    #[default]
+    Once,
+    /// Retry the request immediately, up to `max_retries` times.
+    Idempotent { max_retries: u32 },
+    /// Retry the request up to `max_retries` times, doubling the delay between attempts
+    /// from `initial_delay` up to at most `max_delay`.
+    ///
+    /// Delays require a Tokio runtime with the timer enabled; on wasm32 retries are not delayed.
+    ExponentialBackoff { max_retries: u32, initial_delay: Duration, max_delay: Duration },
+}
+
+impl RequestStrategy {
+    /// The number of times a failed request may be retried.
+    pub fn max_retries(&self) -> u32 {
+        match *self {
+            RequestStrategy::Once => 0,
+            RequestStrategy::Idempotent { max_retries } => max_retries,
+            RequestStrategy::ExponentialBackoff { max_retries, .. } => max_retries,
+        }
+    }
+
+    /// The delay before sending the request again after `retries` failed retries,
+    /// or `None` if the request should not be retried.
+    pub fn retry_delay(&self, retries: u32) -> Option<Duration> {
+        if retries >= self.max_retries() {
+            return None;
+        }
+        match *self {
+            RequestStrategy::Once => None,
+            RequestStrategy::Idempotent { .. } => Some(Duration::from_secs(0)),
+            RequestStrategy::ExponentialBackoff { initial_delay, max_delay, .. } => {
+                let factor = 2u32.checked_pow(retries).unwrap_or(u32::MAX);
+                Some(initial_delay.checked_mul(factor).unwrap_or(max_delay).min(max_delay))
+            }
+        }
+    }
 }
 
 /// Implemented by types which represent stripe objects.
@@ -208,6 +301,32 @@ impl<T: Paginate + DeserializeOwned + Send + 'static> List<T> {
         Ok(data)
     }
 
+    /// Lazily fetches the remaining pages of the list, yielding each element in turn.
+    ///
+    /// Only a single page is held in memory at a time, which makes this suitable
+    /// for iterating over very large lists.
+    ///
+    /// Not available with `feature = "blocking"`, use `List::get_all` instead.
+    #[cfg(not(feature = "blocking"))]
+    pub fn stream(self, client: &Client) -> ListStream<T> {
+        ListStream {
+            client: client.clone(),
+            next_page: self.next_cursor().map(NextPage::Cursor),
+            url: self.url,
+            page: self.data.into_iter(),
+            prefetch: false,
+        }
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    fn next_cursor(&self) -> Option<String> {
+        if self.has_more {
+            self.data.last().map(|d| d.cursor().as_ref().to_string())
+        } else {
+            None
+        }
+    }
+
     /// Fetch an additional page of data from stripe.
     pub fn next(&self, client: &Client) -> Response<List<T>> {
         if let Some(last_id) = self.data.last().map(|d| d.cursor()) {
@@ -223,8 +342,486 @@ impl<T: Paginate + DeserializeOwned + Send + 'static> List<T> {
     }
 }
 
-pub type Metadata = HashMap<String, String>;
-pub type Timestamp = i64;
+/// A stream over the elements of a `List`, fetching additional pages as needed.
+///
+/// See `List::stream`.
+#[cfg(not(feature = "blocking"))]
+pub struct ListStream<T> {
+    client: Client,
+    url: String,
+    page: std::vec::IntoIter<T>,
+    next_page: Option<NextPage<T>>,
+    prefetch: bool,
+}
+
+#[cfg(not(feature = "blocking"))]
+enum NextPage<T> {
+    /// The id of the last element of the previous page, which has not been requested yet.
+    Cursor(String),
+    Pending(Response<List<T>>),
+    Ready(Result<List<T>, Error>),
+}
+
+// `ListStream` never pins its fields, so it is `Unpin` regardless of `T`.
+#[cfg(not(feature = "blocking"))]
+impl<T> Unpin for ListStream<T> {}
+
+#[cfg(not(feature = "blocking"))]
+impl<T: Paginate + DeserializeOwned + Send + 'static> ListStream<T> {
+    /// Request the next page as soon as the current one starts being consumed,
+    /// rather than once it has been exhausted.
+    ///
+    /// At most one page is fetched ahead, so this roughly doubles the memory used
+    /// by the stream in exchange for overlapping network requests with processing.
+    ///
+    /// On wasm32 the prefetched request keeps being polled each time the stream is, and since
+    /// retries aren't delayed there (see `RequestStrategy`), pages are requested back to back
+    /// with no delay between them.
+    pub fn with_prefetch(mut self) -> Self {
+        self.prefetch = true;
+        self
+    }
+
+    fn poll_next_page(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Result<List<T>, Error>>> {
+        use std::task::Poll;
+
+        if let Some(NextPage::Cursor(last_id)) = &self.next_page {
+            let next = List::get_next(&self.client, &self.url, last_id);
+            self.next_page = Some(NextPage::Pending(next));
+        }
+        match self.next_page.take() {
+            None => Poll::Ready(None),
+            Some(NextPage::Ready(result)) => Poll::Ready(Some(result)),
+            Some(NextPage::Pending(mut next)) => match next.as_mut().poll(cx) {
+                Poll::Ready(result) => Poll::Ready(Some(result)),
+                Poll::Pending => {
+                    self.next_page = Some(NextPage::Pending(next));
+                    Poll::Pending
+                }
+            },
+            Some(NextPage::Cursor(_)) => unreachable!(),
+        }
+    }
+}
+
+#[cfg(not(feature = "blocking"))]
+impl<T: Paginate + DeserializeOwned + Send + 'static> futures_util::Stream for ListStream<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let this = self.get_mut();
+        if this.prefetch {
+            if let Poll::Ready(Some(result)) = this.poll_next_page(cx) {
+                this.next_page = Some(NextPage::Ready(result));
+            }
+        }
+        loop {
+            if let Some(item) = this.page.next() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            match futures_util::ready!(this.poll_next_page(cx)) {
+                None => return Poll::Ready(None),
+                Some(Ok(list)) => {
+                    this.next_page = list.next_cursor().map(NextPage::Cursor);
+                    this.page = list.data.into_iter();
+                }
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+            }
+        }
+    }
+}
+
+/// A response from Stripe along with the headers it was sent with.
+///
+/// See `Client::with_envelope`.
+#[derive(Clone, Debug)]
+pub struct ResponseEnvelope<T> {
+    pub data: T,
+    pub headers: http::HeaderMap,
+}
+
+impl<T> ResponseEnvelope<T> {
+    /// The id Stripe assigned to the request, which can be used to find it in the dashboard logs.
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("request-id")
+    }
+
+    /// The api version Stripe used to render the response.
+    pub fn stripe_version(&self) -> Option<&str> {
+        self.header("stripe-version")
+    }
+
+    /// Whether the response was replayed from an earlier request with the same idempotency key.
+    pub fn idempotent_replayed(&self) -> bool {
+        self.header("idempotent-replayed") == Some("true")
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// A single page of results from a search api request.
+///
+/// Search results are paginated with an opaque `next_page` token instead of object ids;
+/// pass it back as the `page` parameter of the search request to fetch the following page.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SearchList<T> {
+    pub data: Vec<T>,
+    pub has_more: bool,
+    pub next_page: Option<String>,
+    pub total_count: Option<u64>,
+    pub url: String,
+}
+
+impl<T> Default for SearchList<T> {
+    fn default() -> Self {
+        SearchList {
+            data: Vec::new(),
+            has_more: false,
+            next_page: None,
+            total_count: None,
+            url: String::new(),
+        }
+    }
+}
+
+impl<T: Clone> Clone for SearchList<T> {
+    fn clone(&self) -> Self {
+        SearchList {
+            data: self.data.clone(),
+            has_more: self.has_more,
+            next_page: self.next_page.clone(),
+            total_count: self.total_count,
+            url: self.url.clone(),
+        }
+    }
+}
+
+/// A single page of results from a v2 api list request.
+///
+/// Pages are linked by url rather than by object id; pass `next_page_url` to
+/// `V2List::get_next` to fetch the following page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct V2List<T> {
+    pub data: Vec<T>,
+    pub next_page_url: Option<String>,
+    pub previous_page_url: Option<String>,
+}
+
+impl<T: DeserializeOwned + Send + 'static> V2List<T> {
+    /// Fetches the page at `url`, e.g. the `next_page_url` of the previous page.
+    pub fn get_next(client: &Client, url: &str) -> Response<V2List<T>> {
+        match url.strip_prefix("/v2/") {
+            Some(path) => client.get_v2(path),
+            None => err(Error::Unsupported(
+                "URL for fetching additional data uses different API version",
+            )),
+        }
+    }
+}
+
+/// Set of key-value pairs that you can attach to an object.
+///
+/// Stripe allows up to 50 keys, with key names up to 40 characters and values up to
+/// 500 characters long. Metadata which exceeds these limits fails to serialize,
+/// so the request is never sent.
+///
+/// ```
+/// let mut metadata = stripe::metadata! { "order_id" => "6735", "channel" => "web" };
+/// metadata.unset("channel");
+/// assert_eq!(metadata["channel"], "");
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(transparent)]
+pub struct Metadata(HashMap<String, String>);
+
+impl Metadata {
+    pub const MAX_KEYS: usize = 50;
+    pub const MAX_KEY_LENGTH: usize = 40;
+    pub const MAX_VALUE_LENGTH: usize = 500;
+
+    pub fn new() -> Self {
+        Metadata(HashMap::new())
+    }
+
+    /// Sets the value of a key, returning its previous value.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.0.insert(key.into(), value.into())
+    }
+
+    /// Removes a key from the object the metadata is posted to (by sending an empty value).
+    pub fn unset(&mut self, key: impl Into<String>) {
+        self.0.insert(key.into(), String::new());
+    }
+
+    /// Checks the metadata against the limits enforced by Stripe.
+    pub fn validate(&self) -> Result<(), MetadataError> {
+        if self.0.len() > Metadata::MAX_KEYS {
+            return Err(MetadataError::TooManyKeys(self.0.len()));
+        }
+        for (key, value) in &self.0 {
+            if key.chars().count() > Metadata::MAX_KEY_LENGTH {
+                return Err(MetadataError::KeyTooLong(key.clone()));
+            }
+            if value.chars().count() > Metadata::MAX_VALUE_LENGTH {
+                return Err(MetadataError::ValueTooLong(key.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> HashMap<String, String> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for Metadata {
+    type Target = HashMap<String, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Metadata {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<HashMap<String, String>> for Metadata {
+    fn from(map: HashMap<String, String>) -> Self {
+        Metadata(map)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> std::iter::FromIterator<(K, V)> for Metadata {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Metadata(iter.into_iter().map(|(k, v)| (k.into(), v.into())).collect())
+    }
+}
+
+impl IntoIterator for Metadata {
+    type Item = (String, String);
+    type IntoIter = std::collections::hash_map::IntoIter<String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Metadata {
+    type Item = (&'a String, &'a String);
+    type IntoIter = std::collections::hash_map::Iter<'a, String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl serde::Serialize for Metadata {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.validate().map_err(serde::ser::Error::custom)?;
+        self.0.serialize(serializer)
+    }
+}
+
+/// Creates a `Metadata` from a list of `key => value` pairs.
+#[macro_export]
+macro_rules! metadata {
+    () => {
+        $crate::Metadata::new()
+    };
+    ($($key:expr => $value:expr),+ $(,)?) => {{
+        let mut metadata = $crate::Metadata::new();
+        $(metadata.insert($key, $value);)+
+        metadata
+    }};
+}
+
+/// Implements a chainable setter for each listed field of a parameter struct.
+///
+/// Optional fields are listed with the type inside their `Option`, or as `impl Into<T>` for
+/// setters which accept anything convertible, e.g. `CreateCheckoutSession::new().success_url(url)`
+/// with either a `&str` or a `String`. Fields which aren't optional are listed with `=` instead
+/// of `:`, and their setters assign the value as is.
+macro_rules! impl_setters {
+    ($params:ident<$lt:lifetime> { $($fields:tt)* }) => {
+        impl<$lt> $params<$lt> {
+            $crate::params::impl_setters!(@setters $($fields)*);
+        }
+    };
+    (@setters) => {};
+    (@setters $field:ident = $ty:ty, $($rest:tt)*) => {
+        pub fn $field(mut self, $field: $ty) -> Self {
+            self.$field = $field;
+            self
+        }
+        $crate::params::impl_setters!(@setters $($rest)*);
+    };
+    (@setters $field:ident: impl Into<$ty:ty>, $($rest:tt)*) => {
+        pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+            self.$field = Some($field.into());
+            self
+        }
+        $crate::params::impl_setters!(@setters $($rest)*);
+    };
+    (@setters $field:ident: $ty:ty, $($rest:tt)*) => {
+        pub fn $field(mut self, $field: $ty) -> Self {
+            self.$field = Some($field);
+            self
+        }
+        $crate::params::impl_setters!(@setters $($rest)*);
+    };
+}
+pub(crate) use impl_setters;
+
+/// Text shown on a customer's bank or card statement, checked against Stripe's rules when constructed.
+///
+/// A full descriptor must be 5 to 22 characters long, while a suffix (which is appended to the
+/// account's prefix) may be as short as a single character.
+/// Both must contain at least one letter and none of `<`, `>`, `\`, `'`, `"` or `*`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct StatementDescriptor(String);
+
+impl StatementDescriptor {
+    pub const MIN_LENGTH: usize = 5;
+    pub const MAX_LENGTH: usize = 22;
+
+    /// Creates a complete statement descriptor, e.g. for `statement_descriptor`.
+    pub fn new(descriptor: impl Into<String>) -> Result<Self, StatementDescriptorError> {
+        StatementDescriptor::validated(descriptor.into(), StatementDescriptor::MIN_LENGTH)
+    }
+
+    /// Creates a descriptor suffix, e.g. for `statement_descriptor_suffix`.
+    pub fn suffix(descriptor: impl Into<String>) -> Result<Self, StatementDescriptorError> {
+        StatementDescriptor::validated(descriptor.into(), 1)
+    }
+
+    fn validated(descriptor: String, min_length: usize) -> Result<Self, StatementDescriptorError> {
+        let len = descriptor.chars().count();
+        if len < min_length {
+            return Err(StatementDescriptorError::TooShort(len));
+        }
+        if len > StatementDescriptor::MAX_LENGTH {
+            return Err(StatementDescriptorError::TooLong(len));
+        }
+        if let Some(c) =
+            descriptor.chars().find(|c| matches!(c, '<' | '>' | '\\' | '\'' | '"' | '*'))
+        {
+            return Err(StatementDescriptorError::InvalidCharacter(c));
+        }
+        if !descriptor.chars().any(char::is_alphabetic) {
+            return Err(StatementDescriptorError::NoLetters);
+        }
+        Ok(StatementDescriptor(descriptor))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for StatementDescriptor {
+    type Err = StatementDescriptorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StatementDescriptor::new(s)
+    }
+}
+
+impl AsRef<str> for StatementDescriptor {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for StatementDescriptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for StatementDescriptor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        StatementDescriptor::suffix(s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A point in time, measured in seconds since the Unix epoch.
+///
+/// With the `chrono` feature enabled it converts to and from `chrono::DateTime<Utc>`,
+/// and with the `time` feature to and from `time::OffsetDateTime`.
+#[derive(
+    Copy, Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash,
+)]
+#[serde(transparent)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    pub fn from_secs(secs: i64) -> Self {
+        Timestamp(secs)
+    }
+
+    pub fn as_secs(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the timestamp as a `DateTime`, or `None` if it is out of range.
+    #[cfg(feature = "chrono")]
+    pub fn to_datetime(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::TimeZone::timestamp_opt(&chrono::Utc, self.0, 0).single()
+    }
+
+    /// Returns the timestamp as an `OffsetDateTime` in UTC, or `None` if it is out of range.
+    #[cfg(feature = "time")]
+    pub fn to_offset_datetime(self) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::from_unix_timestamp(self.0).ok()
+    }
+}
+
+impl From<i64> for Timestamp {
+    fn from(secs: i64) -> Self {
+        Timestamp(secs)
+    }
+}
+
+impl From<Timestamp> for i64 {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        Timestamp(datetime.timestamp())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        Timestamp(datetime.unix_timestamp())
+    }
+}
+
+impl std::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -292,6 +889,43 @@ pub enum IdOrCreate<'a, T> {
     Create(&'a T),
 }
 
+/// A field of an update request which can be left unchanged, set, or cleared.
+///
+/// Stripe clears a nullable field when it is sent as an empty string,
+/// which can't be expressed with an `Option`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum UpdateField<T> {
+    /// Leave the field unchanged (it is omitted from the request).
+    #[default]
+    Keep,
+    /// Set the field to a new value.
+    Set(T),
+    /// Clear the field's current value.
+    Clear,
+}
+
+impl<T> UpdateField<T> {
+    pub fn is_keep(&self) -> bool {
+        matches!(self, UpdateField::Keep)
+    }
+}
+
+impl<T> From<T> for UpdateField<T> {
+    fn from(value: T) -> Self {
+        UpdateField::Set(value)
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for UpdateField<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            UpdateField::Keep => serializer.serialize_none(),
+            UpdateField::Set(value) => value.serialize(serializer),
+            UpdateField::Clear => serializer.serialize_str(""),
+        }
+    }
+}
+
 // NOTE: Only intended to handle conversion from ASCII CamelCase to SnakeCase
 //   This function is used to convert static Rust identifiers to snakecase
 // TODO: pub(crate) fn
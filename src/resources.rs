@@ -6,75 +6,116 @@ pub use self::types::*;
 
 // Core Resources
 mod balance;
+mod balance_ext;
 mod balance_transaction;
 mod balance_transaction_ext;
+mod cash_balance;
 mod charge;
 mod charge_ext;
+mod confirmation_token;
 mod customer;
+mod customer_cash_balance_transaction;
 mod customer_ext;
+mod customer_session;
 mod dispute;
+mod dispute_ext;
+mod exchange_rate;
 mod file;
 mod file_link;
+mod forwarding_request;
 mod mandate;
 mod payment_intent;
 mod payment_source;
 mod payout;
 mod payout_ext;
 mod platform_tax_fee;
+mod price;
 mod product;
 mod refund;
 mod reserve_transaction;
+mod setup_attempt;
 mod setup_intent;
+mod shipping_rate;
 mod tax_deducted_at_source;
 mod token;
 mod token_ext;
 pub use self::balance::*;
 pub use self::balance_transaction::*;
 pub use self::balance_transaction_ext::*;
+pub use self::cash_balance::*;
 pub use self::charge::*;
 pub use self::charge_ext::*;
+pub use self::confirmation_token::*;
 pub use self::customer::*;
+pub use self::customer_cash_balance_transaction::*;
 pub use self::customer_ext::*;
+pub use self::customer_session::*;
 pub use self::dispute::*;
+pub use self::dispute_ext::*;
+pub use self::exchange_rate::*;
 pub use self::file::*;
 pub use self::file_link::*;
+pub use self::forwarding_request::*;
 pub use self::mandate::*;
 pub use self::payment_intent::*;
 pub use self::payment_source::*;
 pub use self::payout::*;
 pub use self::payout_ext::*;
 pub use self::platform_tax_fee::*;
+pub use self::price::*;
 pub use self::product::*;
 pub use self::refund::*;
 pub use self::reserve_transaction::*;
+pub use self::setup_attempt::*;
 pub use self::setup_intent::*;
+pub use self::shipping_rate::*;
 pub use self::tax_deducted_at_source::*;
 pub use self::token::*;
 pub use self::token_ext::*;
 
 // Payment Methods
 mod alipay_account;
+mod apple_pay_domain;
+mod apple_pay_domain_ext;
 mod bank_account;
 mod bank_account_ext;
 mod card;
+mod card_ext;
 mod payment_method;
+mod payment_method_configuration;
+mod payment_method_domain;
 mod payment_method_ext;
 mod source;
 mod source_ext;
+mod source_transaction;
 pub use self::alipay_account::*;
+pub use self::apple_pay_domain::*;
+pub use self::apple_pay_domain_ext::*;
 pub use self::bank_account::*;
 pub use self::bank_account_ext::*;
 pub use self::card::*;
+pub use self::card_ext::*;
 pub use self::payment_method::*;
+pub use self::payment_method_configuration::*;
+pub use self::payment_method_domain::*;
 pub use self::payment_method_ext::*;
 pub use self::source::*;
 pub use self::source_ext::*;
+pub use self::source_transaction::*;
 
 // Events
 #[cfg(feature = "events")]
 mod event;
 #[cfg(feature = "events")]
+mod thin_event;
+#[cfg(feature = "events")]
 pub use self::event::*;
+#[cfg(feature = "events")]
+pub use self::thin_event::*;
+#[cfg(feature = "webhook-events")]
+mod webhook_processor;
+#[cfg(feature = "webhook-events")]
+pub use self::webhook_processor::*;
 
 // Checkout
 #[cfg(feature = "checkout")]
@@ -92,6 +133,16 @@ pub use self::item::*;
 
 // Billing
 #[cfg(feature = "billing")]
+mod billing_alert;
+#[cfg(feature = "billing")]
+mod billing_meter;
+#[cfg(feature = "billing")]
+mod billing_meter_event;
+#[cfg(feature = "billing")]
+mod billing_meter_event_adjustment;
+#[cfg(feature = "billing")]
+mod billing_meter_event_summary;
+#[cfg(feature = "billing")]
 mod billing_portal_session;
 #[cfg(feature = "billing")]
 mod billing_portal_session_ext;
@@ -100,10 +151,16 @@ mod coupon;
 #[cfg(feature = "billing")]
 mod discount;
 #[cfg(feature = "billing")]
+mod entitlements_active_entitlement;
+#[cfg(feature = "billing")]
+mod entitlements_feature;
+#[cfg(feature = "billing")]
 mod invoice;
 #[cfg(feature = "billing")]
 mod invoice_ext;
 #[cfg(feature = "billing")]
+mod invoice_rendering_template;
+#[cfg(feature = "billing")]
 mod invoiceitem;
 #[cfg(feature = "billing")]
 mod line_item;
@@ -112,7 +169,7 @@ mod line_item_ext;
 #[cfg(feature = "billing")]
 mod plan;
 #[cfg(feature = "billing")]
-mod price;
+mod product_feature;
 #[cfg(feature = "billing")]
 mod subscription;
 #[cfg(feature = "billing")]
@@ -126,6 +183,18 @@ mod tax_id;
 #[cfg(feature = "billing")]
 mod tax_rate;
 #[cfg(feature = "billing")]
+mod test_helpers_test_clock;
+#[cfg(feature = "billing")]
+pub use self::billing_alert::*;
+#[cfg(feature = "billing")]
+pub use self::billing_meter::*;
+#[cfg(feature = "billing")]
+pub use self::billing_meter_event::*;
+#[cfg(feature = "billing")]
+pub use self::billing_meter_event_adjustment::*;
+#[cfg(feature = "billing")]
+pub use self::billing_meter_event_summary::*;
+#[cfg(feature = "billing")]
 pub use self::billing_portal_session::*;
 #[cfg(feature = "billing")]
 pub use self::billing_portal_session_ext::*;
@@ -134,10 +203,16 @@ pub use self::coupon::*;
 #[cfg(feature = "billing")]
 pub use self::discount::*;
 #[cfg(feature = "billing")]
+pub use self::entitlements_active_entitlement::*;
+#[cfg(feature = "billing")]
+pub use self::entitlements_feature::*;
+#[cfg(feature = "billing")]
 pub use self::invoice::*;
 #[cfg(feature = "billing")]
 pub use self::invoice_ext::*;
 #[cfg(feature = "billing")]
+pub use self::invoice_rendering_template::*;
+#[cfg(feature = "billing")]
 pub use self::invoiceitem::*;
 #[cfg(feature = "billing")]
 pub use self::line_item::*;
@@ -146,7 +221,7 @@ pub use self::line_item_ext::*;
 #[cfg(feature = "billing")]
 pub use self::plan::*;
 #[cfg(feature = "billing")]
-pub use self::price::*;
+pub use self::product_feature::*;
 #[cfg(feature = "billing")]
 pub use self::subscription::*;
 #[cfg(feature = "billing")]
@@ -159,21 +234,37 @@ pub use self::subscription_schedule::*;
 pub use self::tax_id::*;
 #[cfg(feature = "billing")]
 pub use self::tax_rate::*;
+#[cfg(feature = "billing")]
+pub use self::test_helpers_test_clock::*;
 
 // Connect
 #[cfg(feature = "connect")]
 mod account;
 #[cfg(feature = "connect")]
+mod account_ext;
+#[cfg(feature = "connect")]
 mod account_link;
 #[cfg(feature = "connect")]
+mod account_session;
+#[cfg(feature = "connect")]
 mod application;
 #[cfg(feature = "connect")]
 mod application_fee;
 #[cfg(feature = "connect")]
+mod capability;
+#[cfg(feature = "connect")]
 mod connect_collection_transfer;
 #[cfg(feature = "connect")]
+mod country_spec;
+#[cfg(feature = "connect")]
 mod fee_refund;
 #[cfg(feature = "connect")]
+mod login_link;
+#[cfg(feature = "connect")]
+mod login_link_ext;
+#[cfg(feature = "connect")]
+mod oauth;
+#[cfg(feature = "connect")]
 mod person;
 #[cfg(feature = "connect")]
 mod recipient;
@@ -182,20 +273,34 @@ mod topup;
 #[cfg(feature = "connect")]
 mod transfer;
 #[cfg(feature = "connect")]
+mod transfer_ext;
+#[cfg(feature = "connect")]
 mod transfer_reversal;
 #[cfg(feature = "connect")]
 pub use self::account::*;
 #[cfg(feature = "connect")]
+pub use self::account_ext::*;
+#[cfg(feature = "connect")]
 pub use self::account_link::*;
 #[cfg(feature = "connect")]
+pub use self::account_session::*;
+#[cfg(feature = "connect")]
 pub use self::application::*;
 #[cfg(feature = "connect")]
 pub use self::application_fee::*;
 #[cfg(feature = "connect")]
+pub use self::capability::*;
+#[cfg(feature = "connect")]
 pub use self::connect_collection_transfer::*;
 #[cfg(feature = "connect")]
+pub use self::country_spec::*;
+#[cfg(feature = "connect")]
 pub use self::fee_refund::*;
 #[cfg(feature = "connect")]
+pub use self::login_link::*;
+#[cfg(feature = "connect")]
+pub use self::oauth::*;
+#[cfg(feature = "connect")]
 pub use self::person::*;
 #[cfg(feature = "connect")]
 pub use self::recipient::*;
@@ -280,6 +385,16 @@ pub use self::order_return::*;
 #[cfg(feature = "orders")]
 pub use self::sku::*;
 
+// Tax
+#[cfg(feature = "tax")]
+mod tax_registration;
+#[cfg(feature = "tax")]
+mod tax_settings;
+#[cfg(feature = "tax")]
+pub use self::tax_registration::*;
+#[cfg(feature = "tax")]
+pub use self::tax_settings::*;
+
 #[cfg(feature = "sigma")]
 mod scheduled_query_run;
 #[cfg(feature = "sigma")]
@@ -287,14 +402,24 @@ pub use self::scheduled_query_run::*;
 
 // Not-yet-implemented feature flags
 #[cfg(feature = "webhook-endpoints")]
+mod event_destination;
+#[cfg(feature = "webhook-endpoints")]
 mod webhook_endpoint;
 #[cfg(feature = "webhook-endpoints")]
 mod webhook_endpoint_ext;
 #[cfg(feature = "webhook-endpoints")]
+pub use self::event_destination::*;
+#[cfg(feature = "webhook-endpoints")]
 pub use self::webhook_endpoint::*;
 #[cfg(feature = "webhook-endpoints")]
 pub use self::webhook_endpoint_ext::*;
 
+// Test Helpers
+#[cfg(feature = "test-helpers")]
+mod test_helpers;
+#[cfg(feature = "test-helpers")]
+pub use self::test_helpers::*;
+
 // Fallback types
 #[cfg(not(feature = "full"))]
 mod placeholders;
@@ -1036,6 +1036,11 @@ pub enum AccountRequirementsErrorCode {
     VerificationFailedKeyedMatch,
     VerificationFailedNameMatch,
     VerificationFailedOther,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl AccountRequirementsErrorCode {
@@ -1149,6 +1154,7 @@ impl AccountRequirementsErrorCode {
                 "verification_failed_name_match"
             }
             AccountRequirementsErrorCode::VerificationFailedOther => "verification_failed_other",
+            AccountRequirementsErrorCode::Other => "other",
         }
     }
 }
@@ -1172,6 +1178,11 @@ pub enum AccountType {
     Custom,
     Express,
     Standard,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl AccountType {
@@ -1180,6 +1191,7 @@ impl AccountType {
             AccountType::Custom => "custom",
             AccountType::Express => "express",
             AccountType::Standard => "standard",
+            AccountType::Other => "other",
         }
     }
 }
@@ -1201,16 +1213,26 @@ impl std::fmt::Display for AccountType {
 #[serde(rename_all = "snake_case")]
 pub enum CapabilityStatus {
     Active,
+    Disabled,
     Inactive,
     Pending,
+    Unrequested,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CapabilityStatus {
     pub fn as_str(self) -> &'static str {
         match self {
             CapabilityStatus::Active => "active",
+            CapabilityStatus::Disabled => "disabled",
             CapabilityStatus::Inactive => "inactive",
             CapabilityStatus::Pending => "pending",
+            CapabilityStatus::Unrequested => "unrequested",
+            CapabilityStatus::Other => "other",
         }
     }
 }
@@ -1246,6 +1268,11 @@ pub enum CompanyParamsStructure {
     TaxExemptGovernmentInstrumentality,
     UnincorporatedAssociation,
     UnincorporatedNonProfit,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CompanyParamsStructure {
@@ -1268,6 +1295,7 @@ impl CompanyParamsStructure {
             }
             CompanyParamsStructure::UnincorporatedAssociation => "unincorporated_association",
             CompanyParamsStructure::UnincorporatedNonProfit => "unincorporated_non_profit",
+            CompanyParamsStructure::Other => "other",
         }
     }
 }
@@ -1303,6 +1331,11 @@ pub enum CompanyStructure {
     TaxExemptGovernmentInstrumentality,
     UnincorporatedAssociation,
     UnincorporatedNonProfit,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CompanyStructure {
@@ -1325,6 +1358,7 @@ impl CompanyStructure {
             }
             CompanyStructure::UnincorporatedAssociation => "unincorporated_association",
             CompanyStructure::UnincorporatedNonProfit => "unincorporated_non_profit",
+            CompanyStructure::Other => "other",
         }
     }
 }
@@ -1355,6 +1389,11 @@ pub enum RequestedCapability {
     #[serde(rename = "tax_reporting_us_1099_misc")]
     TaxReportingUs1099Misc,
     Transfers,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl RequestedCapability {
@@ -1368,6 +1407,7 @@ impl RequestedCapability {
             RequestedCapability::TaxReportingUs1099K => "tax_reporting_us_1099_k",
             RequestedCapability::TaxReportingUs1099Misc => "tax_reporting_us_1099_misc",
             RequestedCapability::Transfers => "transfers",
+            RequestedCapability::Other => "other",
         }
     }
 }
@@ -1392,6 +1432,11 @@ pub enum TransferScheduleInterval {
     Manual,
     Monthly,
     Weekly,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TransferScheduleInterval {
@@ -1401,6 +1446,7 @@ impl TransferScheduleInterval {
             TransferScheduleInterval::Manual => "manual",
             TransferScheduleInterval::Monthly => "monthly",
             TransferScheduleInterval::Weekly => "weekly",
+            TransferScheduleInterval::Other => "other",
         }
     }
 }
@@ -0,0 +1,161 @@
+use crate::params::Timestamp;
+// N.B. `person` also defines an `AccountRequirementsError`, so the glob re-export is ambiguous.
+use crate::resources::account::AccountRequirementsError;
+use crate::resources::{Account, AccountRequirements};
+use serde_derive::{Deserialize, Serialize};
+
+impl Account {
+    /// Summarizes what Stripe still needs before the account is fully enabled.
+    ///
+    /// Retrieve the account again (or listen for `account.updated`) to poll for changes.
+    pub fn requirements_summary(&self) -> Requirements {
+        self.requirements.as_ref().map(Requirements::from).unwrap_or_default()
+    }
+}
+
+/// The outstanding requirements of an account, with missing lists treated as empty.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Requirements {
+    /// The date the fields in `currently_due` must be collected by to keep payouts enabled for the account.
+    pub current_deadline: Option<Timestamp>,
+
+    /// The fields that need to be collected to keep the account enabled.
+    pub currently_due: Vec<String>,
+
+    /// If the account is disabled, why it can't create charges or receive payouts.
+    pub disabled_reason: Option<AccountDisabledReason>,
+
+    /// The fields that need to be collected again because validation or verification failed.
+    pub errors: Vec<AccountRequirementsError>,
+
+    /// The fields that need to be collected assuming all volume thresholds are reached.
+    pub eventually_due: Vec<String>,
+
+    /// The fields that weren't collected by the `current_deadline`.
+    pub past_due: Vec<String>,
+
+    /// Fields that may become required depending on the results of verification or review.
+    pub pending_verification: Vec<String>,
+}
+
+impl Requirements {
+    /// Whether the account is enabled and nothing is currently due.
+    ///
+    /// Fields which are only `eventually_due` do not need to be collected yet.
+    pub fn is_complete(&self) -> bool {
+        self.disabled_reason.is_none()
+            && self.currently_due.is_empty()
+            && self.past_due.is_empty()
+            && self.errors.is_empty()
+    }
+}
+
+impl From<&AccountRequirements> for Requirements {
+    fn from(requirements: &AccountRequirements) -> Self {
+        Requirements {
+            current_deadline: requirements.current_deadline,
+            currently_due: requirements.currently_due.clone().unwrap_or_default(),
+            disabled_reason: requirements
+                .disabled_reason
+                .as_deref()
+                .map(AccountDisabledReason::from),
+            errors: requirements.errors.clone().unwrap_or_default(),
+            eventually_due: requirements.eventually_due.clone().unwrap_or_default(),
+            past_due: requirements.past_due.clone().unwrap_or_default(),
+            pending_verification: requirements.pending_verification.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// An enum representing the possible values of an `AccountRequirements`'s `disabled_reason` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub enum AccountDisabledReason {
+    #[serde(rename = "action_required.requested_capabilities")]
+    ActionRequiredRequestedCapabilities,
+    #[serde(rename = "listed")]
+    Listed,
+    #[serde(rename = "platform_paused")]
+    PlatformPaused,
+    #[serde(rename = "rejected.fraud")]
+    RejectedFraud,
+    #[serde(rename = "rejected.incomplete_verification")]
+    RejectedIncompleteVerification,
+    #[serde(rename = "rejected.listed")]
+    RejectedListed,
+    #[serde(rename = "rejected.other")]
+    RejectedOther,
+    #[serde(rename = "rejected.terms_of_service")]
+    RejectedTermsOfService,
+    #[serde(rename = "requirements.past_due")]
+    RequirementsPastDue,
+    #[serde(rename = "requirements.pending_verification")]
+    RequirementsPendingVerification,
+    #[serde(rename = "under_review")]
+    UnderReview,
+
+    /// Stripe's catch-all reason, which unknown reasons are also read as.
+    #[serde(rename = "other", other)]
+    Other,
+}
+
+impl AccountDisabledReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AccountDisabledReason::ActionRequiredRequestedCapabilities => {
+                "action_required.requested_capabilities"
+            }
+            AccountDisabledReason::Listed => "listed",
+            AccountDisabledReason::PlatformPaused => "platform_paused",
+            AccountDisabledReason::RejectedFraud => "rejected.fraud",
+            AccountDisabledReason::RejectedIncompleteVerification => {
+                "rejected.incomplete_verification"
+            }
+            AccountDisabledReason::RejectedListed => "rejected.listed",
+            AccountDisabledReason::RejectedOther => "rejected.other",
+            AccountDisabledReason::RejectedTermsOfService => "rejected.terms_of_service",
+            AccountDisabledReason::RequirementsPastDue => "requirements.past_due",
+            AccountDisabledReason::RequirementsPendingVerification => {
+                "requirements.pending_verification"
+            }
+            AccountDisabledReason::UnderReview => "under_review",
+            AccountDisabledReason::Other => "other",
+        }
+    }
+}
+
+impl From<&str> for AccountDisabledReason {
+    fn from(reason: &str) -> Self {
+        match reason {
+            "action_required.requested_capabilities" => {
+                AccountDisabledReason::ActionRequiredRequestedCapabilities
+            }
+            "listed" => AccountDisabledReason::Listed,
+            "platform_paused" => AccountDisabledReason::PlatformPaused,
+            "rejected.fraud" => AccountDisabledReason::RejectedFraud,
+            "rejected.incomplete_verification" => {
+                AccountDisabledReason::RejectedIncompleteVerification
+            }
+            "rejected.listed" => AccountDisabledReason::RejectedListed,
+            "rejected.other" => AccountDisabledReason::RejectedOther,
+            "rejected.terms_of_service" => AccountDisabledReason::RejectedTermsOfService,
+            "requirements.past_due" => AccountDisabledReason::RequirementsPastDue,
+            "requirements.pending_verification" => {
+                AccountDisabledReason::RequirementsPendingVerification
+            }
+            "under_review" => AccountDisabledReason::UnderReview,
+            _ => AccountDisabledReason::Other,
+        }
+    }
+}
+
+impl AsRef<str> for AccountDisabledReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for AccountDisabledReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -91,6 +91,11 @@ impl<'a> CreateAccountLink<'a> {
 pub enum AccountLinkCollect {
     CurrentlyDue,
     EventuallyDue,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl AccountLinkCollect {
@@ -98,6 +103,7 @@ impl AccountLinkCollect {
         match self {
             AccountLinkCollect::CurrentlyDue => "currently_due",
             AccountLinkCollect::EventuallyDue => "eventually_due",
+            AccountLinkCollect::Other => "other",
         }
     }
 }
@@ -121,6 +127,11 @@ pub enum AccountLinkType {
     CustomAccountUpdate,
     CustomAccountVerification,
     AccountOnboarding,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl AccountLinkType {
@@ -129,6 +140,7 @@ impl AccountLinkType {
             AccountLinkType::CustomAccountUpdate => "custom_account_update",
             AccountLinkType::CustomAccountVerification => "custom_account_verification",
             AccountLinkType::AccountOnboarding => "account_onboarding",
+            AccountLinkType::Other => "other",
         }
     }
 }
@@ -0,0 +1,97 @@
+use crate::config::{Client, Response};
+use crate::ids::ApplePayDomainId;
+use crate::params::{Deleted, Expand, List};
+use crate::resources::ApplePayDomain;
+use serde_derive::Serialize;
+
+impl ApplePayDomain {
+    /// List apple pay domains.
+    ///
+    /// For more details see [https://stripe.com/docs/apple-pay/web](https://stripe.com/docs/apple-pay/web).
+    pub fn list(
+        client: &Client,
+        params: ListApplePayDomains<'_>,
+    ) -> Response<List<ApplePayDomain>> {
+        client.get_query("/apple_pay/domains", &params)
+    }
+
+    /// Create an apple pay domain.
+    ///
+    /// For more details see [https://stripe.com/docs/apple-pay/web](https://stripe.com/docs/apple-pay/web).
+    pub fn create(client: &Client, params: CreateApplePayDomain<'_>) -> Response<ApplePayDomain> {
+        client.post_form("/apple_pay/domains", &params)
+    }
+
+    /// Retrieve an apple pay domain.
+    pub fn retrieve(
+        client: &Client,
+        id: &ApplePayDomainId,
+        expand: &[&str],
+    ) -> Response<ApplePayDomain> {
+        client.get_query(&format!("/apple_pay/domains/{}", id), &Expand { expand })
+    }
+
+    /// Delete an apple pay domain.
+    pub fn delete(client: &Client, id: &ApplePayDomainId) -> Response<Deleted<ApplePayDomainId>> {
+        client.delete(&format!("/apple_pay/domains/{}", id))
+    }
+}
+
+/// The parameters for `ApplePayDomain::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateApplePayDomain<'a> {
+    pub domain_name: &'a str,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CreateApplePayDomain<'a> {
+    pub fn new(domain_name: &'a str) -> Self {
+        CreateApplePayDomain { domain_name, expand: Default::default() }
+    }
+}
+
+/// The parameters for `ApplePayDomain::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListApplePayDomains<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<ApplePayDomainId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<ApplePayDomainId>,
+}
+
+impl<'a> ListApplePayDomains<'a> {
+    pub fn new() -> Self {
+        ListApplePayDomains {
+            domain_name: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::config::{Client, Response};
+use crate::params::Expand;
+use crate::resources::Balance;
+
+impl Balance {
+    /// Retrieves the current account balance.
+    ///
+    /// To retrieve a connected account's balance, make the request on its behalf,
+    /// e.g. `Balance::retrieve(&client.with_options(options), &[])` with `options.stripe_account` set.
+    ///
+    /// For more details see [https://stripe.com/docs/api/balance/balance_retrieve](https://stripe.com/docs/api/balance/balance_retrieve).
+    pub fn retrieve(client: &Client, expand: &[&str]) -> Response<Balance> {
+        client.get_query("/balance", &Expand { expand })
+    }
+}
@@ -72,7 +72,7 @@ pub struct BalanceTransaction {
     /// Either `available` or `pending`.
     pub status: BalanceTransactionStatus,
 
-    /// Transaction type: `adjustment`, `advance`, `advance_funding`, `application_fee`, `application_fee_refund`, `charge`, `connect_collection_transfer`, `issuing_authorization_hold`, `issuing_authorization_release`, `issuing_transaction`, `payment`, `payment_failure_refund`, `payment_refund`, `payout`, `payout_cancel`, `payout_failure`, `refund`, `refund_failure`, `reserve_transaction`, `reserved_funds`, `stripe_fee`, `stripe_fx_fee`, `tax_fee`, `topup`, `topup_reversal`, `transfer`, `transfer_cancel`, `transfer_failure`, or `transfer_refund`.
+    /// Transaction type, e.g. `charge`, `refund`, `payout` or `stripe_fee`.
     ///
     /// [Learn more](https://stripe.com/docs/reports/balance-transaction-types) about balance transaction types and what they represent.
     /// If you are looking to classify transactions for accounting purposes, you might want to consider `reporting_category` instead.
@@ -187,11 +187,9 @@ pub struct ListBalanceTransactions<'a> {
     pub starting_after: Option<BalanceTransactionId>,
 
     /// Only returns transactions of the given type.
-    ///
-    /// One of: `charge`, `refund`, `adjustment`, `application_fee`, `application_fee_refund`, `transfer`, `payment`, `payout`, `payout_failure`, `stripe_fee`, or `network_cost`.
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<&'a str>,
+    pub type_: Option<BalanceTransactionType>,
 }
 
 impl<'a> ListBalanceTransactions<'a> {
@@ -241,19 +239,32 @@ pub enum BalanceTransactionType {
     Adjustment,
     Advance,
     AdvanceFunding,
+    AnticipationRepayment,
     ApplicationFee,
     ApplicationFeeRefund,
     Charge,
+    ClimateOrderPurchase,
+    ClimateOrderRefund,
     ConnectCollectionTransfer,
+    Contribution,
     IssuingAuthorizationHold,
     IssuingAuthorizationRelease,
+    IssuingDispute,
     IssuingTransaction,
+    ObligationOutbound,
+    ObligationReversalInbound,
     Payment,
     PaymentFailureRefund,
+    PaymentNetworkReserveHold,
+    PaymentNetworkReserveRelease,
     PaymentRefund,
+    PaymentReversal,
+    PaymentUnreconciled,
     Payout,
     PayoutCancel,
     PayoutFailure,
+    PayoutMinimumBalanceHold,
+    PayoutMinimumBalanceRelease,
     Refund,
     RefundFailure,
     ReserveTransaction,
@@ -267,6 +278,11 @@ pub enum BalanceTransactionType {
     TransferCancel,
     TransferFailure,
     TransferRefund,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl BalanceTransactionType {
@@ -275,19 +291,34 @@ impl BalanceTransactionType {
             BalanceTransactionType::Adjustment => "adjustment",
             BalanceTransactionType::Advance => "advance",
             BalanceTransactionType::AdvanceFunding => "advance_funding",
+            BalanceTransactionType::AnticipationRepayment => "anticipation_repayment",
             BalanceTransactionType::ApplicationFee => "application_fee",
             BalanceTransactionType::ApplicationFeeRefund => "application_fee_refund",
             BalanceTransactionType::Charge => "charge",
+            BalanceTransactionType::ClimateOrderPurchase => "climate_order_purchase",
+            BalanceTransactionType::ClimateOrderRefund => "climate_order_refund",
             BalanceTransactionType::ConnectCollectionTransfer => "connect_collection_transfer",
+            BalanceTransactionType::Contribution => "contribution",
             BalanceTransactionType::IssuingAuthorizationHold => "issuing_authorization_hold",
             BalanceTransactionType::IssuingAuthorizationRelease => "issuing_authorization_release",
+            BalanceTransactionType::IssuingDispute => "issuing_dispute",
             BalanceTransactionType::IssuingTransaction => "issuing_transaction",
+            BalanceTransactionType::ObligationOutbound => "obligation_outbound",
+            BalanceTransactionType::ObligationReversalInbound => "obligation_reversal_inbound",
             BalanceTransactionType::Payment => "payment",
             BalanceTransactionType::PaymentFailureRefund => "payment_failure_refund",
+            BalanceTransactionType::PaymentNetworkReserveHold => "payment_network_reserve_hold",
+            BalanceTransactionType::PaymentNetworkReserveRelease => {
+                "payment_network_reserve_release"
+            }
             BalanceTransactionType::PaymentRefund => "payment_refund",
+            BalanceTransactionType::PaymentReversal => "payment_reversal",
+            BalanceTransactionType::PaymentUnreconciled => "payment_unreconciled",
             BalanceTransactionType::Payout => "payout",
             BalanceTransactionType::PayoutCancel => "payout_cancel",
             BalanceTransactionType::PayoutFailure => "payout_failure",
+            BalanceTransactionType::PayoutMinimumBalanceHold => "payout_minimum_balance_hold",
+            BalanceTransactionType::PayoutMinimumBalanceRelease => "payout_minimum_balance_release",
             BalanceTransactionType::Refund => "refund",
             BalanceTransactionType::RefundFailure => "refund_failure",
             BalanceTransactionType::ReserveTransaction => "reserve_transaction",
@@ -301,6 +332,7 @@ impl BalanceTransactionType {
             BalanceTransactionType::TransferCancel => "transfer_cancel",
             BalanceTransactionType::TransferFailure => "transfer_failure",
             BalanceTransactionType::TransferRefund => "transfer_refund",
+            BalanceTransactionType::Other => "other",
         }
     }
 }
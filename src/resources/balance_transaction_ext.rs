@@ -1,8 +1,33 @@
 use crate::ids::BalanceTransactionSourceId;
 use crate::params::Object;
-use crate::resources::BalanceTransactionSource;
+use crate::resources::{BalanceTransaction, BalanceTransactionSource};
 use serde_derive::{Deserialize, Serialize};
 
+impl BalanceTransaction {
+    /// The total of the fees of the given type paid for this transaction.
+    pub fn fee_for(&self, fee_type: FeeType) -> i64 {
+        self.fee_details.iter().filter(|fee| fee.type_ == fee_type).map(|fee| fee.amount).sum()
+    }
+
+    /// The amount of the transaction after subtracting every fee in `fee_details`.
+    ///
+    /// This matches the `net` reported by Stripe unless the breakdown is incomplete, so comparing
+    /// the two catches fees a reconciliation would otherwise miss.
+    pub fn net_from_fee_details(&self) -> i64 {
+        self.amount - self.fee_details.iter().map(|fee| fee.amount).sum::<i64>()
+    }
+
+    /// Converts an amount of this transaction (e.g. its `amount`, `fee` or `net`) back into the
+    /// currency it was converted from, using `exchange_rate`.
+    ///
+    /// The result is rounded to the nearest unit, so it may be off by one from the amount which
+    /// was actually charged. Returns `None` if the transaction involved no currency conversion.
+    pub fn to_original_currency(&self, amount: i64) -> Option<i64> {
+        let exchange_rate = self.exchange_rate.filter(|rate| *rate > 0.0)?;
+        Some((amount as f64 / exchange_rate).round() as i64)
+    }
+}
+
 impl Object for BalanceTransactionSource {
     type Id = BalanceTransactionSourceId;
     fn id(&self) -> Self::Id {
@@ -56,6 +81,11 @@ impl Object for BalanceTransactionSource {
 pub enum BalanceTransactionStatus {
     Available,
     Pending,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl BalanceTransactionStatus {
@@ -63,6 +93,7 @@ impl BalanceTransactionStatus {
         match self {
             BalanceTransactionStatus::Available => "available",
             BalanceTransactionStatus::Pending => "pending",
+            BalanceTransactionStatus::Other => "other",
         }
     }
 }
@@ -84,16 +115,26 @@ impl std::fmt::Display for BalanceTransactionStatus {
 #[serde(rename_all = "snake_case")]
 pub enum FeeType {
     ApplicationFee,
+    PaymentMethodPassthroughFee,
     StripeFee,
     Tax,
+    WithheldTax,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl FeeType {
     pub fn as_str(self) -> &'static str {
         match self {
             FeeType::ApplicationFee => "application_fee",
+            FeeType::PaymentMethodPassthroughFee => "payment_method_passthrough_fee",
             FeeType::StripeFee => "stripe_fee",
             FeeType::Tax => "tax",
+            FeeType::WithheldTax => "withheld_tax",
+            FeeType::Other => "other",
         }
     }
 }
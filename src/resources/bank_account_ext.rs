@@ -9,6 +9,11 @@ pub enum BankAccountStatus {
     Validated,
     VerificationFailed,
     Verified,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl BankAccountStatus {
@@ -19,6 +24,7 @@ impl BankAccountStatus {
             BankAccountStatus::Validated => "validated",
             BankAccountStatus::VerificationFailed => "verification_failed",
             BankAccountStatus::Verified => "verified",
+            BankAccountStatus::Other => "other",
         }
     }
 }
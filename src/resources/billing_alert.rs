@@ -0,0 +1,348 @@
+use crate::config::{Client, Response};
+use crate::ids::{BillingAlertId, BillingMeterId, CustomerId};
+use crate::params::{Expand, Expandable, List, Object};
+use crate::resources::{BillingMeter, Customer};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "BillingAlert".
+///
+/// A billing alert is a resource that notifies you when a certain usage threshold on a meter is crossed.
+/// For example, you might create a billing alert to notify you when a certain user made 100 API requests.
+///
+/// For more details see [https://stripe.com/docs/api/billing/alert](https://stripe.com/docs/api/billing/alert).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingAlert {
+    /// Unique identifier for the object.
+    pub id: BillingAlertId,
+
+    /// Defines the type of the alert.
+    pub alert_type: BillingAlertType,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Status of the alert.
+    ///
+    /// This can be active, inactive or archived.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<BillingAlertStatus>,
+
+    /// Title of the alert.
+    pub title: String,
+
+    /// Encapsulates configuration of the alert to monitor usage on a specific meter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_threshold: Option<BillingAlertUsageThreshold>,
+}
+
+impl BillingAlert {
+    /// Lists billing active and inactive alerts.
+    pub fn list(client: &Client, params: ListBillingAlerts<'_>) -> Response<List<BillingAlert>> {
+        client.get_query("/billing/alerts", &params)
+    }
+
+    /// Creates a billing alert.
+    pub fn create(client: &Client, params: CreateBillingAlert<'_>) -> Response<BillingAlert> {
+        client.post_form("/billing/alerts", &params)
+    }
+
+    /// Retrieves a billing alert given an ID.
+    pub fn retrieve(
+        client: &Client,
+        id: &BillingAlertId,
+        expand: &[&str],
+    ) -> Response<BillingAlert> {
+        client.get_query(&format!("/billing/alerts/{}", id), &Expand { expand })
+    }
+
+    /// Reactivates this alert, allowing it to trigger again.
+    pub fn activate(client: &Client, id: &BillingAlertId) -> Response<BillingAlert> {
+        client.post(&format!("/billing/alerts/{}/activate", id))
+    }
+
+    /// Archives this alert, removing it from the list view and APIs.
+    ///
+    /// This is non-reversible.
+    pub fn archive(client: &Client, id: &BillingAlertId) -> Response<BillingAlert> {
+        client.post(&format!("/billing/alerts/{}/archive", id))
+    }
+
+    /// Deactivates this alert, preventing it from triggering.
+    pub fn deactivate(client: &Client, id: &BillingAlertId) -> Response<BillingAlert> {
+        client.post(&format!("/billing/alerts/{}/deactivate", id))
+    }
+}
+
+impl Object for BillingAlert {
+    type Id = BillingAlertId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing.alert"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingAlertUsageThreshold {
+    /// The filters allow limiting the scope of this usage alert.
+    ///
+    /// You can only specify up to one filter at this time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<BillingAlertUsageThresholdFilter>>,
+
+    /// The value at which this alert will trigger.
+    pub gte: i64,
+
+    /// The billing meter whose usage is monitored.
+    pub meter: Expandable<BillingMeter>,
+
+    /// Defines how the alert will behave.
+    pub recurrence: BillingAlertRecurrence,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingAlertUsageThresholdFilter {
+    /// Limit the scope of the alert to this customer ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Expandable<Customer>>,
+
+    #[serde(rename = "type")]
+    pub type_: BillingAlertFilterType,
+}
+
+/// The parameters for `BillingAlert::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingAlert<'a> {
+    /// The type of alert to create.
+    pub alert_type: BillingAlertType,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The title of the alert.
+    pub title: &'a str,
+
+    /// The configuration of the usage threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_threshold: Option<CreateBillingAlertUsageThreshold>,
+}
+
+impl<'a> CreateBillingAlert<'a> {
+    pub fn new(alert_type: BillingAlertType, title: &'a str) -> Self {
+        CreateBillingAlert {
+            alert_type,
+            expand: Default::default(),
+            title,
+            usage_threshold: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `BillingAlert::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListBillingAlerts<'a> {
+    /// Filter results to only include this type of alert.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_type: Option<BillingAlertType>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<BillingAlertId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Filter results to only include alerts with the given meter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meter: Option<BillingMeterId>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<BillingAlertId>,
+}
+
+impl<'a> ListBillingAlerts<'a> {
+    pub fn new() -> Self {
+        ListBillingAlerts {
+            alert_type: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            meter: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateBillingAlertUsageThreshold {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<CreateBillingAlertUsageThresholdFilters>>,
+
+    pub gte: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meter: Option<BillingMeterId>,
+
+    pub recurrence: BillingAlertRecurrence,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateBillingAlertUsageThresholdFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    #[serde(rename = "type")]
+    pub type_: BillingAlertFilterType,
+}
+
+/// An enum representing the possible values of an `BillingAlertUsageThresholdFilter`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingAlertFilterType {
+    Customer,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingAlertFilterType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingAlertFilterType::Customer => "customer",
+            BillingAlertFilterType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingAlertFilterType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingAlertFilterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `BillingAlertUsageThreshold`'s `recurrence` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingAlertRecurrence {
+    OneTime,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingAlertRecurrence {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingAlertRecurrence::OneTime => "one_time",
+            BillingAlertRecurrence::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingAlertRecurrence {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingAlertRecurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `BillingAlert`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingAlertStatus {
+    Active,
+    Archived,
+    Inactive,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingAlertStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingAlertStatus::Active => "active",
+            BillingAlertStatus::Archived => "archived",
+            BillingAlertStatus::Inactive => "inactive",
+            BillingAlertStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingAlertStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingAlertStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `BillingAlert`'s `alert_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingAlertType {
+    UsageThreshold,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingAlertType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingAlertType::UsageThreshold => "usage_threshold",
+            BillingAlertType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingAlertType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingAlertType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -0,0 +1,457 @@
+use crate::config::{Client, Response};
+use crate::ids::{BillingMeterEventSummaryId, BillingMeterId, CustomerId};
+use crate::params::{Expand, List, Object, Timestamp};
+use crate::resources::BillingMeterEventSummary;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "BillingMeter".
+///
+/// A billing meter is a resource that allows you to track usage of a particular event.
+/// For example, you might create a billing meter to track the number of API calls made by a particular user.
+/// You can then attach the billing meter to a price and attach the price to a subscription to charge the user for the number of API calls they make.
+///
+/// For more details see [https://stripe.com/docs/api/billing/meter](https://stripe.com/docs/api/billing/meter).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeter {
+    /// Unique identifier for the object.
+    pub id: BillingMeterId,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    pub customer_mapping: BillingMeterCustomerMapping,
+
+    pub default_aggregation: BillingMeterDefaultAggregation,
+
+    /// The meter's name.
+    pub display_name: String,
+
+    /// The name of the meter event to record usage for.
+    ///
+    /// Corresponds with the `event_name` field on meter events.
+    pub event_name: String,
+
+    /// The time window to pre-aggregate meter events for, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_time_window: Option<BillingMeterEventTimeWindow>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The meter's status.
+    pub status: BillingMeterStatus,
+
+    pub status_transitions: BillingMeterStatusTransitions,
+
+    /// Time at which the object was last updated.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub updated: Timestamp,
+
+    pub value_settings: BillingMeterValueSettings,
+}
+
+impl BillingMeter {
+    /// Retrieve a list of billing meters.
+    pub fn list(client: &Client, params: ListBillingMeters<'_>) -> Response<List<BillingMeter>> {
+        client.get_query("/billing/meters", &params)
+    }
+
+    /// Creates a billing meter.
+    pub fn create(client: &Client, params: CreateBillingMeter<'_>) -> Response<BillingMeter> {
+        client.post_form("/billing/meters", &params)
+    }
+
+    /// Retrieves a billing meter given an ID.
+    pub fn retrieve(
+        client: &Client,
+        id: &BillingMeterId,
+        expand: &[&str],
+    ) -> Response<BillingMeter> {
+        client.get_query(&format!("/billing/meters/{}", id), &Expand { expand })
+    }
+
+    /// Updates a billing meter.
+    pub fn update(
+        client: &Client,
+        id: &BillingMeterId,
+        params: UpdateBillingMeter<'_>,
+    ) -> Response<BillingMeter> {
+        client.post_form(&format!("/billing/meters/{}", id), &params)
+    }
+
+    /// When a meter is deactivated, no more meter events will be accepted for this meter.
+    ///
+    /// You can't attach a deactivated meter to a price.
+    pub fn deactivate(client: &Client, id: &BillingMeterId) -> Response<BillingMeter> {
+        client.post(&format!("/billing/meters/{}/deactivate", id))
+    }
+
+    /// When a meter is reactivated, events for this meter can be accepted and you can attach the meter to a price.
+    pub fn reactivate(client: &Client, id: &BillingMeterId) -> Response<BillingMeter> {
+        client.post(&format!("/billing/meters/{}/reactivate", id))
+    }
+
+    /// Retrieve a list of billing meter event summaries for a customer.
+    pub fn list_event_summaries(
+        client: &Client,
+        id: &BillingMeterId,
+        params: ListBillingMeterEventSummaries<'_>,
+    ) -> Response<List<BillingMeterEventSummary>> {
+        client.get_query(&format!("/billing/meters/{}/event_summaries", id), &params)
+    }
+}
+
+impl Object for BillingMeter {
+    type Id = BillingMeterId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing.meter"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterCustomerMapping {
+    /// The key in the meter event payload to use for mapping the event to a customer.
+    pub event_payload_key: String,
+
+    /// The method for mapping a meter event to a customer.
+    #[serde(rename = "type")]
+    pub type_: BillingMeterCustomerMappingType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterDefaultAggregation {
+    /// Specifies how events are aggregated.
+    pub formula: BillingMeterDefaultAggregationFormula,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterStatusTransitions {
+    /// The time the meter was deactivated, if any.
+    ///
+    /// Measured in seconds since epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivated_at: Option<Timestamp>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterValueSettings {
+    /// The key in the meter event payload to use as the value for this meter.
+    pub event_payload_key: String,
+}
+
+/// The parameters for `BillingMeter::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeter<'a> {
+    /// Fields that specify how to map a meter event to a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_mapping: Option<BillingMeterCustomerMapping>,
+
+    /// The default settings to aggregate a meter's events with.
+    pub default_aggregation: BillingMeterDefaultAggregation,
+
+    /// The meter's name.
+    pub display_name: &'a str,
+
+    /// The name of the meter event to record usage for.
+    ///
+    /// Corresponds with the `event_name` field on meter events.
+    pub event_name: &'a str,
+
+    /// The time window to pre-aggregate meter events for, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_time_window: Option<BillingMeterEventTimeWindow>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Fields that specify how to calculate a meter event's value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_settings: Option<BillingMeterValueSettings>,
+}
+
+impl<'a> CreateBillingMeter<'a> {
+    pub fn new(
+        default_aggregation: BillingMeterDefaultAggregation,
+        display_name: &'a str,
+        event_name: &'a str,
+    ) -> Self {
+        CreateBillingMeter {
+            customer_mapping: Default::default(),
+            default_aggregation,
+            display_name,
+            event_name,
+            event_time_window: Default::default(),
+            expand: Default::default(),
+            value_settings: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `BillingMeter::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListBillingMeters<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<BillingMeterId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<BillingMeterId>,
+
+    /// Filter results to only include meters with the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<BillingMeterStatus>,
+}
+
+impl<'a> ListBillingMeters<'a> {
+    pub fn new() -> Self {
+        ListBillingMeters {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `BillingMeter::list_event_summaries`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListBillingMeterEventSummaries<'a> {
+    /// The customer for which to fetch event summaries.
+    pub customer: CustomerId,
+
+    /// The timestamp from when to stop aggregating meter events (exclusive).
+    ///
+    /// Must be aligned with minute boundaries.
+    pub end_time: Timestamp,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<BillingMeterEventSummaryId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// The timestamp from when to start aggregating meter events (inclusive).
+    ///
+    /// Must be aligned with minute boundaries.
+    pub start_time: Timestamp,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<BillingMeterEventSummaryId>,
+
+    /// Specifies what granularity to use when generating event summaries.
+    ///
+    /// If not specified, a single event summary would be returned for the specified time range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_grouping_window: Option<BillingMeterEventTimeWindow>,
+}
+
+impl<'a> ListBillingMeterEventSummaries<'a> {
+    pub fn new(customer: CustomerId, start_time: Timestamp, end_time: Timestamp) -> Self {
+        ListBillingMeterEventSummaries {
+            customer,
+            end_time,
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            start_time,
+            starting_after: Default::default(),
+            value_grouping_window: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `BillingMeter::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateBillingMeter<'a> {
+    /// The meter's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> UpdateBillingMeter<'a> {
+    pub fn new() -> Self {
+        UpdateBillingMeter { display_name: Default::default(), expand: Default::default() }
+    }
+}
+
+/// An enum representing the possible values of an `BillingMeterCustomerMapping`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingMeterCustomerMappingType {
+    ById,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingMeterCustomerMappingType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingMeterCustomerMappingType::ById => "by_id",
+            BillingMeterCustomerMappingType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingMeterCustomerMappingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingMeterCustomerMappingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `BillingMeterDefaultAggregation`'s `formula` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingMeterDefaultAggregationFormula {
+    Count,
+    Last,
+    Sum,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingMeterDefaultAggregationFormula {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingMeterDefaultAggregationFormula::Count => "count",
+            BillingMeterDefaultAggregationFormula::Last => "last",
+            BillingMeterDefaultAggregationFormula::Sum => "sum",
+            BillingMeterDefaultAggregationFormula::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingMeterDefaultAggregationFormula {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingMeterDefaultAggregationFormula {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `BillingMeter`'s `event_time_window` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingMeterEventTimeWindow {
+    Day,
+    Hour,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingMeterEventTimeWindow {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingMeterEventTimeWindow::Day => "day",
+            BillingMeterEventTimeWindow::Hour => "hour",
+            BillingMeterEventTimeWindow::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingMeterEventTimeWindow {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingMeterEventTimeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `BillingMeter`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingMeterStatus {
+    Active,
+    Inactive,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingMeterStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingMeterStatus::Active => "active",
+            BillingMeterStatus::Inactive => "inactive",
+            BillingMeterStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingMeterStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingMeterStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::config::{Client, Response};
+use crate::params::{Expand, Timestamp};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "BillingMeterEvent".
+///
+/// A billing meter event represents a customer's usage of a product.
+/// Meter events are used to bill a customer based on their usage.
+/// Meter events are associated with billing meters, which define the shape of the event's payload and how those events are aggregated for billing.
+///
+/// For more details see [https://stripe.com/docs/api/billing/meter-event](https://stripe.com/docs/api/billing/meter-event).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterEvent {
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// The name of the meter event.
+    ///
+    /// Corresponds with the `event_name` field on a meter.
+    pub event_name: String,
+
+    /// A unique identifier for the event.
+    pub identifier: String,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The payload of the event.
+    ///
+    /// This contains the fields corresponding to a meter's `customer_mapping.event_payload_key` (default is `stripe_customer_id`) and `value_settings.event_payload_key` (default is `value`).
+    pub payload: HashMap<String, String>,
+
+    /// The timestamp passed in when creating the event.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub timestamp: Timestamp,
+}
+
+impl BillingMeterEvent {
+    /// Creates a billing meter event.
+    pub fn create(
+        client: &Client,
+        params: CreateBillingMeterEvent<'_>,
+    ) -> Response<BillingMeterEvent> {
+        client.post_form("/billing/meter_events", &params)
+    }
+}
+
+/// The parameters for `BillingMeterEvent::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeterEvent<'a> {
+    /// The name of the meter event.
+    ///
+    /// Corresponds with the `event_name` field on a meter.
+    pub event_name: &'a str,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A unique identifier for the event.
+    ///
+    /// If not provided, one will be generated.
+    /// We recommend using a globally unique identifier for this.
+    /// We'll enforce uniqueness within a rolling 24 hour period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<&'a str>,
+
+    /// The payload of the event.
+    ///
+    /// This must contain the fields corresponding to a meter's `customer_mapping.event_payload_key` (default is `stripe_customer_id`) and `value_settings.event_payload_key` (default is `value`).
+    /// Read more about the [payload](https://docs.stripe.com/billing/subscriptions/usage-based/recording-usage#payload-key-overrides).
+    pub payload: HashMap<String, String>,
+
+    /// The time of the event.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    /// Must be within the past 35 calendar days or up to 5 minutes in the future.
+    /// Defaults to current timestamp if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+}
+
+impl<'a> CreateBillingMeterEvent<'a> {
+    pub fn new(event_name: &'a str, payload: HashMap<String, String>) -> Self {
+        CreateBillingMeterEvent {
+            event_name,
+            expand: Default::default(),
+            identifier: Default::default(),
+            payload,
+            timestamp: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::config::{Client, Response};
+use crate::params::Expand;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "BillingMeterEventAdjustment".
+///
+/// A billing meter event adjustment is a resource that allows you to cancel a meter event.
+/// For example, you might create a billing meter event adjustment to cancel a meter event that was created in error or attached to the wrong customer.
+///
+/// For more details see [https://stripe.com/docs/api/billing/meter-event-adjustment](https://stripe.com/docs/api/billing/meter-event-adjustment).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterEventAdjustment {
+    /// Specifies which event to cancel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel: Option<BillingMeterEventAdjustmentCancel>,
+
+    /// The name of the meter event.
+    ///
+    /// Corresponds with the `event_name` field on a meter.
+    pub event_name: String,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The meter event adjustment's status.
+    pub status: BillingMeterEventAdjustmentStatus,
+
+    /// Specifies whether to cancel a single event or a range of events for a time period.
+    ///
+    /// Time period cancellation is not supported yet.
+    #[serde(rename = "type")]
+    pub type_: BillingMeterEventAdjustmentType,
+}
+
+impl BillingMeterEventAdjustment {
+    /// Creates a billing meter event adjustment.
+    pub fn create(
+        client: &Client,
+        params: CreateBillingMeterEventAdjustment<'_>,
+    ) -> Response<BillingMeterEventAdjustment> {
+        client.post_form("/billing/meter_event_adjustments", &params)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterEventAdjustmentCancel {
+    /// Unique identifier for the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+}
+
+/// The parameters for `BillingMeterEventAdjustment::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeterEventAdjustment<'a> {
+    /// Specifies which event to cancel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel: Option<BillingMeterEventAdjustmentCancel>,
+
+    /// The name of the meter event.
+    ///
+    /// Corresponds with the `event_name` field on a meter.
+    pub event_name: &'a str,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Specifies whether to cancel a single event or a range of events for a time period.
+    ///
+    /// Time period cancellation is not supported yet.
+    #[serde(rename = "type")]
+    pub type_: BillingMeterEventAdjustmentType,
+}
+
+impl<'a> CreateBillingMeterEventAdjustment<'a> {
+    pub fn new(event_name: &'a str, type_: BillingMeterEventAdjustmentType) -> Self {
+        CreateBillingMeterEventAdjustment {
+            cancel: Default::default(),
+            event_name,
+            expand: Default::default(),
+            type_,
+        }
+    }
+
+    /// Cancels the meter event with the given `identifier`.
+    pub fn cancel(event_name: &'a str, identifier: impl Into<String>) -> Self {
+        let mut params = Self::new(event_name, BillingMeterEventAdjustmentType::Cancel);
+        params.cancel =
+            Some(BillingMeterEventAdjustmentCancel { identifier: Some(identifier.into()) });
+        params
+    }
+}
+
+/// An enum representing the possible values of an `BillingMeterEventAdjustment`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingMeterEventAdjustmentStatus {
+    Complete,
+    Pending,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingMeterEventAdjustmentStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingMeterEventAdjustmentStatus::Complete => "complete",
+            BillingMeterEventAdjustmentStatus::Pending => "pending",
+            BillingMeterEventAdjustmentStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingMeterEventAdjustmentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingMeterEventAdjustmentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `BillingMeterEventAdjustment`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingMeterEventAdjustmentType {
+    Cancel,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BillingMeterEventAdjustmentType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingMeterEventAdjustmentType::Cancel => "cancel",
+            BillingMeterEventAdjustmentType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for BillingMeterEventAdjustmentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingMeterEventAdjustmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -23,6 +23,16 @@ pub struct CreateBillingPortalSession {
     pub configuration: Option<BillingPortalConfigurationId>,
 }
 
+impl CreateBillingPortalSession {
+    pub fn new(customer: CustomerId) -> Self {
+        CreateBillingPortalSession {
+            customer,
+            return_url: Default::default(),
+            configuration: Default::default(),
+        }
+    }
+}
+
 impl BillingPortalSession {
     /// Attach a payment method to a customer
     ///
@@ -0,0 +1,105 @@
+use crate::config::{Client, Response};
+use crate::ids::{AccountId, CapabilityId};
+use crate::params::{Expand, Expandable, List, Object, Timestamp};
+use crate::resources::{Account, AccountRequirements, CapabilityStatus};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "Capability".
+///
+/// This is an object representing a capability for a Stripe account.
+///
+/// For more details see [https://stripe.com/docs/api/capabilities](https://stripe.com/docs/api/capabilities).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Capability {
+    /// The identifier for the capability.
+    pub id: CapabilityId,
+
+    /// The account for which the capability enables functionality.
+    pub account: Expandable<Account>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub future_requirements: Option<AccountRequirements>,
+
+    /// Whether the capability has been requested.
+    pub requested: bool,
+
+    /// Time at which the capability was requested.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_at: Option<Timestamp>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<AccountRequirements>,
+
+    /// The status of the capability.
+    ///
+    /// Can be `active`, `disabled`, `inactive`, `pending`, or `unrequested`.
+    pub status: CapabilityStatus,
+}
+
+impl Capability {
+    /// Returns a list of capabilities associated with the account.
+    ///
+    /// The capabilities are returned sorted by creation date, with the most recent capability appearing first.
+    pub fn list(client: &Client, account_id: &AccountId) -> Response<List<Capability>> {
+        client.get(&format!("/accounts/{}/capabilities", account_id))
+    }
+
+    /// Retrieves information about the specified Account Capability.
+    pub fn retrieve(
+        client: &Client,
+        account_id: &AccountId,
+        id: &CapabilityId,
+        expand: &[&str],
+    ) -> Response<Capability> {
+        client
+            .get_query(&format!("/accounts/{}/capabilities/{}", account_id, id), &Expand { expand })
+    }
+
+    /// Updates an existing Account Capability.
+    ///
+    /// Request or remove a capability by updating its `requested` parameter.
+    pub fn update(
+        client: &Client,
+        account_id: &AccountId,
+        id: &CapabilityId,
+        params: UpdateCapability<'_>,
+    ) -> Response<Capability> {
+        client.post_form(&format!("/accounts/{}/capabilities/{}", account_id, id), &params)
+    }
+}
+
+impl Object for Capability {
+    type Id = CapabilityId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "capability"
+    }
+}
+
+/// The parameters for `Capability::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateCapability<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// To request a new capability for an account, pass true.
+    ///
+    /// There can be a delay before the requested capability becomes active.
+    /// If the capability has any activation requirements, the response includes them in the `requirements` arrays.
+    /// If a capability isn't permanent, you can remove it from the account by passing false.
+    /// Most capabilities are permanent after they've been requested.
+    /// Attempting to remove a permanent capability returns an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested: Option<bool>,
+}
+
+impl<'a> UpdateCapability<'a> {
+    pub fn new() -> Self {
+        UpdateCapability { expand: Default::default(), requested: Default::default() }
+    }
+}
@@ -150,6 +150,11 @@ pub enum CheckResult {
     Unavailable,
     #[serde(rename = "unchecked")]
     Unchecked,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
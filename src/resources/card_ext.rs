@@ -0,0 +1,167 @@
+use crate::config::{Client, Response};
+use crate::ids::{CardId, CustomerId, TokenId};
+use crate::params::{Deleted, Expand, List, Metadata};
+use crate::resources::Card;
+use serde_derive::Serialize;
+
+impl Card {
+    /// Creates a card for a customer from a token, e.g. one received from Stripe Elements.
+    ///
+    /// For more details see https://stripe.com/docs/api/cards/create.
+    pub fn create(client: &Client, customer_id: &CustomerId, token: &TokenId) -> Response<Card> {
+        #[derive(Serialize)]
+        struct CreateCard<'a> {
+            source: &'a TokenId,
+        }
+        let params = CreateCard { source: token };
+        client.post_form(&format!("/customers/{}/sources", customer_id), params)
+    }
+
+    /// Returns a list of the cards stored on a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/cards/list.
+    pub fn list(
+        client: &Client,
+        customer_id: &CustomerId,
+        params: ListCards<'_>,
+    ) -> Response<List<Card>> {
+        #[derive(Serialize)]
+        struct ListSources<'a> {
+            object: &'static str,
+            #[serde(flatten)]
+            params: ListCards<'a>,
+        }
+        let params = ListSources { object: "card", params };
+        client.get_query(&format!("/customers/{}/sources", customer_id), params)
+    }
+
+    /// Updates the expiration date, name, address or metadata of a customer's card.
+    ///
+    /// For more details see https://stripe.com/docs/api/cards/update.
+    pub fn update(
+        client: &Client,
+        customer_id: &CustomerId,
+        card_id: &CardId,
+        params: UpdateCard<'_>,
+    ) -> Response<Card> {
+        client.post_form(&format!("/customers/{}/sources/{}", customer_id, card_id), params)
+    }
+
+    /// Deletes a card from a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/cards/delete.
+    pub fn delete(
+        client: &Client,
+        customer_id: &CustomerId,
+        card_id: &CardId,
+    ) -> Response<Deleted<CardId>> {
+        client.delete(&format!("/customers/{}/sources/{}", customer_id, card_id))
+    }
+}
+
+/// The parameters for `Card::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListCards<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<CardId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<CardId>,
+}
+
+impl<'a> ListCards<'a> {
+    pub fn new() -> Self {
+        ListCards {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Card::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateCard<'a> {
+    /// City/District/Suburb/Town/Village.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_city: Option<&'a str>,
+
+    /// Billing address country, if provided when creating card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_country: Option<&'a str>,
+
+    /// Address line 1 (Street address/PO Box/Company name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<&'a str>,
+
+    /// Address line 2 (Apartment/Suite/Unit/Building).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<&'a str>,
+
+    /// State/County/Province/Region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_state: Option<&'a str>,
+
+    /// ZIP or postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_zip: Option<&'a str>,
+
+    /// Two digit number representing the card’s expiration month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<i64>,
+
+    /// Four digit number representing the card’s expiration year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<i64>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Cardholder name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+impl<'a> UpdateCard<'a> {
+    pub fn new() -> Self {
+        UpdateCard {
+            address_city: Default::default(),
+            address_country: Default::default(),
+            address_line1: Default::default(),
+            address_line2: Default::default(),
+            address_state: Default::default(),
+            address_zip: Default::default(),
+            exp_month: Default::default(),
+            exp_year: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            name: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::config::{Client, Response};
+use crate::ids::CustomerId;
+use crate::params::{Expand, Object};
+use crate::resources::Currency;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "CashBalance".
+///
+/// A customer's `Cash balance` represents real funds.
+/// Customers can add funds to their cash balance by sending a bank transfer.
+/// These funds can be used for payment and can eventually be paid out to your bank account.
+///
+/// For more details see [https://stripe.com/docs/api/cash_balance/object](https://stripe.com/docs/api/cash_balance/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CashBalance {
+    /// A hash of all cash balances available to this customer.
+    ///
+    /// You cannot delete a customer with any cash balances, even if the balance is 0.
+    /// Amounts are represented in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available: Option<HashMap<Currency, i64>>,
+
+    /// The ID of the customer whose cash balance this object represents.
+    pub customer: CustomerId,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    pub settings: CashBalanceSettings,
+}
+
+impl CashBalance {
+    /// Retrieves a customer's cash balance.
+    pub fn retrieve(
+        client: &Client,
+        customer_id: &CustomerId,
+        expand: &[&str],
+    ) -> Response<CashBalance> {
+        client.get_query(&format!("/customers/{}/cash_balance", customer_id), &Expand { expand })
+    }
+
+    /// Changes the settings on a customer's cash balance.
+    pub fn update(
+        client: &Client,
+        customer_id: &CustomerId,
+        params: UpdateCashBalance<'_>,
+    ) -> Response<CashBalance> {
+        client.post_form(&format!("/customers/{}/cash_balance", customer_id), &params)
+    }
+}
+
+impl Object for CashBalance {
+    type Id = ();
+    fn id(&self) -> Self::Id {}
+    fn object(&self) -> &'static str {
+        "cash_balance"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CashBalanceSettings {
+    /// The configuration for how funds that land in the customer cash balance are reconciled.
+    pub reconciliation_mode: CashBalanceSettingsReconciliationMode,
+
+    /// A flag to indicate if reconciliation mode returned is the user's default or is specific to this customer cash balance.
+    pub using_merchant_default: bool,
+}
+
+/// The parameters for `CashBalance::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateCashBalance<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A hash of settings for this cash balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<UpdateCashBalanceSettings>,
+}
+
+impl<'a> UpdateCashBalance<'a> {
+    pub fn new() -> Self {
+        UpdateCashBalance { expand: Default::default(), settings: Default::default() }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateCashBalanceSettings {
+    /// Controls how funds transferred by the customer are applied to payment intents and invoices.
+    ///
+    /// Valid options are `automatic`, `manual`, or `merchant_default`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconciliation_mode: Option<UpdateCashBalanceSettingsReconciliationMode>,
+}
+
+/// An enum representing the possible values of an `CashBalanceSettings`'s `reconciliation_mode` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CashBalanceSettingsReconciliationMode {
+    Automatic,
+    Manual,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CashBalanceSettingsReconciliationMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CashBalanceSettingsReconciliationMode::Automatic => "automatic",
+            CashBalanceSettingsReconciliationMode::Manual => "manual",
+            CashBalanceSettingsReconciliationMode::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CashBalanceSettingsReconciliationMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CashBalanceSettingsReconciliationMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `UpdateCashBalanceSettings`'s `reconciliation_mode` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateCashBalanceSettingsReconciliationMode {
+    Automatic,
+    Manual,
+    MerchantDefault,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl UpdateCashBalanceSettingsReconciliationMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdateCashBalanceSettingsReconciliationMode::Automatic => "automatic",
+            UpdateCashBalanceSettingsReconciliationMode::Manual => "manual",
+            UpdateCashBalanceSettingsReconciliationMode::MerchantDefault => "merchant_default",
+            UpdateCashBalanceSettingsReconciliationMode::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for UpdateCashBalanceSettingsReconciliationMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for UpdateCashBalanceSettingsReconciliationMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
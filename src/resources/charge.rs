@@ -3,7 +3,7 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::{ChargeId, CustomerId, PaymentIntentId};
+use crate::ids::{AccountId, ChargeId, CustomerId, PaymentIntentId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
     Account, Application, ApplicationFee, BalanceTransaction, BillingDetails, ChargeSourceParams,
@@ -160,6 +160,7 @@ pub struct Charge {
     pub refunded: bool,
 
     /// A list of refunds that have been applied to the charge.
+    #[serde(default)]
     pub refunds: List<Refund>,
 
     /// ID of the review associated with this charge if one exists.
@@ -404,7 +405,7 @@ pub struct CreateCharge<'a> {
     /// Automatically set if you use the `destination` parameter.
     /// For details, see [Creating Separate Charges and Transfers](https://stripe.com/docs/connect/charges-transfers#on-behalf-of).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub on_behalf_of: Option<&'a str>,
+    pub on_behalf_of: Option<AccountId>,
 
     /// The email address to which this charge's [receipt](https://stripe.com/docs/dashboard/receipts) will be sent.
     ///
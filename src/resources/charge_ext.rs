@@ -1,7 +1,7 @@
 use crate::config::{Client, Response};
 use crate::ids::{BankAccountId, CardId, ChargeId, SourceId, TokenId};
-use crate::params::Object;
-use crate::resources::{Charge, Rule};
+use crate::params::{List, Object};
+use crate::resources::{Charge, ListCharges, Rule};
 use serde_derive::{Deserialize, Serialize};
 
 /// The set of PaymentSource parameters that can be used to create a charge.
@@ -42,6 +42,19 @@ impl Charge {
     ) -> Response<Charge> {
         client.post_form(&format!("/charges/{}/capture", charge_id), params)
     }
+
+    /// Lists the charges in a transfer group, e.g. to reconcile them with the transfers
+    /// made from them using `Transfer::list_by_transfer_group`.
+    ///
+    /// Any `transfer_group` already set on `params` is replaced.
+    pub fn list_by_transfer_group<'a>(
+        client: &Client,
+        transfer_group: &'a str,
+        mut params: ListCharges<'a>,
+    ) -> Response<List<Charge>> {
+        params.transfer_group = Some(transfer_group);
+        Charge::list(client, params)
+    }
 }
 
 impl Object for Rule {
@@ -2,11 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::CheckoutSessionId;
-use crate::params::{Expandable, List, Metadata, Object};
+use crate::ids::{AccountId, CheckoutSessionId, TaxIdId};
+use crate::params::{Expandable, List, Metadata, Object, Timestamp};
 use crate::resources::{
-    CheckoutSessionItem, Currency, Customer, PaymentIntent, Plan, SetupIntent, Shipping, Sku,
-    Subscription,
+    Address, CheckoutSessionItem, Currency, CustomField, Customer, CustomerTaxExempt, Invoice,
+    PaymentIntent, Plan, SetupIntent, Shipping, ShippingRate, Sku, Subscription,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -18,13 +18,36 @@ pub struct CheckoutSession {
     /// Used to pass to `redirectToCheckout` in Stripe.js.
     pub id: CheckoutSessionId,
 
+    /// When set, provides configuration for actions to take if this Checkout Session expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_expiration: Option<CheckoutSessionAfterExpiration>,
+
+    /// Total of all items before discounts or taxes are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_subtotal: Option<i64>,
+
+    /// Total of all items after discounts and taxes are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_total: Option<i64>,
+
+    /// Details on the state of automatic tax for the session, including the status of the latest tax calculation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CheckoutSessionAutomaticTax>,
+
     /// The value (`auto` or `required`) for whether Checkout collected the
     /// customer's billing address.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub billing_address_collection: Option<String>,
 
     /// The URL the customer will be directed to if they decide to cancel payment and return to your website.
-    pub cancel_url: String,
+    ///
+    /// Not set for sessions with `ui_mode` set to `embedded`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<String>,
+
+    /// Client secret to be used when initializing Stripe.js embedded checkout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
 
     /// A unique string to reference the Checkout Session.
     ///
@@ -32,6 +55,20 @@ pub struct CheckoutSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_reference_id: Option<String>,
 
+    /// Results of `consent_collection` for this session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent: Option<CheckoutSessionConsent>,
+
+    /// When set, provides configuration for the Checkout Session to gather active consent from customers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent_collection: Option<CheckoutSessionConsentCollection>,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
     /// The ID of the customer for this session.
     /// For Checkout Sessions in `payment` or `subscription` mode, Checkout
     /// will create a new customer object based on information provided
@@ -40,6 +77,26 @@ pub struct CheckoutSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<Expandable<Customer>>,
 
+    /// Configure whether a Checkout Session creates a Customer when the Checkout Session completes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_creation: Option<CheckoutSessionCustomerCreation>,
+
+    /// Collect additional information from your customer using custom fields.
+    ///
+    /// Up to 3 fields are supported.
+    #[serde(default)]
+    pub custom_fields: Vec<CheckoutSessionCustomField>,
+
+    /// Display additional text for your customers using custom text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_text: Option<CheckoutSessionCustomText>,
+
+    /// The customer details including the customer's tax exempt status and the customer's tax IDs.
+    ///
+    /// Customer's address details are not present on Sessions in `setup` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_details: Option<CheckoutSessionCustomerDetails>,
+
     /// If provided, this value will be used when the Customer object is created.
     /// If not provided, customers will be asked to enter their email address.
     /// Use this parameter to prefill customer data if you already have an email
@@ -53,6 +110,18 @@ pub struct CheckoutSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_items: Option<Vec<CheckoutSessionDisplayItem>>,
 
+    /// The timestamp at which the Checkout Session will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// ID of the invoice created by the Checkout Session, if it exists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Expandable<Invoice>>,
+
+    /// Details on the state of invoice creation for the Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_creation: Option<CheckoutSessionInvoiceCreation>,
+
     /// The line items purchased by the customer.
     ///
     /// [Expand](https://stripe.com/docs/api/expanding_objects) this field to include it in the response.
@@ -85,7 +154,39 @@ pub struct CheckoutSession {
     /// A list of the types of payment methods (e.g.
     ///
     /// card) this Checkout Session is allowed to accept.
-    pub payment_method_types: Vec<String>,
+    pub payment_method_types: Vec<CheckoutSessionPaymentMethodType>,
+
+    /// The payment status of the Checkout Session, one of `paid`, `unpaid`, or `no_payment_required`.
+    ///
+    /// You can use this value to decide when to fulfill your customer's order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_status: Option<CheckoutSessionPaymentStatus>,
+
+    /// Details on whether Checkout collected the customer's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number_collection: Option<CheckoutSessionPhoneNumberCollection>,
+
+    /// The ID of the original expired Checkout Session that triggered the recovery flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovered_from: Option<CheckoutSessionId>,
+
+    /// This parameter applies to `ui_mode: embedded`.
+    ///
+    /// Defaults to `always`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_on_completion: Option<CheckoutSessionRedirectOnCompletion>,
+
+    /// Applies to Checkout Sessions with `ui_mode: embedded`.
+    ///
+    /// The URL to redirect your customer back to after they authenticate or cancel their payment on the payment method's app or site.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+
+    /// Controls saved payment method settings for the session.
+    ///
+    /// Only available in `payment` and `subscription` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saved_payment_method_options: Option<CheckoutSessionSavedPaymentMethodOptions>,
 
     /// The ID of the SetupIntent for Checkout Sessions in `setup` mode.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -99,6 +200,22 @@ pub struct CheckoutSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping_address_collection: Option<ShippingAddressCollection>,
 
+    /// The details of the customer cost of shipping, including the customer chosen ShippingRate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<CheckoutSessionShippingCost>,
+
+    /// Shipping information for this Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_details: Option<Shipping>,
+
+    /// The shipping rate options applied to this Session.
+    #[serde(default)]
+    pub shipping_options: Vec<CheckoutSessionShippingOption>,
+
+    /// The status of the Checkout Session, one of `open`, `complete`, or `expired`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckoutSessionStatus>,
+
     /// Describes the type of transaction being performed by Checkout in order to customize
     /// relevant text on the page, such as the submit button.
     ///
@@ -106,84 +223,798 @@ pub struct CheckoutSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub submit_type: Option<CheckoutSessionSubmitType>,
 
-    /// The ID of the subscription for Checkout Sessions in `subscription` mode.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription: Option<Expandable<Subscription>>,
+    /// The ID of the subscription for Checkout Sessions in `subscription` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Expandable<Subscription>>,
+
+    /// The URL the customer will be directed to after the payment or
+    /// subscription creation is successful.
+    ///
+    /// Not set for sessions with `ui_mode` set to `embedded`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_url: Option<String>,
+
+    /// Details on whether Checkout collected the customer's tax IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_id_collection: Option<CheckoutSessionTaxIdCollection>,
+
+    /// Tax and discount details for the computed total amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_details: Option<CheckoutSessionTotalDetails>,
+
+    /// The UI mode of the Session.
+    ///
+    /// Defaults to `hosted`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_mode: Option<CheckoutSessionUiMode>,
+
+    // The URL to the checkout session
+    // https://stripe.com/docs/api/checkout/sessions/object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Object for CheckoutSession {
+    type Id = CheckoutSessionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "checkout.session"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionDisplayItem {
+    /// Amount for the display item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<CheckoutSessionCustomDisplayItemDescription>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<Plan>,
+
+    /// Quantity of the display item being purchased.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<Sku>,
+
+    /// The type of display item.
+    ///
+    /// One of `custom`, `plan` or `sku`.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomDisplayItemDescription {
+    /// The description of the line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The images of the line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<String>>,
+
+    /// The name of the line item.
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionSavedPaymentMethodOptions {
+    /// Uses the `allow_redisplay` value of each saved payment method to filter the set presented to a returning customer.
+    ///
+    /// By default, only saved payment methods with `allow_redisplay: 'always'` are shown in Checkout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_redisplay_filters: Option<Vec<CheckoutSessionAllowRedisplay>>,
+
+    /// Enable customers to choose if they wish to save their payment method for future use.
+    ///
+    /// Disabled by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_save: Option<CheckoutSessionPaymentMethodSave>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionShippingCost {
+    /// Total shipping cost before any discounts or taxes are applied.
+    pub amount_subtotal: i64,
+
+    /// Total tax amount applied due to shipping costs.
+    ///
+    /// If no tax was applied, defaults to 0.
+    pub amount_tax: i64,
+
+    /// Total shipping cost after discounts and taxes are applied.
+    pub amount_total: i64,
+
+    /// The ID of the ShippingRate for this order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<Expandable<ShippingRate>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionShippingOption {
+    /// A non-negative integer in cents representing how much to charge.
+    pub shipping_amount: i64,
+
+    /// The shipping rate.
+    pub shipping_rate: Expandable<ShippingRate>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionAfterExpiration {
+    /// When set, configuration used to recover the Checkout Session on expiry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery: Option<CheckoutSessionAfterExpirationRecovery>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionAfterExpirationRecovery {
+    /// Enables user redeemable promotion codes on the recovered Checkout Sessions.
+    ///
+    /// Defaults to `false`.
+    pub allow_promotion_codes: bool,
+
+    /// If `true`, a recovery url will be generated to recover this Checkout Session if it expires before a transaction is completed.
+    ///
+    /// It will be attached to the Checkout Session object upon expiration.
+    pub enabled: bool,
+
+    /// The timestamp at which the recovery URL will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// URL that creates a new Checkout Session when clicked that is a copy of this expired Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionAutomaticTax {
+    /// Indicates whether automatic tax is enabled for the session.
+    pub enabled: bool,
+
+    /// The account that's liable for tax.
+    ///
+    /// If set, the business address and tax registrations required to perform the tax calculation are loaded from this account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liability: Option<CheckoutSessionTaxLiability>,
+
+    /// The status of the most recent automated tax calculation for this session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckoutSessionAutomaticTaxStatus>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionTaxLiability {
+    /// The connected account being referenced when `type` is `account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountId>,
+
+    /// Type of the account referenced.
+    #[serde(rename = "type")]
+    pub type_: CheckoutSessionTaxLiabilityType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionConsent {
+    /// If `opt_in`, the customer consents to receiving promotional communications from the merchant about this Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotions: Option<CheckoutSessionConsentPromotions>,
+
+    /// If `accepted`, the customer in this Checkout Session has agreed to the merchant's terms of service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<CheckoutSessionConsentTermsOfService>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionConsentCollection {
+    /// If set to `auto`, enables the collection of customer consent for promotional communications.
+    ///
+    /// The Checkout Session will determine whether to display an option to opt into promotional communication from the merchant depending on the customer's locale.
+    /// Only available to US merchants.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotions: Option<CheckoutSessionConsentCollectionPromotions>,
+
+    /// If set to `required`, it requires customers to accept the terms of service before being able to pay.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<CheckoutSessionConsentCollectionTermsOfService>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropdown: Option<CheckoutSessionCustomFieldDropdown>,
+
+    /// String of your choice that your integration can use to reconcile this field.
+    ///
+    /// Must be unique to this field, alphanumeric, and up to 200 characters.
+    pub key: String,
+
+    pub label: CheckoutSessionCustomFieldLabel,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numeric: Option<CheckoutSessionCustomFieldText>,
+
+    /// Whether the customer is required to complete the field before completing the Checkout Session.
+    ///
+    /// Defaults to `false`.
+    pub optional: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<CheckoutSessionCustomFieldText>,
+
+    /// The type of the field.
+    #[serde(rename = "type")]
+    pub type_: CheckoutSessionCustomFieldType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldDropdown {
+    /// The value that will pre-fill on the payment page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+
+    /// The options available for the customer to select.
+    ///
+    /// Up to 200 options allowed.
+    pub options: Vec<CheckoutSessionCustomFieldDropdownOption>,
+
+    /// The option selected by the customer.
+    ///
+    /// This will be the `value` for the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldDropdownOption {
+    /// The label for the option, displayed to the customer.
+    ///
+    /// Up to 100 characters.
+    pub label: String,
+
+    /// The value for this option, not displayed to the customer, used by your integration to reconcile the option selected by the customer.
+    ///
+    /// Must be unique to this option, alphanumeric, and up to 100 characters.
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldLabel {
+    /// Custom text for the label, displayed to the customer.
+    ///
+    /// Up to 50 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<String>,
+
+    /// The type of the label.
+    #[serde(rename = "type")]
+    pub type_: CheckoutSessionCustomFieldLabelType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldText {
+    /// The value that will pre-fill the field on the payment page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+
+    /// The maximum character length constraint for the customer's input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_length: Option<i64>,
+
+    /// The minimum character length requirement for the customer's input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_length: Option<i64>,
+
+    /// The value entered by the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomText {
+    /// Custom text that should be displayed alongside shipping address collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<CheckoutSessionCustomTextPosition>,
+
+    /// Custom text that should be displayed alongside the payment confirmation button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<CheckoutSessionCustomTextPosition>,
+
+    /// Custom text that should be displayed in place of the default terms of service agreement text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service_acceptance: Option<CheckoutSessionCustomTextPosition>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomTextPosition {
+    /// Text may be up to 1200 characters in length.
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomerDetails {
+    /// The customer's address after a completed Checkout Session.
+    ///
+    /// Note: This property is populated only for sessions on or after March 30, 2022.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+
+    /// The email associated with the Customer, if one exists, on the Checkout Session after a completed Checkout Session or at time of session expiry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// The customer's name after a completed Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The customer's phone number after a completed Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    /// The customer’s tax exempt status after a completed Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_exempt: Option<CustomerTaxExempt>,
+
+    /// The customer’s tax IDs after a completed Checkout Session.
+    #[serde(default)]
+    pub tax_ids: Vec<CheckoutSessionCustomerTaxId>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomerTaxId {
+    /// The type of the tax ID, one of `ad_nrt`, `ar_cuit`, `eu_vat`, `bo_tin`, `al_tin`, `kh_tin`, or `unknown`, among others.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// The value of the tax ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionInvoiceCreation {
+    /// Indicates whether invoice creation is enabled for the Checkout Session.
+    pub enabled: bool,
+
+    pub invoice_data: CheckoutSessionInvoiceData,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionInvoiceData {
+    /// The account tax IDs associated with the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_tax_ids: Option<Vec<TaxIdId>>,
+
+    /// Custom fields displayed on the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomField>>,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Footer displayed on the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
+    pub metadata: Metadata,
+
+    /// Options for invoice PDF rendering.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendering_options: Option<CheckoutSessionInvoiceRenderingOptions>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionInvoiceRenderingOptions {
+    /// How line-item prices and amounts will be displayed with respect to tax on invoice PDFs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_tax_display: Option<CheckoutSessionInvoiceAmountTaxDisplay>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionPhoneNumberCollection {
+    /// Indicates whether phone number collection is enabled for the session.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionTaxIdCollection {
+    /// Indicates whether tax ID collection is enabled for the session.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionTotalDetails {
+    /// This is the sum of all the discounts.
+    pub amount_discount: i64,
+
+    /// This is the sum of all the shipping amounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_shipping: Option<i64>,
+
+    /// This is the sum of all the tax amounts.
+    pub amount_tax: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShippingAddressCollection {
+    /// An array of two-letter ISO country codes representing which countries Checkout should provide as options for
+    /// shipping locations.
+    ///
+    /// Unsupported country codes: `AS, CX, CC, CU, HM, IR, KP, MH, FM, NF, MP, PW, SD, SY, UM, VI`.
+    pub allowed_countries: Vec<ShippingAddressCollectionAllowedCountries>,
+}
+
+/// An enum representing the possible values of an `CheckoutSessionSavedPaymentMethodOptions`'s `allow_redisplay_filters` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionAllowRedisplay {
+    Always,
+    Limited,
+    Unspecified,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionAllowRedisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionAllowRedisplay::Always => "always",
+            CheckoutSessionAllowRedisplay::Limited => "limited",
+            CheckoutSessionAllowRedisplay::Unspecified => "unspecified",
+            CheckoutSessionAllowRedisplay::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionAllowRedisplay {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionAllowRedisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionAutomaticTax`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionAutomaticTaxStatus {
+    Complete,
+    Failed,
+    RequiresLocationInputs,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionAutomaticTaxStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionAutomaticTaxStatus::Complete => "complete",
+            CheckoutSessionAutomaticTaxStatus::Failed => "failed",
+            CheckoutSessionAutomaticTaxStatus::RequiresLocationInputs => "requires_location_inputs",
+            CheckoutSessionAutomaticTaxStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionAutomaticTaxStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionAutomaticTaxStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionConsentCollection`'s `promotions` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionConsentCollectionPromotions {
+    Auto,
+    None,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionConsentCollectionPromotions {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionConsentCollectionPromotions::Auto => "auto",
+            CheckoutSessionConsentCollectionPromotions::None => "none",
+            CheckoutSessionConsentCollectionPromotions::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionConsentCollectionPromotions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionConsentCollectionPromotions {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionConsentCollection`'s `terms_of_service` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionConsentCollectionTermsOfService {
+    None,
+    Required,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionConsentCollectionTermsOfService {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionConsentCollectionTermsOfService::None => "none",
+            CheckoutSessionConsentCollectionTermsOfService::Required => "required",
+            CheckoutSessionConsentCollectionTermsOfService::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionConsentCollectionTermsOfService {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionConsentCollectionTermsOfService {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionConsent`'s `promotions` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionConsentPromotions {
+    OptIn,
+    OptOut,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionConsentPromotions {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionConsentPromotions::OptIn => "opt_in",
+            CheckoutSessionConsentPromotions::OptOut => "opt_out",
+            CheckoutSessionConsentPromotions::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionConsentPromotions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionConsentPromotions {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionConsent`'s `terms_of_service` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionConsentTermsOfService {
+    Accepted,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionConsentTermsOfService {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionConsentTermsOfService::Accepted => "accepted",
+            CheckoutSessionConsentTermsOfService::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionConsentTermsOfService {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionConsentTermsOfService {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionCustomFieldLabel`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionCustomFieldLabelType {
+    Custom,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionCustomFieldLabelType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionCustomFieldLabelType::Custom => "custom",
+            CheckoutSessionCustomFieldLabelType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionCustomFieldLabelType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionCustomFieldLabelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionCustomField`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionCustomFieldType {
+    Dropdown,
+    Numeric,
+    Text,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
 
-    /// The URL the customer will be directed to after the payment or
-    /// subscription creation is successful.
-    pub success_url: String,
-    
-    // The URL to the checkout session
-    // https://stripe.com/docs/api/checkout/sessions/object
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
+impl CheckoutSessionCustomFieldType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionCustomFieldType::Dropdown => "dropdown",
+            CheckoutSessionCustomFieldType::Numeric => "numeric",
+            CheckoutSessionCustomFieldType::Text => "text",
+            CheckoutSessionCustomFieldType::Other => "other",
+        }
+    }
 }
 
-impl Object for CheckoutSession {
-    type Id = CheckoutSessionId;
-    fn id(&self) -> Self::Id {
-        self.id.clone()
+impl AsRef<str> for CheckoutSessionCustomFieldType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
-    fn object(&self) -> &'static str {
-        "checkout.session"
+}
+
+impl std::fmt::Display for CheckoutSessionCustomFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct CheckoutSessionDisplayItem {
-    /// Amount for the display item.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<i64>,
+/// An enum representing the possible values of an `CheckoutSession`'s `customer_creation` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionCustomerCreation {
+    Always,
+    IfRequired,
 
-    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
-    ///
-    /// Must be a [supported currency](https://stripe.com/docs/currencies).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<Currency>,
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom: Option<CheckoutSessionCustomDisplayItemDescription>,
+impl CheckoutSessionCustomerCreation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionCustomerCreation::Always => "always",
+            CheckoutSessionCustomerCreation::IfRequired => "if_required",
+            CheckoutSessionCustomerCreation::Other => "other",
+        }
+    }
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan: Option<Plan>,
+impl AsRef<str> for CheckoutSessionCustomerCreation {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
 
-    /// Quantity of the display item being purchased.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<u64>,
+impl std::fmt::Display for CheckoutSessionCustomerCreation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sku: Option<Sku>,
+/// An enum representing the possible values of an `CheckoutSessionInvoiceRenderingOptions`'s `amount_tax_display` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionInvoiceAmountTaxDisplay {
+    ExcludeTax,
+    IncludeInclusiveTax,
 
-    /// The type of display item.
-    ///
-    /// One of `custom`, `plan` or `sku`.
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct CheckoutSessionCustomDisplayItemDescription {
-    /// The description of the line item.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-
-    /// The images of the line item.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub images: Option<Vec<String>>,
+impl CheckoutSessionInvoiceAmountTaxDisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionInvoiceAmountTaxDisplay::ExcludeTax => "exclude_tax",
+            CheckoutSessionInvoiceAmountTaxDisplay::IncludeInclusiveTax => "include_inclusive_tax",
+            CheckoutSessionInvoiceAmountTaxDisplay::Other => "other",
+        }
+    }
+}
 
-    /// The name of the line item.
-    pub name: String,
+impl AsRef<str> for CheckoutSessionInvoiceAmountTaxDisplay {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct ShippingAddressCollection {
-    /// An array of two-letter ISO country codes representing which countries Checkout should provide as options for
-    /// shipping locations.
-    ///
-    /// Unsupported country codes: `AS, CX, CC, CU, HM, IR, KP, MH, FM, NF, MP, PW, SD, SY, UM, VI`.
-    pub allowed_countries: Vec<ShippingAddressCollectionAllowedCountries>,
+impl std::fmt::Display for CheckoutSessionInvoiceAmountTaxDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
 }
 
 /// An enum representing the possible values of an `CheckoutSession`'s `locale` field.
@@ -208,6 +1039,11 @@ pub enum CheckoutSessionLocale {
     PtBr,
     Sv,
     Zh,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CheckoutSessionLocale {
@@ -230,6 +1066,7 @@ impl CheckoutSessionLocale {
             CheckoutSessionLocale::PtBr => "pt-BR",
             CheckoutSessionLocale::Sv => "sv",
             CheckoutSessionLocale::Zh => "zh",
+            CheckoutSessionLocale::Other => "other",
         }
     }
 }
@@ -253,6 +1090,11 @@ pub enum CheckoutSessionMode {
     Payment,
     Setup,
     Subscription,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CheckoutSessionMode {
@@ -261,6 +1103,7 @@ impl CheckoutSessionMode {
             CheckoutSessionMode::Payment => "payment",
             CheckoutSessionMode::Setup => "setup",
             CheckoutSessionMode::Subscription => "subscription",
+            CheckoutSessionMode::Other => "other",
         }
     }
 }
@@ -277,6 +1120,306 @@ impl std::fmt::Display for CheckoutSessionMode {
     }
 }
 
+/// An enum representing the possible values of an `CheckoutSessionSavedPaymentMethodOptions`'s `payment_method_save` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionPaymentMethodSave {
+    Disabled,
+    Enabled,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionPaymentMethodSave {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionPaymentMethodSave::Disabled => "disabled",
+            CheckoutSessionPaymentMethodSave::Enabled => "enabled",
+            CheckoutSessionPaymentMethodSave::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionPaymentMethodSave {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionPaymentMethodSave {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSession`'s `payment_method_types` field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CheckoutSessionPaymentMethodType {
+    AcssDebit,
+    Affirm,
+    AfterpayClearpay,
+    Alipay,
+    AuBecsDebit,
+    BacsDebit,
+    Bancontact,
+    Blik,
+    Boleto,
+    Card,
+    Cashapp,
+    CustomerBalance,
+    Eps,
+    Fpx,
+    Giropay,
+    Grabpay,
+    Ideal,
+    Klarna,
+    Konbini,
+    Link,
+    Oxxo,
+    P24,
+    Paynow,
+    Paypal,
+    Pix,
+    Promptpay,
+    RevolutPay,
+    SepaDebit,
+    Sofort,
+    Swish,
+    UsBankAccount,
+    WechatPay,
+    Zip,
+
+    /// A payment method type not yet supported by the library.
+    Other(String),
+}
+
+impl CheckoutSessionPaymentMethodType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CheckoutSessionPaymentMethodType::AcssDebit => "acss_debit",
+            CheckoutSessionPaymentMethodType::Affirm => "affirm",
+            CheckoutSessionPaymentMethodType::AfterpayClearpay => "afterpay_clearpay",
+            CheckoutSessionPaymentMethodType::Alipay => "alipay",
+            CheckoutSessionPaymentMethodType::AuBecsDebit => "au_becs_debit",
+            CheckoutSessionPaymentMethodType::BacsDebit => "bacs_debit",
+            CheckoutSessionPaymentMethodType::Bancontact => "bancontact",
+            CheckoutSessionPaymentMethodType::Blik => "blik",
+            CheckoutSessionPaymentMethodType::Boleto => "boleto",
+            CheckoutSessionPaymentMethodType::Card => "card",
+            CheckoutSessionPaymentMethodType::Cashapp => "cashapp",
+            CheckoutSessionPaymentMethodType::CustomerBalance => "customer_balance",
+            CheckoutSessionPaymentMethodType::Eps => "eps",
+            CheckoutSessionPaymentMethodType::Fpx => "fpx",
+            CheckoutSessionPaymentMethodType::Giropay => "giropay",
+            CheckoutSessionPaymentMethodType::Grabpay => "grabpay",
+            CheckoutSessionPaymentMethodType::Ideal => "ideal",
+            CheckoutSessionPaymentMethodType::Klarna => "klarna",
+            CheckoutSessionPaymentMethodType::Konbini => "konbini",
+            CheckoutSessionPaymentMethodType::Link => "link",
+            CheckoutSessionPaymentMethodType::Oxxo => "oxxo",
+            CheckoutSessionPaymentMethodType::P24 => "p24",
+            CheckoutSessionPaymentMethodType::Paynow => "paynow",
+            CheckoutSessionPaymentMethodType::Paypal => "paypal",
+            CheckoutSessionPaymentMethodType::Pix => "pix",
+            CheckoutSessionPaymentMethodType::Promptpay => "promptpay",
+            CheckoutSessionPaymentMethodType::RevolutPay => "revolut_pay",
+            CheckoutSessionPaymentMethodType::SepaDebit => "sepa_debit",
+            CheckoutSessionPaymentMethodType::Sofort => "sofort",
+            CheckoutSessionPaymentMethodType::Swish => "swish",
+            CheckoutSessionPaymentMethodType::UsBankAccount => "us_bank_account",
+            CheckoutSessionPaymentMethodType::WechatPay => "wechat_pay",
+            CheckoutSessionPaymentMethodType::Zip => "zip",
+            CheckoutSessionPaymentMethodType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for CheckoutSessionPaymentMethodType {
+    fn from(s: &str) -> Self {
+        match s {
+            "acss_debit" => CheckoutSessionPaymentMethodType::AcssDebit,
+            "affirm" => CheckoutSessionPaymentMethodType::Affirm,
+            "afterpay_clearpay" => CheckoutSessionPaymentMethodType::AfterpayClearpay,
+            "alipay" => CheckoutSessionPaymentMethodType::Alipay,
+            "au_becs_debit" => CheckoutSessionPaymentMethodType::AuBecsDebit,
+            "bacs_debit" => CheckoutSessionPaymentMethodType::BacsDebit,
+            "bancontact" => CheckoutSessionPaymentMethodType::Bancontact,
+            "blik" => CheckoutSessionPaymentMethodType::Blik,
+            "boleto" => CheckoutSessionPaymentMethodType::Boleto,
+            "card" => CheckoutSessionPaymentMethodType::Card,
+            "cashapp" => CheckoutSessionPaymentMethodType::Cashapp,
+            "customer_balance" => CheckoutSessionPaymentMethodType::CustomerBalance,
+            "eps" => CheckoutSessionPaymentMethodType::Eps,
+            "fpx" => CheckoutSessionPaymentMethodType::Fpx,
+            "giropay" => CheckoutSessionPaymentMethodType::Giropay,
+            "grabpay" => CheckoutSessionPaymentMethodType::Grabpay,
+            "ideal" => CheckoutSessionPaymentMethodType::Ideal,
+            "klarna" => CheckoutSessionPaymentMethodType::Klarna,
+            "konbini" => CheckoutSessionPaymentMethodType::Konbini,
+            "link" => CheckoutSessionPaymentMethodType::Link,
+            "oxxo" => CheckoutSessionPaymentMethodType::Oxxo,
+            "p24" => CheckoutSessionPaymentMethodType::P24,
+            "paynow" => CheckoutSessionPaymentMethodType::Paynow,
+            "paypal" => CheckoutSessionPaymentMethodType::Paypal,
+            "pix" => CheckoutSessionPaymentMethodType::Pix,
+            "promptpay" => CheckoutSessionPaymentMethodType::Promptpay,
+            "revolut_pay" => CheckoutSessionPaymentMethodType::RevolutPay,
+            "sepa_debit" => CheckoutSessionPaymentMethodType::SepaDebit,
+            "sofort" => CheckoutSessionPaymentMethodType::Sofort,
+            "swish" => CheckoutSessionPaymentMethodType::Swish,
+            "us_bank_account" => CheckoutSessionPaymentMethodType::UsBankAccount,
+            "wechat_pay" => CheckoutSessionPaymentMethodType::WechatPay,
+            "zip" => CheckoutSessionPaymentMethodType::Zip,
+            other => CheckoutSessionPaymentMethodType::Other(other.to_string()),
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionPaymentMethodType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionPaymentMethodType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for CheckoutSessionPaymentMethodType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CheckoutSessionPaymentMethodType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(CheckoutSessionPaymentMethodType::from(s.as_str()))
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSession`'s `payment_status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionPaymentStatus {
+    NoPaymentRequired,
+    Paid,
+    Unpaid,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionPaymentStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionPaymentStatus::NoPaymentRequired => "no_payment_required",
+            CheckoutSessionPaymentStatus::Paid => "paid",
+            CheckoutSessionPaymentStatus::Unpaid => "unpaid",
+            CheckoutSessionPaymentStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionPaymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionPaymentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSession`'s `redirect_on_completion` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionRedirectOnCompletion {
+    Always,
+    IfRequired,
+    Never,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionRedirectOnCompletion {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionRedirectOnCompletion::Always => "always",
+            CheckoutSessionRedirectOnCompletion::IfRequired => "if_required",
+            CheckoutSessionRedirectOnCompletion::Never => "never",
+            CheckoutSessionRedirectOnCompletion::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionRedirectOnCompletion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionRedirectOnCompletion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSession`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionStatus {
+    Complete,
+    Expired,
+    Open,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionStatus::Complete => "complete",
+            CheckoutSessionStatus::Expired => "expired",
+            CheckoutSessionStatus::Open => "open",
+            CheckoutSessionStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `CheckoutSession`'s `submit_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -285,6 +1428,11 @@ pub enum CheckoutSessionSubmitType {
     Book,
     Donate,
     Pay,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CheckoutSessionSubmitType {
@@ -294,6 +1442,7 @@ impl CheckoutSessionSubmitType {
             CheckoutSessionSubmitType::Book => "book",
             CheckoutSessionSubmitType::Donate => "donate",
             CheckoutSessionSubmitType::Pay => "pay",
+            CheckoutSessionSubmitType::Other => "other",
         }
     }
 }
@@ -310,6 +1459,77 @@ impl std::fmt::Display for CheckoutSessionSubmitType {
     }
 }
 
+/// An enum representing the possible values of an `CheckoutSessionTaxLiability`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionTaxLiabilityType {
+    Account,
+    #[serde(rename = "self")]
+    Self_,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionTaxLiabilityType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionTaxLiabilityType::Account => "account",
+            CheckoutSessionTaxLiabilityType::Self_ => "self",
+            CheckoutSessionTaxLiabilityType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionTaxLiabilityType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionTaxLiabilityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSession`'s `ui_mode` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionUiMode {
+    Embedded,
+    Hosted,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionUiMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionUiMode::Embedded => "embedded",
+            CheckoutSessionUiMode::Hosted => "hosted",
+            CheckoutSessionUiMode::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionUiMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionUiMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `ShippingAddressCollection`'s `allowed_countries` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -788,6 +2008,11 @@ pub enum ShippingAddressCollectionAllowedCountries {
     Zw,
     #[serde(rename = "ZZ")]
     Zz,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl ShippingAddressCollectionAllowedCountries {
@@ -1030,6 +2255,7 @@ impl ShippingAddressCollectionAllowedCountries {
             ShippingAddressCollectionAllowedCountries::Zm => "ZM",
             ShippingAddressCollectionAllowedCountries::Zw => "ZW",
             ShippingAddressCollectionAllowedCountries::Zz => "ZZ",
+            ShippingAddressCollectionAllowedCountries::Other => "other",
         }
     }
 }
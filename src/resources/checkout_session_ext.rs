@@ -1,32 +1,91 @@
-use std::collections::HashMap;
-
 use crate::config::{Client, Response};
-use crate::ids::CustomerId;
+use crate::ids::{
+    CheckoutSessionId, CustomerId, PaymentMethodConfigurationId, PriceId, ProductId,
+    ShippingRateId, TaxIdId, TaxRateId,
+};
+use crate::params::{Expand, Metadata, StatementDescriptor, Timestamp};
 use crate::resources::{
-    CheckoutSession, CheckoutSessionLocale, CheckoutSessionMode, CheckoutSessionSubmitType,
-    Currency,
+    CheckoutSession, CheckoutSessionConsentCollection, CheckoutSessionCustomFieldDropdownOption,
+    CheckoutSessionCustomFieldLabelType, CheckoutSessionCustomFieldType, CheckoutSessionCustomText,
+    CheckoutSessionCustomerCreation, CheckoutSessionInvoiceRenderingOptions, CheckoutSessionLocale,
+    CheckoutSessionMode, CheckoutSessionPaymentMethodType, CheckoutSessionPhoneNumberCollection,
+    CheckoutSessionRedirectOnCompletion, CheckoutSessionSavedPaymentMethodOptions,
+    CheckoutSessionSubmitType, CheckoutSessionTaxIdCollection, CheckoutSessionTaxLiability,
+    CheckoutSessionUiMode, CreatePriceRecurringInterval, Currency, CustomField, Money,
+    PaymentIntentCaptureMethod, ShippingAddressCollection, ShippingParams,
+    ShippingRateDeliveryEstimate, ShippingRateFixedAmount, ShippingRateTaxBehavior,
+    ShippingRateType,
 };
 use serde_derive::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// The parameters for `CheckoutSession::create`
 ///
 /// For more details see [https://stripe.com/docs/api/payment_methods/attach](https://stripe.com/docs/api/payment_methods/attach).
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateCheckoutSession<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_deserializing, skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
     /// The URL the customer will be directed to if they decide to cancel payment and return to your website.
-    pub cancel_url: &'a str,
+    ///
+    /// This parameter is not allowed if `ui_mode` is `embedded`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<Cow<'a, str>>,
 
-    /// A list of the types of payment methods (e.g. card) this Checkout Session is allowed to accept. The only supported values today are `card` and `ideal`.
-    pub payment_method_types: Vec<&'a str>,
+    /// A list of the types of payment methods (e.g. card) this Checkout Session is allowed to accept.
+    pub payment_method_types: Vec<CheckoutSessionPaymentMethodType>,
 
     /// The URL the customer will be directed to after the payment or subscription creation is successful.
-    pub success_url: &'a str,
+    ///
+    /// This parameter is not allowed if `ui_mode` is `embedded`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_url: Option<Cow<'a, str>>,
+
+    /// The UI mode of the Session.
+    ///
+    /// Defaults to `hosted`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_mode: Option<CheckoutSessionUiMode>,
+
+    /// The URL to redirect your customer back to after they authenticate or cancel their payment on the payment method's app or site.
+    ///
+    /// This parameter is required if `ui_mode` is `embedded` and redirect-based payment methods are enabled on the session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<Cow<'a, str>>,
+
+    /// This parameter applies to `ui_mode: embedded`.
+    ///
+    /// Defaults to `always`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_on_completion: Option<CheckoutSessionRedirectOnCompletion>,
 
     /// A unique string to reference the Checkout Session.
     ///
     /// This can be a customer ID, a cart ID, or similar, and can be used to reconcile the session with your internal systems.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_reference_id: Option<&'a str>,
+    pub client_reference_id: Option<Cow<'a, str>>,
+
+    /// Configure actions after a Checkout Session has expired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_expiration: Option<CheckoutSessionAfterExpirationParams>,
+
+    /// Configure fields for the Checkout Session to gather active consent from customers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent_collection: Option<CheckoutSessionConsentCollection>,
+
+    /// The Epoch time in seconds at which the Checkout Session will expire.
+    ///
+    /// It can be anywhere from 30 minutes to 24 hours after Checkout Session creation.
+    /// By default, this value is 24 hours from creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Generate a post-purchase Invoice for one-time payments.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_creation: Option<CheckoutSessionInvoiceCreationParams<'a>>,
 
     /// The ID of the customer for this session.
     ///
@@ -34,6 +93,13 @@ pub struct CreateCheckoutSession<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<CustomerId>,
 
+    /// Configure whether a Checkout Session creates a Customer during Session confirmation.
+    ///
+    /// When a Customer is not created, you can still retrieve email, address, and other customer data entered in Checkout with `customer_details`.
+    /// Can only be set in `payment` and `setup` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_creation: Option<CheckoutSessionCustomerCreation>,
+
     /// If provided, this value will be used when the Customer object is created.
     /// If not provided, customers will be asked to enter their email address.
     /// Use this parameter to prefill customer data if you already have an email
@@ -41,13 +107,39 @@ pub struct CreateCheckoutSession<'a> {
     ///
     /// To access information about the customer once a session is complete, use the `customer` field.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer_email: Option<&'a str>,
+    pub customer_email: Option<Cow<'a, str>>,
+
+    /// Collect additional information from your customer using custom fields.
+    ///
+    /// Up to 3 fields are supported.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CheckoutSessionCustomFieldParams<'a>>>,
+
+    /// Display additional text for your customers using custom text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_text: Option<CheckoutSessionCustomText>,
 
     /// The value (`auto` or `required`) for whether Checkout should collect the customer's billing address.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_address_collection: Option<&'a str>,
+    pub billing_address_collection: Option<Cow<'a, str>>,
+
+    /// Settings for automatic tax lookup for this session and resulting payments, invoices, and subscriptions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CheckoutSessionAutomaticTaxParams>,
+
+    /// Controls phone number collection settings for the session.
+    ///
+    /// We recommend that you review your privacy policy and check with your legal contacts before using this feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number_collection: Option<CheckoutSessionPhoneNumberCollection>,
+
+    /// Controls tax ID collection during checkout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_id_collection: Option<CheckoutSessionTaxIdCollection>,
 
     /// The line items, plans, or SKUs purchased by the customer.
+    #[serde(borrow)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub line_items: Option<Vec<CheckoutSessionLineItem<'a>>>,
 
@@ -62,11 +154,39 @@ pub struct CreateCheckoutSession<'a> {
     pub mode: Option<CheckoutSessionMode>,
 
     // A subset of parameters to be passed to PaymentIntent creation for Checkout Sessions in payment mode
+    #[serde(borrow)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_intent_data: Option<CheckoutPaymentIntentData<'a>>,
 
     // A subset of parameters to be passed to SetupIntent creation for Checkout Sessions in setup mode.
     // TODO: setup_intent_data
+
+    /// The ID of the payment method configuration to use with this Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_configuration: Option<PaymentMethodConfigurationId>,
+
+    /// Payment-method-specific configuration.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<CheckoutSessionPaymentMethodOptions<'a>>,
+
+    /// Controls saved payment method settings for the session.
+    ///
+    /// Only available in `payment` and `subscription` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saved_payment_method_options: Option<CheckoutSessionSavedPaymentMethodOptions>,
+
+    /// When set, provides configuration for Checkout to collect a shipping address from a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address_collection: Option<ShippingAddressCollection>,
+
+    /// The shipping rate options to apply to this Session.
+    ///
+    /// Up to a maximum of 5.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Vec<CheckoutSessionShippingOptionParams<'a>>>,
+
     /// Describes the type of transaction being performed by Checkout in order
     /// to customize relevant text on the page, such as the submit button.
     /// `submit_type` can only be specified on Checkout Sessions using line
@@ -79,30 +199,485 @@ pub struct CreateCheckoutSession<'a> {
     // TODO: subscription_data
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+impl<'a> CreateCheckoutSession<'a> {
+    pub fn new() -> Self {
+        CreateCheckoutSession {
+            after_expiration: Default::default(),
+            automatic_tax: Default::default(),
+            billing_address_collection: Default::default(),
+            cancel_url: Default::default(),
+            client_reference_id: Default::default(),
+            consent_collection: Default::default(),
+            custom_fields: Default::default(),
+            custom_text: Default::default(),
+            customer: Default::default(),
+            customer_creation: Default::default(),
+            customer_email: Default::default(),
+            expand: Default::default(),
+            expires_at: Default::default(),
+            invoice_creation: Default::default(),
+            line_items: Default::default(),
+            locale: Default::default(),
+            mode: Default::default(),
+            payment_intent_data: Default::default(),
+            payment_method_configuration: Default::default(),
+            payment_method_options: Default::default(),
+            payment_method_types: Default::default(),
+            phone_number_collection: Default::default(),
+            redirect_on_completion: Default::default(),
+            return_url: Default::default(),
+            saved_payment_method_options: Default::default(),
+            shipping_address_collection: Default::default(),
+            shipping_options: Default::default(),
+            submit_type: Default::default(),
+            success_url: Default::default(),
+            tax_id_collection: Default::default(),
+            ui_mode: Default::default(),
+        }
+    }
+}
+
+crate::params::impl_setters! {
+    CreateCheckoutSession<'a> {
+        after_expiration: CheckoutSessionAfterExpirationParams,
+        automatic_tax: CheckoutSessionAutomaticTaxParams,
+        billing_address_collection: impl Into<Cow<'a, str>>,
+        cancel_url: impl Into<Cow<'a, str>>,
+        client_reference_id: impl Into<Cow<'a, str>>,
+        consent_collection: CheckoutSessionConsentCollection,
+        custom_fields: Vec<CheckoutSessionCustomFieldParams<'a>>,
+        custom_text: CheckoutSessionCustomText,
+        customer: CustomerId,
+        customer_creation: CheckoutSessionCustomerCreation,
+        customer_email: impl Into<Cow<'a, str>>,
+        expand = &'a [&'a str],
+        expires_at: Timestamp,
+        invoice_creation: CheckoutSessionInvoiceCreationParams<'a>,
+        line_items: Vec<CheckoutSessionLineItem<'a>>,
+        locale: CheckoutSessionLocale,
+        mode: CheckoutSessionMode,
+        payment_intent_data: CheckoutPaymentIntentData<'a>,
+        payment_method_configuration: PaymentMethodConfigurationId,
+        payment_method_options: CheckoutSessionPaymentMethodOptions<'a>,
+        payment_method_types = Vec<CheckoutSessionPaymentMethodType>,
+        phone_number_collection: CheckoutSessionPhoneNumberCollection,
+        redirect_on_completion: CheckoutSessionRedirectOnCompletion,
+        return_url: impl Into<Cow<'a, str>>,
+        saved_payment_method_options: CheckoutSessionSavedPaymentMethodOptions,
+        shipping_address_collection: ShippingAddressCollection,
+        shipping_options: Vec<CheckoutSessionShippingOptionParams<'a>>,
+        submit_type: CheckoutSessionSubmitType,
+        success_url: impl Into<Cow<'a, str>>,
+        tax_id_collection: CheckoutSessionTaxIdCollection,
+        ui_mode: CheckoutSessionUiMode,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CheckoutSessionLineItem<'a> {
+    /// The ID of the [Price](https://stripe.com/docs/api/prices) or [Plan](https://stripe.com/docs/api/plans) object.
+    ///
+    /// One of `price` or `price_data` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceId>,
+
+    /// Data used to generate a new [Price](https://stripe.com/docs/api/prices) object inline.
+    ///
+    /// One of `price` or `price_data` is required.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<CheckoutSessionLineItemPriceData<'a>>,
+
+    /// The quantity of the line item being purchased.
+    pub quantity: u64,
+
+    /// The [tax rates](https://stripe.com/docs/api/tax_rates) which apply to this line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<TaxRateId>>,
+
     /// The amount to be collected per unit of the line item.
-    pub amount: i64,
+    ///
+    /// Only accepted by API versions before `2022-08-01`; use `price_data` instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
 
     /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
     ///
-    /// Must be a [supported currency](https://stripe.com/docs/currencies).
-    pub currency: Currency,
+    /// Only accepted by API versions before `2022-08-01`; use `price_data` instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
 
     /// The name for the line item.
-    pub name: &'a str,
-
-    /// The quantity of the line item being purchased.
-    pub quantity: u64,
+    ///
+    /// Only accepted by API versions before `2022-08-01`; use `price_data` instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Cow<'a, str>>,
 
     /// The description for the line item.
+    ///
+    /// Only accepted by API versions before `2022-08-01`; use `price_data` instead.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
 
     /// A list of images representing this line item.
+    ///
+    /// Only accepted by API versions before `2022-08-01`; use `price_data` instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub images: Option<Vec<String>>,
-    // TODO: remaining optional fields
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionLineItemPriceData<'a> {
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The ID of the product that this price will belong to.
+    ///
+    /// One of `product` or `product_data` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<ProductId>,
+
+    /// Data used to generate a new product object inline.
+    ///
+    /// One of `product` or `product_data` is required.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_data: Option<CheckoutSessionLineItemProductData<'a>>,
+
+    /// The recurring components of a price such as `interval` and `interval_count`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<CheckoutSessionLineItemRecurring>,
+
+    /// A non-negative integer in cents representing how much to charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+
+    /// Same as `unit_amount`, but accepts a decimal value in cents with at most 12 decimal places.
+    ///
+    /// Only one of `unit_amount` and `unit_amount_decimal` can be set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<Cow<'a, str>>,
+}
+
+impl<'a> CheckoutSessionLineItemPriceData<'a> {
+    /// Creates price data charging `money` per unit.
+    pub fn from_money(money: Money) -> Self {
+        CheckoutSessionLineItemPriceData {
+            currency: money.currency,
+            product: None,
+            product_data: None,
+            recurring: None,
+            unit_amount: Some(money.amount_minor),
+            unit_amount_decimal: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionLineItemProductData<'a> {
+    /// The product's description, meant to be displayable to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// A list of up to 8 URLs of images for this product, meant to be displayable to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<String>>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The product's name, meant to be displayable to the customer.
+    pub name: Cow<'a, str>,
+
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionLineItemRecurring {
+    /// Specifies billing frequency.
+    ///
+    /// Either `day`, `week`, `month` or `year`.
+    pub interval: CreatePriceRecurringInterval,
+
+    /// The number of intervals between subscription billings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionAfterExpirationParams {
+    /// Configure a Checkout Session that can be used to recover an expired session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery: Option<CheckoutSessionAfterExpirationRecoveryParams>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionAfterExpirationRecoveryParams {
+    /// Enables user redeemable promotion codes on the recovered Checkout Sessions.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+
+    /// If `true`, a recovery URL will be generated to recover this Checkout Session if it expires before a successful transaction is completed.
+    ///
+    /// It will be attached to the Checkout Session object upon expiration.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionAutomaticTaxParams {
+    /// Set to true to enable automatic taxes.
+    pub enabled: bool,
+
+    /// The account that's liable for tax.
+    ///
+    /// If set, the business address and tax registrations required to perform the tax calculation are loaded from this account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liability: Option<CheckoutSessionTaxLiability>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldParams<'a> {
+    /// Configuration for `type=dropdown` fields.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropdown: Option<CheckoutSessionCustomFieldDropdownParams<'a>>,
+
+    /// String of your choice that your integration can use to reconcile this field.
+    ///
+    /// Must be unique to this field, alphanumeric, and up to 200 characters.
+    pub key: Cow<'a, str>,
+
+    /// The label for the field, displayed to the customer.
+    #[serde(borrow)]
+    pub label: CheckoutSessionCustomFieldLabelParams<'a>,
+
+    /// Configuration for `type=numeric` fields.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numeric: Option<CheckoutSessionCustomFieldTextParams<'a>>,
+
+    /// Whether the customer is required to complete the field before completing the Checkout Session.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+
+    /// Configuration for `type=text` fields.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<CheckoutSessionCustomFieldTextParams<'a>>,
+
+    /// The type of the field.
+    #[serde(rename = "type")]
+    pub type_: CheckoutSessionCustomFieldType,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldDropdownParams<'a> {
+    /// The value that will pre-fill the field on the payment page.
+    ///
+    /// Must match a `value` in the `options` array.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<Cow<'a, str>>,
+
+    /// The options available for the customer to select.
+    ///
+    /// Up to 200 options allowed.
+    pub options: Vec<CheckoutSessionCustomFieldDropdownOption>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldLabelParams<'a> {
+    /// Custom text for the label, displayed to the customer.
+    ///
+    /// Up to 50 characters.
+    pub custom: Cow<'a, str>,
+
+    /// The type of the label.
+    #[serde(rename = "type")]
+    pub type_: CheckoutSessionCustomFieldLabelType,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomFieldTextParams<'a> {
+    /// The value that will pre-fill the field on the payment page.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<Cow<'a, str>>,
+
+    /// The maximum character length constraint for the customer's input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_length: Option<i64>,
+
+    /// The minimum character length requirement for the customer's input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_length: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionInvoiceCreationParams<'a> {
+    /// Set to `true` to enable invoice creation.
+    pub enabled: bool,
+
+    /// Parameters passed when creating invoices for payment-mode Checkout Sessions.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_data: Option<CheckoutSessionInvoiceDataParams<'a>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionInvoiceDataParams<'a> {
+    /// The account tax IDs associated with the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_tax_ids: Option<Vec<TaxIdId>>,
+
+    /// Default custom fields to be displayed on invoices for this customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomField>>,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Default footer to be displayed on invoices for this customer.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<Cow<'a, str>>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Default options for invoice PDF rendering for this customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendering_options: Option<CheckoutSessionInvoiceRenderingOptions>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionPaymentMethodOptions<'a> {
+    /// Contains details about the AfterpayClearpay payment method options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub afterpay_clearpay: Option<CheckoutSessionPaymentMethodOptionsSetupFutureUsage>,
+
+    /// Contains details about the Card payment method options.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<CheckoutSessionPaymentMethodOptionsCard<'a>>,
+
+    /// Contains details about the Klarna payment method options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub klarna: Option<CheckoutSessionPaymentMethodOptionsSetupFutureUsage>,
+
+    /// Contains details about the Us Bank Account payment method options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<CheckoutSessionPaymentMethodOptionsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionPaymentMethodOptionsCard<'a> {
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<CheckoutSessionSetupFutureUsage>,
+
+    /// Provides information about a card payment that customers see on their statements.
+    ///
+    /// Concatenated with the Kana prefix (shortened Kana descriptor) or Kana statement descriptor that’s set on the account to form the complete statement descriptor.
+    /// Maximum 22 characters.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix_kana: Option<Cow<'a, str>>,
+
+    /// Provides information about a card payment that customers see on their statements.
+    ///
+    /// Concatenated with the Kanji prefix (shortened Kanji descriptor) or Kanji statement descriptor that’s set on the account to form the complete statement descriptor.
+    /// Maximum 17 characters.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix_kanji: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionPaymentMethodOptionsSetupFutureUsage {
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<CheckoutSessionSetupFutureUsage>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionPaymentMethodOptionsUsBankAccount {
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<CheckoutSessionSetupFutureUsage>,
+
+    /// Verification method for the intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<CheckoutSessionUsBankAccountVerificationMethod>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionShippingOptionParams<'a> {
+    /// The ID of the Shipping Rate to use for this shipping option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<ShippingRateId>,
+
+    /// Parameters to be passed to Shipping Rate creation for this shipping option.
+    #[serde(borrow)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate_data: Option<CheckoutShippingRateData<'a>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutShippingRateData<'a> {
+    /// The name of the shipping rate, meant to be displayable to the customer.
+    ///
+    /// This will appear on CheckoutSessions.
+    pub display_name: Cow<'a, str>,
+
+    /// The estimated range for how long shipping will take, meant to be displayable to the customer.
+    ///
+    /// This will appear on CheckoutSessions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_estimate: Option<ShippingRateDeliveryEstimate>,
+
+    /// Describes a fixed amount to charge for shipping.
+    ///
+    /// Must be present if type is `fixed_amount`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<ShippingRateFixedAmount>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+    ///
+    /// The Shipping tax code is `txcd_92010001`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<Cow<'a, str>>,
+
+    /// Specifies whether the rate is considered inclusive of taxes or exclusive of taxes.
+    ///
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<ShippingRateTaxBehavior>,
+
+    /// The type of calculation to use on the shipping rate.
+    ///
+    /// Can only be `fixed_amount` for now.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<ShippingRateType>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -114,48 +689,61 @@ pub struct CheckoutPaymentIntentData<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_amount: Option<u64>,
 
+    /// Controls when the funds will be captured from the customer's account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_method: Option<PaymentIntentCaptureMethod>,
+
     // An arbitrary string attached to the object. Often useful for displaying to users.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
 
     // Set of key-value pairs that you can attach to an object. This can be useful for storing 
     // additional information about the object in a structured format. Individual keys can be 
     // unset by posting an empty value to them. All keys can be unset by posting an empty value to metadata.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<Metadata>,
 
     // The Stripe account ID for which these funds are intended. For details, see the PaymentIntents use 
     #[serde(skip_serializing_if = "Option::is_none")]
     // case for connected accounts.
-    pub on_behalf_of: Option<&'a str>,
+    pub on_behalf_of: Option<Cow<'a, str>>,
 
     // Email address that the receipt for the resulting payment will be sent to. If receipt_email is 
     // specified for a payment in live mode, a receipt will be sent regardless of your email settings.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub receipt_email: Option<&'a str>,
+    pub receipt_email: Option<Cow<'a, str>>,
+
+    /// Indicates that you intend to make future payments with the payment method collected by this Checkout Session.
+    ///
+    /// When setting this to `off_session`, Checkout will show a notice to the customer that their payment details will be saved and used for future payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<CheckoutPaymentIntentSetupFutureUsage>,
 
-    // TODO: setup_future_usage
+    /// Shipping information for this payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingParams>,
 
     // Extra information about the payment. This will appear on your customer’s statement when this payment 
     // succeeds in creating a charge.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statement_descriptor: Option<&'a str>,
+    pub statement_descriptor: Option<StatementDescriptor>,
 
     // Provides information about the charge that customers see on their statements. Concatenated with the 
     // prefix (shortened descriptor) or statement descriptor that’s set on the account to form the complete 
     // statement descriptor. Maximum 22 characters for the concatenated descriptor.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statement_descriptor_suffix: Option<&'a str>,
+    pub statement_descriptor_suffix: Option<StatementDescriptor>,
 
     // The parameters used to automatically create a Transfer when the payment succeeds. 
     // For more information, see the PaymentIntents use case for connected accounts.
+    #[serde(borrow)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transfer_data: Option<CheckoutTransferData<'a>>,
 
     // A string that identifies the resulting payment as part of a group. See the PaymentIntents use case 
     // for connected accounts for details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transfer_group: Option<&'a str>,
+    pub transfer_group: Option<Cow<'a, str>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -163,7 +751,7 @@ pub struct CheckoutTransferData<'a> {
     // If specified, successful charges will be attributed to the destination account for tax reporting, 
     // and the funds from charges will be transferred to the destination account. The ID of the resulting 
     // transfer will be returned on the successful charge’s transfer field.
-    pub destination: &'a str,
+    pub destination: Cow<'a, str>,
 
     // The amount that will be transferred automatically when a charge succeeds.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -177,4 +765,122 @@ impl CheckoutSession {
     pub fn create(client: &Client, params: CreateCheckoutSession) -> Response<CheckoutSession> {
         client.post_form("/checkout/sessions", params)
     }
+
+    /// Retrieves a Session object.
+    ///
+    /// Expand `line_items`, `payment_intent` or `customer` to fetch what fulfillment needs in a single request.
+    pub fn retrieve(
+        client: &Client,
+        id: &CheckoutSessionId,
+        expand: &[&str],
+    ) -> Response<CheckoutSession> {
+        client.get_query(&format!("/checkout/sessions/{}", id), &Expand { expand })
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutPaymentIntentData`'s `setup_future_usage` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutPaymentIntentSetupFutureUsage {
+    OffSession,
+    OnSession,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutPaymentIntentSetupFutureUsage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutPaymentIntentSetupFutureUsage::OffSession => "off_session",
+            CheckoutPaymentIntentSetupFutureUsage::OnSession => "on_session",
+            CheckoutPaymentIntentSetupFutureUsage::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutPaymentIntentSetupFutureUsage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutPaymentIntentSetupFutureUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionPaymentMethodOptionsCard`'s `setup_future_usage` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionSetupFutureUsage {
+    None,
+    OffSession,
+    OnSession,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionSetupFutureUsage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionSetupFutureUsage::None => "none",
+            CheckoutSessionSetupFutureUsage::OffSession => "off_session",
+            CheckoutSessionSetupFutureUsage::OnSession => "on_session",
+            CheckoutSessionSetupFutureUsage::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionSetupFutureUsage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionSetupFutureUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionPaymentMethodOptionsUsBankAccount`'s `verification_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionUsBankAccountVerificationMethod {
+    Automatic,
+    Instant,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CheckoutSessionUsBankAccountVerificationMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionUsBankAccountVerificationMethod::Automatic => "automatic",
+            CheckoutSessionUsBankAccountVerificationMethod::Instant => "instant",
+            CheckoutSessionUsBankAccountVerificationMethod::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionUsBankAccountVerificationMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionUsBankAccountVerificationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
 }
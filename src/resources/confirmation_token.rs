@@ -0,0 +1,147 @@
+use crate::config::{Client, Response};
+use crate::ids::{ConfirmationTokenId, PaymentIntentId, SetupIntentId};
+use crate::params::{Expand, Object, Timestamp};
+use crate::resources::{
+    BillingDetails, CardDetails, CardPresent, PaymentMethodAuBecsDebit, PaymentMethodFpx,
+    PaymentMethodIdeal, PaymentMethodSepaDebit, PaymentMethodType, Shipping,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "ConfirmationToken".
+///
+/// ConfirmationTokens help transport client side data collected by Stripe JS over
+/// to your server for confirming a PaymentIntent or SetupIntent.
+///
+/// For more details see [https://stripe.com/docs/api/confirmation_tokens](https://stripe.com/docs/api/confirmation_tokens).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConfirmationToken {
+    /// Unique identifier for the object.
+    pub id: ConfirmationTokenId,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Time at which this ConfirmationToken expires and can no longer be used to confirm a PaymentIntent or SetupIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// ID of the PaymentIntent that this ConfirmationToken was used to confirm, or null if this ConfirmationToken has not yet been used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<PaymentIntentId>,
+
+    /// Payment details collected by the Payment Element, used to create a PaymentMethod when a PaymentIntent or SetupIntent is confirmed with this ConfirmationToken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_preview: Option<ConfirmationTokenPaymentMethodPreview>,
+
+    /// Return URL used to confirm the Intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+
+    /// Indicates that you intend to make future payments with this ConfirmationToken's payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<ConfirmationTokenSetupFutureUsage>,
+
+    /// ID of the SetupIntent that this ConfirmationToken was used to confirm, or null if this ConfirmationToken has not yet been used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_intent: Option<SetupIntentId>,
+
+    /// Shipping information collected on this ConfirmationToken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+
+    /// Indicates whether the Stripe SDK is used to handle confirmation flow.
+    ///
+    /// Defaults to `true` on ConfirmationToken.
+    pub use_stripe_sdk: bool,
+}
+
+impl ConfirmationToken {
+    /// Retrieves an existing ConfirmationToken object.
+    pub fn retrieve(
+        client: &Client,
+        id: &ConfirmationTokenId,
+        expand: &[&str],
+    ) -> Response<ConfirmationToken> {
+        client.get_query(&format!("/confirmation_tokens/{}", id), &Expand { expand })
+    }
+}
+
+impl Object for ConfirmationToken {
+    type Id = ConfirmationTokenId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "confirmation_token"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConfirmationTokenPaymentMethodPreview {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub au_becs_debit: Option<PaymentMethodAuBecsDebit>,
+
+    pub billing_details: BillingDetails,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<CardDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_present: Option<CardPresent>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fpx: Option<PaymentMethodFpx>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ideal: Option<PaymentMethodIdeal>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<PaymentMethodSepaDebit>,
+
+    /// The type of the PaymentMethod.
+    ///
+    /// An additional hash is included on the PaymentMethod with a name matching this value.
+    /// It contains additional information specific to the PaymentMethod type.
+    #[serde(rename = "type")]
+    pub type_: PaymentMethodType,
+}
+
+/// An enum representing the possible values of an `ConfirmationToken`'s `setup_future_usage` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmationTokenSetupFutureUsage {
+    OffSession,
+    OnSession,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl ConfirmationTokenSetupFutureUsage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ConfirmationTokenSetupFutureUsage::OffSession => "off_session",
+            ConfirmationTokenSetupFutureUsage::OnSession => "on_session",
+            ConfirmationTokenSetupFutureUsage::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for ConfirmationTokenSetupFutureUsage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ConfirmationTokenSetupFutureUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -291,6 +291,11 @@ pub enum CouponDuration {
     Forever,
     Once,
     Repeating,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CouponDuration {
@@ -299,6 +304,7 @@ impl CouponDuration {
             CouponDuration::Forever => "forever",
             CouponDuration::Once => "once",
             CouponDuration::Repeating => "repeating",
+            CouponDuration::Other => "other",
         }
     }
 }
@@ -284,6 +284,11 @@ pub enum Currency {
     ZAR, // South African Rand
     #[serde(rename = "zmw")]
     ZMW, // Zambian Kwacha
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl Default for Currency {
@@ -461,3 +466,136 @@ impl std::error::Error for ParseCurrencyError {
         "unknown currency code"
     }
 }
+
+impl Currency {
+    /// The number of decimal places in the currency's minor unit, as used by Stripe.
+    ///
+    /// Zero-decimal currencies such as JPY and KRW are sent to Stripe in whole units.
+    /// For more details see https://stripe.com/docs/currencies#zero-decimal.
+    ///
+    /// Returns `None` for `Currency::Other`, whose minor unit isn't known.
+    pub fn decimal_places(self) -> Option<u32> {
+        match self {
+            Currency::Other => None,
+            _ if self.is_zero_decimal() => Some(0),
+            _ => Some(2),
+        }
+    }
+
+    /// Whether amounts in this currency are expressed in whole units rather than cents.
+    pub fn is_zero_decimal(self) -> bool {
+        matches!(
+            self,
+            Currency::BIF
+                | Currency::CLP
+                | Currency::DJF
+                | Currency::GNF
+                | Currency::JPY
+                | Currency::KMF
+                | Currency::KRW
+                | Currency::MGA
+                | Currency::PYG
+                | Currency::RWF
+                | Currency::UGX
+                | Currency::VND
+                | Currency::VUV
+                | Currency::XAF
+                | Currency::XOF
+                | Currency::XPF
+        )
+    }
+}
+
+/// An amount in the smallest unit of its currency, which is how Stripe expects amounts.
+///
+/// The minor unit is the cent for USD and the yen itself for JPY, so `Money::from_major(10, Currency::USD)`
+/// is `1000` while `Money::from_major(10, Currency::JPY)` is `10`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Money {
+    pub amount_minor: i64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount_minor: i64, currency: Currency) -> Self {
+        Money { amount_minor, currency }
+    }
+
+    /// Creates an amount from a number of whole units, e.g. dollars or yen.
+    ///
+    /// Returns `None` if the currency's minor unit isn't known or the amount overflows.
+    pub fn from_major(amount: i64, currency: Currency) -> Option<Self> {
+        let scale = 10i64.pow(currency.decimal_places()?);
+        Some(Money { amount_minor: amount.checked_mul(scale)?, currency })
+    }
+
+    /// Parses a decimal amount in whole units, e.g. `"12.34"` dollars or `"1000"` yen.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows, or if the
+    /// currency's minor unit isn't known.
+    pub fn from_decimal_str(amount: &str, currency: Currency) -> Result<Self, ParseMoneyError> {
+        let places = currency.decimal_places().ok_or(ParseMoneyError(()))? as usize;
+        let (negative, digits) = match amount.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, amount),
+        };
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, fraction),
+            None => (digits, ""),
+        };
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) || fraction.len() > places
+        {
+            return Err(ParseMoneyError(()));
+        }
+        let padded = format!("{}{:0<width$}", whole, fraction, width = places);
+        let amount_minor = padded.parse::<i64>().map_err(|_| ParseMoneyError(()))?;
+        Ok(Money { amount_minor: if negative { -amount_minor } else { amount_minor }, currency })
+    }
+
+    /// The amount in whole units, truncating any minor units.
+    ///
+    /// Returns `None` if the currency's minor unit isn't known.
+    pub fn major_amount(&self) -> Option<i64> {
+        Some(self.amount_minor / 10i64.pow(self.currency.decimal_places()?))
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // N.B. Amounts in a currency whose minor unit isn't known are shown in minor units.
+        let places = self.currency.decimal_places().unwrap_or(0);
+        let sign = if self.amount_minor < 0 { "-" } else { "" };
+        let amount = self.amount_minor.unsigned_abs();
+        let scale = 10u64.pow(places);
+        if places == 0 {
+            write!(f, "{}{} {}", sign, amount, self.currency)
+        } else {
+            write!(
+                f,
+                "{}{}.{:0width$} {}",
+                sign,
+                amount / scale,
+                amount % scale,
+                self.currency,
+                width = places as usize
+            )
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseMoneyError(/* private */ ());
+
+impl std::fmt::Display for ParseMoneyError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[allow(deprecated)]
+        fmt.write_str(::std::error::Error::description(self))
+    }
+}
+
+impl std::error::Error for ParseMoneyError {
+    fn description(&self) -> &str {
+        "invalid amount for currency"
+    }
+}
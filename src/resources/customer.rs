@@ -5,11 +5,14 @@
 use crate::config::{Client, Response};
 use crate::ids::{
     AlipayAccountId, BankAccountId, CardId, CouponId, CustomerId, PaymentMethodId, PaymentSourceId,
+    TestHelpersTestClockId,
+};
+use crate::params::{
+    Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp, UpdateField,
 };
-use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
     Address, Currency, CustomField, Discount, PaymentMethod, PaymentSource, PaymentSourceParams,
-    Scheduled, Shipping, ShippingParams, Subscription, TaxId,
+    Scheduled, Shipping, ShippingParams, Subscription, TaxId, TestHelpersTestClock,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -129,6 +132,10 @@ pub struct Customer {
     /// The customer's tax IDs.
     #[serde(default)]
     pub tax_ids: List<TaxId>,
+
+    /// ID of the test clock this customer belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<Expandable<TestHelpersTestClock>>,
 }
 
 impl Customer {
@@ -299,6 +306,10 @@ pub struct CreateCustomer<'a> {
     /// The customer's tax IDs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_id_data: Option<Vec<TaxIdData>>,
+
+    /// ID of the test clock to attach to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<TestHelpersTestClockId>,
 }
 
 impl<'a> CreateCustomer<'a> {
@@ -322,6 +333,7 @@ impl<'a> CreateCustomer<'a> {
             source: Default::default(),
             tax_exempt: Default::default(),
             tax_id_data: Default::default(),
+            test_clock: Default::default(),
         }
     }
 }
@@ -329,6 +341,7 @@ impl<'a> CreateCustomer<'a> {
 /// The parameters for `Customer::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListCustomers<'a> {
+    /// Only return customers that were created during the given date interval.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<RangeQuery<Timestamp>>,
 
@@ -361,6 +374,12 @@ pub struct ListCustomers<'a> {
     /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_after: Option<CustomerId>,
+
+    /// Provides a list of customers that are associated with the specified test clock.
+    ///
+    /// The response will not include customers with test clocks if this parameter is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<TestHelpersTestClockId>,
 }
 
 impl<'a> ListCustomers<'a> {
@@ -372,6 +391,7 @@ impl<'a> ListCustomers<'a> {
             expand: Default::default(),
             limit: Default::default(),
             starting_after: Default::default(),
+            test_clock: Default::default(),
         }
     }
 }
@@ -380,8 +400,8 @@ impl<'a> ListCustomers<'a> {
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateCustomer<'a> {
     /// The customer's address.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "UpdateField::is_keep")]
+    pub address: UpdateField<Address>,
 
     /// An integer amount in %s that represents the customer's current balance, which affect the customer's future invoices.
     ///
@@ -415,15 +435,15 @@ pub struct UpdateCustomer<'a> {
     /// An arbitrary string that you can attach to a customer object.
     ///
     /// It is displayed alongside the customer in the dashboard.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "UpdateField::is_keep")]
+    pub description: UpdateField<&'a str>,
 
     /// Customer's email address.
     ///
     /// It's displayed alongside the customer in your dashboard and can be useful for searching and tracking.
     /// This may be up to *512 characters*.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "UpdateField::is_keep")]
+    pub email: UpdateField<&'a str>,
 
     /// Specifies which fields in the response should be expanded.
     #[serde(skip_serializing_if = "Expand::is_empty")]
@@ -444,12 +464,12 @@ pub struct UpdateCustomer<'a> {
     /// This can be useful for storing additional information about the object in a structured format.
     /// Individual keys can be unset by posting an empty value to them.
     /// All keys can be unset by posting an empty value to `metadata`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "UpdateField::is_keep")]
+    pub metadata: UpdateField<Metadata>,
 
     /// The customer's full name or business name.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<&'a str>,
+    #[serde(skip_serializing_if = "UpdateField::is_keep")]
+    pub name: UpdateField<&'a str>,
 
     /// The sequence to be used on the customer's next invoice.
     ///
@@ -458,8 +478,8 @@ pub struct UpdateCustomer<'a> {
     pub next_invoice_sequence: Option<i64>,
 
     /// The customer's phone number.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "UpdateField::is_keep")]
+    pub phone: UpdateField<&'a str>,
 
     /// Customer's preferred languages, ordered by preference.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -468,8 +488,8 @@ pub struct UpdateCustomer<'a> {
     /// The customer's shipping information.
     ///
     /// Appears on invoices emailed to this customer.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<ShippingParams>,
+    #[serde(skip_serializing_if = "UpdateField::is_keep")]
+    pub shipping: UpdateField<ShippingParams>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<PaymentSourceParams>,
@@ -545,6 +565,11 @@ pub enum CustomerTaxExempt {
     Exempt,
     None,
     Reverse,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CustomerTaxExempt {
@@ -553,6 +578,7 @@ impl CustomerTaxExempt {
             CustomerTaxExempt::Exempt => "exempt",
             CustomerTaxExempt::None => "none",
             CustomerTaxExempt::Reverse => "reverse",
+            CustomerTaxExempt::Other => "other",
         }
     }
 }
@@ -576,6 +602,11 @@ pub enum CustomerTaxExemptFilter {
     Exempt,
     None,
     Reverse,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CustomerTaxExemptFilter {
@@ -584,6 +615,7 @@ impl CustomerTaxExemptFilter {
             CustomerTaxExemptFilter::Exempt => "exempt",
             CustomerTaxExemptFilter::None => "none",
             CustomerTaxExemptFilter::Reverse => "reverse",
+            CustomerTaxExemptFilter::Other => "other",
         }
     }
 }
@@ -629,6 +661,11 @@ pub enum TaxIdType {
     TwVat,
     UsEin,
     ZaVat,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TaxIdType {
@@ -659,6 +696,7 @@ impl TaxIdType {
             TaxIdType::TwVat => "tw_vat",
             TaxIdType::UsEin => "us_ein",
             TaxIdType::ZaVat => "za_vat",
+            TaxIdType::Other => "other",
         }
     }
 }
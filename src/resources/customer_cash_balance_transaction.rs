@@ -0,0 +1,234 @@
+use crate::config::{Client, Response};
+use crate::ids::{CustomerCashBalanceTransactionId, CustomerId};
+use crate::params::{Expand, Expandable, List, Object, Timestamp};
+use crate::resources::{BalanceTransaction, Currency, Customer, PaymentIntent, Refund};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "CustomerCashBalanceTransaction".
+///
+/// Customers with certain payments enabled have a cash balance, representing funds that were paid
+/// by the customer to a merchant, but have not yet been allocated to a payment.
+/// Cash Balance Transactions represent when funds are moved into or out of this balance.
+/// This includes funding by the customer, allocation to payments, and refunds to the customer.
+///
+/// For more details see [https://stripe.com/docs/api/cash_balance_transactions/object](https://stripe.com/docs/api/cash_balance_transactions/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerCashBalanceTransaction {
+    /// Unique identifier for the object.
+    pub id: CustomerCashBalanceTransactionId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_to_payment: Option<CustomerBalanceResourceCashBalanceTransactionAppliedToPayment>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The customer whose available cash balance changed as a result of this transaction.
+    pub customer: Expandable<Customer>,
+
+    /// The total available cash balance for the specified currency after this transaction was applied.
+    ///
+    /// Represented in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    pub ending_balance: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funded: Option<CustomerBalanceResourceCashBalanceTransactionFunded>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The amount by which the cash balance changed, represented in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    ///
+    /// A positive value represents funds being added to the cash balance, a negative value represents funds being removed from the cash balance.
+    pub net_amount: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refunded_from_payment:
+        Option<CustomerBalanceResourceCashBalanceTransactionRefundedFromPayment>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transferred_to_balance:
+        Option<CustomerBalanceResourceCashBalanceTransactionTransferredToBalance>,
+
+    /// The type of the cash balance transaction.
+    ///
+    /// New types may be added in future.
+    /// See [Customer Balance](https://stripe.com/docs/payments/customer-balance#types) to learn more about these types.
+    #[serde(rename = "type")]
+    pub type_: CustomerCashBalanceTransactionType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unapplied_from_payment:
+        Option<CustomerBalanceResourceCashBalanceTransactionUnappliedFromPayment>,
+}
+
+impl CustomerCashBalanceTransaction {
+    /// Returns a list of transactions that modified the customer's cash balance.
+    pub fn list(
+        client: &Client,
+        customer_id: &CustomerId,
+        params: ListCustomerCashBalanceTransactions<'_>,
+    ) -> Response<List<CustomerCashBalanceTransaction>> {
+        client.get_query(&format!("/customers/{}/cash_balance_transactions", customer_id), &params)
+    }
+
+    /// Retrieves a specific cash balance transaction, which updated the customer's cash balance.
+    pub fn retrieve(
+        client: &Client,
+        customer_id: &CustomerId,
+        id: &CustomerCashBalanceTransactionId,
+        expand: &[&str],
+    ) -> Response<CustomerCashBalanceTransaction> {
+        client.get_query(
+            &format!("/customers/{}/cash_balance_transactions/{}", customer_id, id),
+            &Expand { expand },
+        )
+    }
+}
+
+impl Object for CustomerCashBalanceTransaction {
+    type Id = CustomerCashBalanceTransactionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "customer_cash_balance_transaction"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerBalanceResourceCashBalanceTransactionAppliedToPayment {
+    /// The [Payment Intent](https://stripe.com/docs/api/payment_intents/object) that funds were applied to.
+    pub payment_intent: Expandable<PaymentIntent>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerBalanceResourceCashBalanceTransactionFunded {
+    pub bank_transfer: CustomerBalanceResourceCashBalanceTransactionFundedBankTransfer,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerBalanceResourceCashBalanceTransactionFundedBankTransfer {
+    /// The user-supplied reference field on the bank transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// The funding method type used to fund the customer balance.
+    ///
+    /// Permitted values include: `eu_bank_transfer`, `gb_bank_transfer`, `jp_bank_transfer`, `mx_bank_transfer`, or `us_bank_transfer`.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerBalanceResourceCashBalanceTransactionRefundedFromPayment {
+    /// The [Refund](https://stripe.com/docs/api/refunds/object) that moved these funds into the customer's cash balance.
+    pub refund: Expandable<Refund>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerBalanceResourceCashBalanceTransactionTransferredToBalance {
+    /// The [Balance Transaction](https://stripe.com/docs/api/balance_transactions/object) that corresponds to funds transferred to your Stripe balance.
+    pub balance_transaction: Expandable<BalanceTransaction>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerBalanceResourceCashBalanceTransactionUnappliedFromPayment {
+    /// The [Payment Intent](https://stripe.com/docs/api/payment_intents/object) that funds were unapplied from.
+    pub payment_intent: Expandable<PaymentIntent>,
+}
+
+/// The parameters for `CustomerCashBalanceTransaction::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListCustomerCashBalanceTransactions<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<CustomerCashBalanceTransactionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<CustomerCashBalanceTransactionId>,
+}
+
+impl<'a> ListCustomerCashBalanceTransactions<'a> {
+    pub fn new() -> Self {
+        ListCustomerCashBalanceTransactions {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// An enum representing the possible values of an `CustomerCashBalanceTransaction`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerCashBalanceTransactionType {
+    AdjustedForOverdraft,
+    AppliedToPayment,
+    Funded,
+    FundingReversed,
+    RefundedFromPayment,
+    ReturnCanceled,
+    ReturnInitiated,
+    TransferredToBalance,
+    UnappliedFromPayment,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CustomerCashBalanceTransactionType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CustomerCashBalanceTransactionType::AdjustedForOverdraft => "adjusted_for_overdraft",
+            CustomerCashBalanceTransactionType::AppliedToPayment => "applied_to_payment",
+            CustomerCashBalanceTransactionType::Funded => "funded",
+            CustomerCashBalanceTransactionType::FundingReversed => "funding_reversed",
+            CustomerCashBalanceTransactionType::RefundedFromPayment => "refunded_from_payment",
+            CustomerCashBalanceTransactionType::ReturnCanceled => "return_canceled",
+            CustomerCashBalanceTransactionType::ReturnInitiated => "return_initiated",
+            CustomerCashBalanceTransactionType::TransferredToBalance => "transferred_to_balance",
+            CustomerCashBalanceTransactionType::UnappliedFromPayment => "unapplied_from_payment",
+            CustomerCashBalanceTransactionType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CustomerCashBalanceTransactionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CustomerCashBalanceTransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
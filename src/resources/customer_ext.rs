@@ -1,7 +1,12 @@
 use crate::config::{Client, Response};
-use crate::ids::{BankAccountId, CardId, CustomerId, PaymentSourceId};
-use crate::params::Deleted;
-use crate::resources::{BankAccount, Customer, PaymentSource, PaymentSourceParams, Source};
+use crate::ids::{BankAccountId, CardId, CustomerId, PaymentMethodId, PaymentSourceId};
+use crate::params::{Deleted, Expand, List};
+#[cfg(feature = "billing")]
+use crate::resources::Discount;
+use crate::resources::{
+    BankAccount, Customer, PaymentMethod, PaymentMethodTypeFilter, PaymentSource,
+    PaymentSourceParams, Source, UpdateCustomer,
+};
 use serde_derive::{Deserialize, Serialize};
 
 impl Customer {
@@ -32,6 +37,19 @@ impl Customer {
         client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 
+    /// Sets the Card, BankAccount, or Source used by default to pay a Customer's invoices and charges.
+    ///
+    /// The source must already be attached to the customer, e.g. with `Customer::attach_source`.
+    pub fn set_default_source(
+        client: &Client,
+        customer_id: &CustomerId,
+        source_id: &PaymentSourceId,
+    ) -> Response<Customer> {
+        let mut params = UpdateCustomer::new();
+        params.default_source = Some(source_id.clone());
+        Customer::update(client, customer_id, params)
+    }
+
     /// Retrieves a Card, BankAccount, or Source for a Customer
     pub fn retrieve_source(
         client: &Client,
@@ -41,6 +59,32 @@ impl Customer {
         client.get(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 
+    /// Returns a list of PaymentMethods for a given Customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/customer_list.
+    pub fn list_payment_methods(
+        client: &Client,
+        customer_id: &CustomerId,
+        params: ListCustomerPaymentMethods<'_>,
+    ) -> Response<List<PaymentMethod>> {
+        client.get_query(&format!("/customers/{}/payment_methods", customer_id), &params)
+    }
+
+    /// Retrieves a PaymentMethod object for a given Customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/customer.
+    pub fn retrieve_payment_method(
+        client: &Client,
+        customer_id: &CustomerId,
+        payment_method_id: &PaymentMethodId,
+        expand: &[&str],
+    ) -> Response<PaymentMethod> {
+        client.get_query(
+            &format!("/customers/{}/payment_methods/{}", customer_id, payment_method_id),
+            &Expand { expand },
+        )
+    }
+
     /// Verifies a Bank Account for a Customer.
     ///
     /// For more details see https://stripe.com/docs/api/customer_bank_accounts/verify.
@@ -55,6 +99,14 @@ impl Customer {
             params,
         )
     }
+
+    /// Removes the currently applied discount on a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/discounts/delete.
+    #[cfg(feature = "billing")]
+    pub fn delete_discount(client: &Client, customer_id: &CustomerId) -> Response<Discount> {
+        client.delete(&format!("/customers/{}/discount", customer_id))
+    }
 }
 
 /// The set of parameters that can be used when verifying a Bank Account.
@@ -74,6 +126,52 @@ impl VerifyBankAccount<'_> {
     }
 }
 
+/// The parameters for `Customer::list_payment_methods`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListCustomerPaymentMethods<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<PaymentMethodId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<PaymentMethodId>,
+
+    /// An optional filter on the list, based on the object `type` field.
+    ///
+    /// Without the filter, the list includes all current and future payment method types.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<PaymentMethodTypeFilter>,
+}
+
+impl<'a> ListCustomerPaymentMethods<'a> {
+    pub fn new() -> Self {
+        ListCustomerPaymentMethods {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            type_: Default::default(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "object", rename_all = "snake_case")]
 pub enum DetachedSource {
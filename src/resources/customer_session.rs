@@ -0,0 +1,252 @@
+use crate::config::{Client, Response};
+use crate::ids::CustomerId;
+use crate::params::{Expand, Expandable, Object, Timestamp};
+use crate::resources::Customer;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "CustomerSession".
+///
+/// A Customer Session allows you to grant Stripe's frontend SDKs (like Stripe.js) client-side access
+/// control over a Customer.
+///
+/// For more details see [https://stripe.com/docs/api/customer_sessions](https://stripe.com/docs/api/customer_sessions).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerSession {
+    /// The client secret of this Customer Session.
+    ///
+    /// Used on the client to set up secure access to the given `customer`.
+    /// The client secret can be used to provide access to `customer` from your frontend.
+    /// It should not be stored, logged, or exposed to anyone other than the relevant customer.
+    pub client_secret: String,
+
+    /// Configuration for the components supported by this Customer Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<CustomerSessionComponents>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// The Customer the Customer Session was created for.
+    pub customer: Expandable<Customer>,
+
+    /// The timestamp at which this Customer Session will expire.
+    pub expires_at: Timestamp,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+}
+
+impl CustomerSession {
+    /// Creates a Customer Session object that includes a single-use client secret that you can use on your front-end to grant client-side API access for certain customer resources.
+    pub fn create(client: &Client, params: CreateCustomerSession<'_>) -> Response<CustomerSession> {
+        client.post_form("/customer_sessions", &params)
+    }
+}
+
+impl Object for CustomerSession {
+    type Id = ();
+    fn id(&self) -> Self::Id {}
+    fn object(&self) -> &'static str {
+        "customer_session"
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CustomerSessionComponents {
+    /// Configuration for buy button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buy_button: Option<CustomerSessionComponent>,
+
+    /// Configuration for the Payment Element.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_element: Option<CustomerSessionPaymentElement>,
+
+    /// Configuration for the pricing table.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pricing_table: Option<CustomerSessionComponent>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CustomerSessionComponent {
+    /// Whether the component is enabled.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CustomerSessionPaymentElement {
+    /// Whether the Payment Element is enabled.
+    pub enabled: bool,
+
+    /// This hash defines whether the Payment Element supports certain features.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<CustomerSessionPaymentElementFeatures>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CustomerSessionPaymentElementFeatures {
+    /// A list of [`allow_redisplay`](https://docs.stripe.com/api/payment_methods/object#payment_method_object-allow_redisplay) values that controls which saved payment methods the Payment Element displays by filtering to only show payment methods with an `allow_redisplay` value that is present in this list.
+    ///
+    /// If not specified, defaults to ["always"].
+    /// In order to display all saved payment methods, specify ["always", "limited", "unspecified"].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_allow_redisplay_filters: Option<Vec<CustomerSessionAllowRedisplay>>,
+
+    /// Controls whether or not the Payment Element shows saved payment methods.
+    ///
+    /// This parameter defaults to `disabled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_redisplay: Option<CustomerSessionFeatureStatus>,
+
+    /// Determines the max number of saved payment methods for the Payment Element to display.
+    ///
+    /// This parameter defaults to `3`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_redisplay_limit: Option<u64>,
+
+    /// Controls whether the Payment Element displays the option to remove a saved payment method.
+    ///
+    /// This parameter defaults to `disabled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_remove: Option<CustomerSessionFeatureStatus>,
+
+    /// Controls whether the Payment Element displays a checkbox offering to save a new payment method.
+    ///
+    /// This parameter defaults to `disabled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_save: Option<CustomerSessionFeatureStatus>,
+
+    /// When using PaymentIntents and the customer checks the save checkbox, this field determines the [`setup_future_usage`](https://docs.stripe.com/api/payment_intents/object#payment_intent_object-setup_future_usage) value used to confirm the PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_save_usage: Option<CustomerSessionPaymentMethodSaveUsage>,
+}
+
+/// The parameters for `CustomerSession::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCustomerSession<'a> {
+    /// Configuration for each component.
+    ///
+    /// Exactly 1 component must be enabled.
+    pub components: CustomerSessionComponents,
+
+    /// The ID of an existing customer for which to create the Customer Session.
+    pub customer: CustomerId,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CreateCustomerSession<'a> {
+    pub fn new(customer: CustomerId, components: CustomerSessionComponents) -> Self {
+        CreateCustomerSession { components, customer, expand: Default::default() }
+    }
+}
+
+/// An enum representing the possible values of an `CustomerSessionPaymentElementFeatures`'s `payment_method_allow_redisplay_filters` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerSessionAllowRedisplay {
+    Always,
+    Limited,
+    Unspecified,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CustomerSessionAllowRedisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CustomerSessionAllowRedisplay::Always => "always",
+            CustomerSessionAllowRedisplay::Limited => "limited",
+            CustomerSessionAllowRedisplay::Unspecified => "unspecified",
+            CustomerSessionAllowRedisplay::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CustomerSessionAllowRedisplay {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CustomerSessionAllowRedisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CustomerSessionPaymentElementFeatures`'s `payment_method_redisplay` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerSessionFeatureStatus {
+    Disabled,
+    Enabled,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CustomerSessionFeatureStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CustomerSessionFeatureStatus::Disabled => "disabled",
+            CustomerSessionFeatureStatus::Enabled => "enabled",
+            CustomerSessionFeatureStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CustomerSessionFeatureStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CustomerSessionFeatureStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CustomerSessionPaymentElementFeatures`'s `payment_method_save_usage` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerSessionPaymentMethodSaveUsage {
+    OffSession,
+    OnSession,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CustomerSessionPaymentMethodSaveUsage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CustomerSessionPaymentMethodSaveUsage::OffSession => "off_session",
+            CustomerSessionPaymentMethodSaveUsage::OnSession => "on_session",
+            CustomerSessionPaymentMethodSaveUsage::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CustomerSessionPaymentMethodSaveUsage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CustomerSessionPaymentMethodSaveUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
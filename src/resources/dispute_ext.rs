@@ -0,0 +1,143 @@
+use crate::config::{Client, Response};
+use crate::ids::{DisputeId, FileId};
+use crate::resources::{
+    CreateFile, Dispute, File, FilePurpose, UpdateDispute, UpdateDisputeEvidence,
+};
+use serde_derive::{Deserialize, Serialize};
+
+impl Dispute {
+    /// Uploads `files` with the `dispute_evidence` purpose, then submits them along with `evidence`.
+    ///
+    /// Each uploaded file is set as the evidence field given by its `kind`.
+    /// If an upload fails the evidence is not submitted, though files which were already uploaded remain in Stripe.
+    #[cfg(feature = "blocking")]
+    pub fn submit_evidence_with_files(
+        client: &Client,
+        id: &DisputeId,
+        evidence: UpdateDisputeEvidence,
+        files: &[DisputeEvidenceFile<'_>],
+    ) -> Response<Dispute> {
+        let mut evidence = evidence;
+        for file in files {
+            let uploaded = File::create(client, file.params())?;
+            file.kind.set(&mut evidence, uploaded.id);
+        }
+        Dispute::update(client, id, UpdateDispute::submit(evidence))
+    }
+
+    /// Uploads `files` with the `dispute_evidence` purpose, then submits them along with `evidence`.
+    ///
+    /// Each uploaded file is set as the evidence field given by its `kind`.
+    /// If an upload fails the evidence is not submitted, though files which were already uploaded remain in Stripe.
+    #[cfg(not(feature = "blocking"))]
+    pub fn submit_evidence_with_files(
+        client: &Client,
+        id: &DisputeId,
+        evidence: UpdateDisputeEvidence,
+        files: &[DisputeEvidenceFile<'_>],
+    ) -> Response<Dispute> {
+        // N.B. The uploads are only sent when awaited, so they are still made one at a time.
+        let uploads: Vec<_> =
+            files.iter().map(|file| (file.kind, File::create(client, file.params()))).collect();
+        let client = client.clone();
+        let id = id.clone();
+        Box::pin(async move {
+            let mut evidence = evidence;
+            for (kind, upload) in uploads {
+                kind.set(&mut evidence, upload.await?.id);
+            }
+            Dispute::update(&client, &id, UpdateDispute::submit(evidence)).await
+        })
+    }
+}
+
+impl UpdateDispute<'_> {
+    fn submit(evidence: UpdateDisputeEvidence) -> Self {
+        UpdateDispute { evidence: Some(evidence), submit: Some(true), ..UpdateDispute::new() }
+    }
+}
+
+/// A file to upload as dispute evidence with `Dispute::submit_evidence_with_files`.
+#[derive(Copy, Clone, Debug)]
+pub struct DisputeEvidenceFile<'a> {
+    /// The evidence field the uploaded file is set as.
+    pub kind: DisputeEvidenceFileKind,
+
+    /// The name the file is uploaded with, which Stripe uses to infer its type.
+    pub filename: &'a str,
+
+    /// The contents of the file.
+    pub contents: &'a [u8],
+}
+
+impl<'a> DisputeEvidenceFile<'a> {
+    pub fn new(kind: DisputeEvidenceFileKind, filename: &'a str, contents: &'a [u8]) -> Self {
+        DisputeEvidenceFile { kind, filename, contents }
+    }
+
+    fn params(&self) -> CreateFile<'a> {
+        CreateFile::new(self.contents, self.filename, FilePurpose::DisputeEvidence)
+    }
+}
+
+/// The file fields of `UpdateDisputeEvidence`.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeEvidenceFileKind {
+    CancellationPolicy,
+    CustomerCommunication,
+    CustomerSignature,
+    DuplicateChargeDocumentation,
+    Receipt,
+    RefundPolicy,
+    ServiceDocumentation,
+    ShippingDocumentation,
+    UncategorizedFile,
+}
+
+impl DisputeEvidenceFileKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DisputeEvidenceFileKind::CancellationPolicy => "cancellation_policy",
+            DisputeEvidenceFileKind::CustomerCommunication => "customer_communication",
+            DisputeEvidenceFileKind::CustomerSignature => "customer_signature",
+            DisputeEvidenceFileKind::DuplicateChargeDocumentation => {
+                "duplicate_charge_documentation"
+            }
+            DisputeEvidenceFileKind::Receipt => "receipt",
+            DisputeEvidenceFileKind::RefundPolicy => "refund_policy",
+            DisputeEvidenceFileKind::ServiceDocumentation => "service_documentation",
+            DisputeEvidenceFileKind::ShippingDocumentation => "shipping_documentation",
+            DisputeEvidenceFileKind::UncategorizedFile => "uncategorized_file",
+        }
+    }
+
+    fn set(self, evidence: &mut UpdateDisputeEvidence, file: FileId) {
+        let field = match self {
+            DisputeEvidenceFileKind::CancellationPolicy => &mut evidence.cancellation_policy,
+            DisputeEvidenceFileKind::CustomerCommunication => &mut evidence.customer_communication,
+            DisputeEvidenceFileKind::CustomerSignature => &mut evidence.customer_signature,
+            DisputeEvidenceFileKind::DuplicateChargeDocumentation => {
+                &mut evidence.duplicate_charge_documentation
+            }
+            DisputeEvidenceFileKind::Receipt => &mut evidence.receipt,
+            DisputeEvidenceFileKind::RefundPolicy => &mut evidence.refund_policy,
+            DisputeEvidenceFileKind::ServiceDocumentation => &mut evidence.service_documentation,
+            DisputeEvidenceFileKind::ShippingDocumentation => &mut evidence.shipping_documentation,
+            DisputeEvidenceFileKind::UncategorizedFile => &mut evidence.uncategorized_file,
+        };
+        *field = Some(file);
+    }
+}
+
+impl AsRef<str> for DisputeEvidenceFileKind {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for DisputeEvidenceFileKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
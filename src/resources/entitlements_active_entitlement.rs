@@ -0,0 +1,99 @@
+use crate::config::{Client, Response};
+use crate::ids::{CustomerId, EntitlementsActiveEntitlementId};
+use crate::params::{Expand, Expandable, List, Object};
+use crate::resources::EntitlementsFeature;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "ActiveEntitlement".
+///
+/// An active entitlement describes access to a feature for a customer.
+///
+/// For more details see [https://stripe.com/docs/api/entitlements/active-entitlement](https://stripe.com/docs/api/entitlements/active-entitlement).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EntitlementsActiveEntitlement {
+    /// Unique identifier for the object.
+    pub id: EntitlementsActiveEntitlementId,
+
+    /// The [Feature](https://stripe.com/docs/api/entitlements/feature) that the customer is entitled to.
+    pub feature: Expandable<EntitlementsFeature>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// A unique key you provide as your own system identifier.
+    ///
+    /// This may be up to 80 characters.
+    pub lookup_key: String,
+}
+
+impl EntitlementsActiveEntitlement {
+    /// Retrieve a list of active entitlements for a customer.
+    pub fn list(
+        client: &Client,
+        params: ListEntitlementsActiveEntitlements<'_>,
+    ) -> Response<List<EntitlementsActiveEntitlement>> {
+        client.get_query("/entitlements/active_entitlements", &params)
+    }
+
+    /// Retrieve an active entitlement.
+    pub fn retrieve(
+        client: &Client,
+        id: &EntitlementsActiveEntitlementId,
+        expand: &[&str],
+    ) -> Response<EntitlementsActiveEntitlement> {
+        client.get_query(&format!("/entitlements/active_entitlements/{}", id), &Expand { expand })
+    }
+}
+
+impl Object for EntitlementsActiveEntitlement {
+    type Id = EntitlementsActiveEntitlementId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "entitlements.active_entitlement"
+    }
+}
+
+/// The parameters for `EntitlementsActiveEntitlement::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListEntitlementsActiveEntitlements<'a> {
+    /// The ID of the customer.
+    pub customer: CustomerId,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<EntitlementsActiveEntitlementId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<EntitlementsActiveEntitlementId>,
+}
+
+impl<'a> ListEntitlementsActiveEntitlements<'a> {
+    pub fn new(customer: CustomerId) -> Self {
+        ListEntitlementsActiveEntitlements {
+            customer,
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::config::{Client, Response};
+use crate::ids::EntitlementsFeatureId;
+use crate::params::{Expand, List, Metadata, Object};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "EntitlementsFeature".
+///
+/// A feature represents a monetizable ability or functionality in your system.
+/// Features can be assigned to products, and when those products are purchased, Stripe will create an entitlement to the feature for the purchasing customer.
+///
+/// For more details see [https://stripe.com/docs/api/entitlements/feature](https://stripe.com/docs/api/entitlements/feature).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EntitlementsFeature {
+    /// Unique identifier for the object.
+    pub id: EntitlementsFeatureId,
+
+    /// Inactive features cannot be attached to new products and will not be returned from the features list endpoint.
+    pub active: bool,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// A unique key you provide as your own system identifier.
+    ///
+    /// This may be up to 80 characters.
+    pub lookup_key: String,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
+    pub metadata: Metadata,
+
+    /// The feature's name, for your own purpose, not meant to be displayable to the customer.
+    pub name: String,
+}
+
+impl EntitlementsFeature {
+    /// Retrieve a list of features.
+    pub fn list(
+        client: &Client,
+        params: ListEntitlementsFeatures<'_>,
+    ) -> Response<List<EntitlementsFeature>> {
+        client.get_query("/entitlements/features", &params)
+    }
+
+    /// Creates a feature.
+    pub fn create(
+        client: &Client,
+        params: CreateEntitlementsFeature<'_>,
+    ) -> Response<EntitlementsFeature> {
+        client.post_form("/entitlements/features", &params)
+    }
+
+    /// Retrieves a feature.
+    pub fn retrieve(
+        client: &Client,
+        id: &EntitlementsFeatureId,
+        expand: &[&str],
+    ) -> Response<EntitlementsFeature> {
+        client.get_query(&format!("/entitlements/features/{}", id), &Expand { expand })
+    }
+
+    /// Update a feature's metadata or permanently deactivate it.
+    pub fn update(
+        client: &Client,
+        id: &EntitlementsFeatureId,
+        params: UpdateEntitlementsFeature<'_>,
+    ) -> Response<EntitlementsFeature> {
+        client.post_form(&format!("/entitlements/features/{}", id), &params)
+    }
+}
+
+impl Object for EntitlementsFeature {
+    type Id = EntitlementsFeatureId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "entitlements.feature"
+    }
+}
+
+/// The parameters for `EntitlementsFeature::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateEntitlementsFeature<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A unique key you provide as your own system identifier.
+    ///
+    /// This may be up to 80 characters.
+    pub lookup_key: &'a str,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The feature's name, for your own purpose, not meant to be displayable to the customer.
+    pub name: &'a str,
+}
+
+impl<'a> CreateEntitlementsFeature<'a> {
+    pub fn new(lookup_key: &'a str, name: &'a str) -> Self {
+        CreateEntitlementsFeature {
+            expand: Default::default(),
+            lookup_key,
+            metadata: Default::default(),
+            name,
+        }
+    }
+}
+
+/// The parameters for `EntitlementsFeature::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListEntitlementsFeatures<'a> {
+    /// If set, filter results to only include features with the given archive status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<EntitlementsFeatureId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// If set, filter results to only include features with the given lookup_key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lookup_key: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<EntitlementsFeatureId>,
+}
+
+impl<'a> ListEntitlementsFeatures<'a> {
+    pub fn new() -> Self {
+        ListEntitlementsFeatures {
+            archived: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            lookup_key: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `EntitlementsFeature::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateEntitlementsFeature<'a> {
+    /// Inactive features cannot be attached to new products and will not be returned from the features list endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The feature's name, for your own purpose, not meant to be displayable to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+impl<'a> UpdateEntitlementsFeature<'a> {
+    pub fn new() -> Self {
+        UpdateEntitlementsFeature {
+            active: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            name: Default::default(),
+        }
+    }
+}
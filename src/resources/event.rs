@@ -1,8 +1,13 @@
+use crate::config::{Client, Response};
+#[cfg(feature = "webhook-events")]
 use crate::error::WebhookError;
 use crate::ids::EventId;
+use crate::params::{Expand, List, Object, RangeQuery, Timestamp};
 use crate::resources::*;
 
+#[cfg(feature = "webhook-events")]
 use chrono::Utc;
+#[cfg(feature = "webhook-events")]
 use hmac::NewMac;
 #[cfg(feature = "webhook-events")]
 use hmac::{Hmac, Mac};
@@ -186,6 +191,11 @@ pub enum EventType {
     TransferReversed,
     #[serde(rename = "transfer.updated")]
     TransferUpdated,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -194,13 +204,33 @@ pub struct Event {
     #[serde(rename = "type")]
     pub event_type: EventType,
     pub data: EventData,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<Timestamp>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    #[serde(default)]
+    pub livemode: bool,
+
+    /// Number of webhooks that haven't been successfully delivered (for example, to return a 20x response) to the URLs you specify.
+    #[serde(default)]
+    pub pending_webhooks: i64,
     // ...
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EventData {
     pub object: EventObject,
-    // previous_attributes: ...
+
+    /// The values of the attributes that changed, prior to the change.
+    ///
+    /// Only present on `*.updated` events, e.g. `customer.subscription.updated`.
+    /// Use `serde_json::from_value` to read it as a typed partial object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_attributes: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -234,15 +264,153 @@ pub enum EventObject {
     Transfer(Transfer),
 }
 
+impl Event {
+    /// List events, going back up to 30 days.
+    ///
+    /// For more details see [https://stripe.com/docs/api/events/list](https://stripe.com/docs/api/events/list).
+    pub fn list(client: &Client, params: ListEvents<'_>) -> Response<List<Event>> {
+        client.get_query("/events", &params)
+    }
+
+    /// Retrieves the details of an event.
+    ///
+    /// For more details see [https://stripe.com/docs/api/events/retrieve](https://stripe.com/docs/api/events/retrieve).
+    pub fn retrieve(client: &Client, id: &EventId, expand: &[&str]) -> Response<Event> {
+        client.get_query(&format!("/events/{}", id), &Expand { expand })
+    }
+
+    /// Whether every webhook endpoint subscribed to this event has acknowledged it.
+    ///
+    /// Reconciliation jobs can list events with `delivery_success: Some(false)` and process
+    /// those which are still undelivered.
+    pub fn is_delivered(&self) -> bool {
+        self.pending_webhooks == 0
+    }
+}
+
+impl Object for Event {
+    type Id = EventId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "event"
+    }
+}
+
+/// The parameters for `Event::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListEvents<'a> {
+    /// Only return events that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// Filter events by whether all webhooks were successfully delivered.
+    ///
+    /// If false, events which are still pending or did not succeed at the last retry will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_success: Option<bool>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<EventId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<EventId>,
+
+    /// A string containing a specific event name, or group of events using * as a wildcard.
+    ///
+    /// The list will be filtered to include only events with a matching event property.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<&'a str>,
+
+    /// An array of up to 20 strings containing specific event names.
+    ///
+    /// The list will be filtered to include only events with a matching event property.
+    /// You may pass either `type` or `types`, but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<Vec<EventType>>,
+}
+
+impl<'a> ListEvents<'a> {
+    pub fn new() -> Self {
+        ListEvents {
+            created: Default::default(),
+            delivery_success: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            type_: Default::default(),
+            types: Default::default(),
+        }
+    }
+}
+
 #[cfg(feature = "webhook-events")]
 pub struct Webhook {
     current_timestamp: i64,
+    tolerance: i64,
 }
 
 #[cfg(feature = "webhook-events")]
 impl Webhook {
+    /// The maximum age of a signature accepted by `Webhook::construct_event`, in seconds.
+    pub const DEFAULT_TOLERANCE: i64 = 300;
+
     pub fn construct_event(payload: &str, sig: &str, secret: &str) -> Result<Event, WebhookError> {
-        Self { current_timestamp: Utc::now().timestamp() }.do_construct_event(payload, sig, secret)
+        Self { current_timestamp: Utc::now().timestamp(), tolerance: Webhook::DEFAULT_TOLERANCE }
+            .do_construct_event(payload, sig, secret)
+    }
+
+    /// Like `Webhook::construct_event`, but accepts signatures up to `tolerance` old
+    /// instead of 5 minutes.
+    pub fn construct_event_with_tolerance(
+        payload: &str,
+        sig: &str,
+        secret: &str,
+        tolerance: std::time::Duration,
+    ) -> Result<Event, WebhookError> {
+        let tolerance = tolerance.as_secs().min(i64::MAX as u64) as i64;
+        Self { current_timestamp: Utc::now().timestamp(), tolerance }
+            .do_construct_event(payload, sig, secret)
+    }
+
+    /// Like `Webhook::construct_event`, but for the thin events sent to a v2 event destination.
+    pub fn construct_thin_event(
+        payload: &str,
+        sig: &str,
+        secret: &str,
+    ) -> Result<ThinEvent, WebhookError> {
+        Self { current_timestamp: Utc::now().timestamp(), tolerance: Webhook::DEFAULT_TOLERANCE }
+            .do_construct(payload, sig, secret)
+    }
+
+    /// Generates a `Stripe-Signature` header for `payload` signed at `timestamp`,
+    /// e.g. to unit test a webhook handler without sending events from Stripe.
+    pub fn generate_test_signature(
+        payload: &str,
+        secret: &str,
+        timestamp: i64,
+    ) -> Result<String, WebhookError> {
+        Ok(format!("t={},v1={}", timestamp, compute_signature(timestamp, payload, secret)?))
     }
 
     fn do_construct_event(
@@ -251,23 +419,23 @@ impl Webhook {
         sig: &str,
         secret: &str,
     ) -> Result<Event, WebhookError> {
+        self.do_construct(payload, sig, secret)
+    }
+
+    fn do_construct<T: serde::de::DeserializeOwned>(
+        self,
+        payload: &str,
+        sig: &str,
+        secret: &str,
+    ) -> Result<T, WebhookError> {
         // Get Stripe signature from header
         let signature = Signature::parse(&sig)?;
-        let signed_payload = format!("{}{}{}", signature.t, ".", payload);
-
-        // Compute HMAC with the SHA256 hash function, using endpoing secret as key
-        // and signed_payload string as the message.
-        let mut mac =
-            Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
-        mac.update(signed_payload.as_bytes());
-        let mac_result = mac.finalize();
-        let hex = to_hex(mac_result.into_bytes().as_ref());
-        if hex != signature.v1 {
+        if compute_signature(signature.t, payload, secret)? != signature.v1 {
             return Err(WebhookError::BadSignature);
         }
 
         // Get current timestamp to compare to signature timestamp
-        if (self.current_timestamp - signature.t).abs() > 300 {
+        if (self.current_timestamp - signature.t).abs() > self.tolerance {
             return Err(WebhookError::BadTimestamp(signature.t));
         }
 
@@ -275,7 +443,39 @@ impl Webhook {
     }
 }
 
+/// Computes the hex-encoded `v1` signature of a payload sent at `timestamp`.
+#[cfg(feature = "webhook-events")]
+fn compute_signature(timestamp: i64, payload: &str, secret: &str) -> Result<String, WebhookError> {
+    let signed_payload = format!("{}{}{}", timestamp, ".", payload);
+
+    // Compute HMAC with the SHA256 hash function, using endpoing secret as key
+    // and signed_payload string as the message.
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
+    mac.update(signed_payload.as_bytes());
+    let mac_result = mac.finalize();
+    Ok(to_hex(mac_result.into_bytes().as_ref()))
+}
+
+/// A webhook event whose `Stripe-Signature` header has been verified.
+///
+/// With the `axum` or `actix` feature enabled this can be used as an extractor in a handler,
+/// which verifies the request body against the endpoint's `WebhookSecret` and rejects
+/// the request with `400 Bad Request` if it does not match.
+#[cfg(any(feature = "axum", feature = "actix"))]
+#[derive(Clone, Debug)]
+pub struct StripeEvent(pub Event);
+
+/// The signing secret of a webhook endpoint (e.g. `whsec_...`), used to verify a `StripeEvent`.
+///
+/// With axum it is read from the router state (via `FromRef`); with actix-web it must be
+/// registered as `web::Data<WebhookSecret>`.
+#[cfg(any(feature = "axum", feature = "actix"))]
+#[derive(Clone, Debug)]
+pub struct WebhookSecret(pub String);
+
 // TODO: If there is a lightweight hex crate, we should just rely on that instead.
+#[cfg(feature = "webhook-events")]
 fn to_hex(bytes: &[u8]) -> String {
     const CHARS: &[u8] = b"0123456789abcdef";
 
@@ -395,7 +595,10 @@ mod tests {
         let secret = "webhook_secret".to_string();
         let signature = format!("t={},v1=f0bdba6d4eacbd8ad8a3bbadd7248e633ec1477f7899c124c51b39405fa36613,v0=63f3a72374a733066c4be69ed7f8e5ac85c22c9f0a6a612ab9a025a9e4ee7eef", event_timestamp);
 
-        let webhook = super::Webhook { current_timestamp: event_timestamp };
+        let webhook = super::Webhook {
+            current_timestamp: event_timestamp,
+            tolerance: super::Webhook::DEFAULT_TOLERANCE,
+        };
 
         let event = webhook
             .do_construct_event(payload, &signature, &secret)
@@ -404,4 +607,37 @@ mod tests {
         assert_eq!(event.event_type, super::EventType::InvoiceItemCreated);
         assert_eq!(event.id.to_string(), "evt_123");
     }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_construct_event_with_tolerance() {
+        use super::{Webhook, WebhookError};
+        use std::time::Duration;
+
+        let payload = r#"{"id": "evt_123", "object": "event", "type": "product.deleted", "data": {"object": {"id": "prod_123", "object": "product", "deleted": true}}}"#;
+        let secret = "webhook_secret";
+        let an_hour_ago = chrono::Utc::now().timestamp() - 3600;
+        let signature = Webhook::generate_test_signature(payload, secret, an_hour_ago).unwrap();
+
+        match Webhook::construct_event(payload, &signature, secret) {
+            Err(WebhookError::BadTimestamp(t)) => assert_eq!(t, an_hour_ago),
+            other => panic!("expected a bad timestamp; was {:?}", other),
+        }
+        let event = Webhook::construct_event_with_tolerance(
+            payload,
+            &signature,
+            secret,
+            Duration::from_secs(2 * 3600),
+        )
+        .expect("Failed to construct event");
+        assert_eq!(event.event_type, super::EventType::ProductDeleted);
+
+        let result = Webhook::construct_event_with_tolerance(
+            payload,
+            &signature,
+            "wrong_secret",
+            Duration::from_secs(2 * 3600),
+        );
+        assert!(matches!(result, Err(WebhookError::BadSignature)));
+    }
 }
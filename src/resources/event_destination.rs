@@ -0,0 +1,422 @@
+use crate::config::{Client, Response};
+use crate::ids::EventDestinationId;
+use crate::params::{Metadata, Object, V2List};
+#[cfg(feature = "events")]
+use crate::resources::ThinEvent;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "EventDestination".
+///
+/// Set up an event destination to receive events from Stripe across multiple destination types,
+/// including webhook endpoints and Amazon EventBridge.
+/// Event destinations support receiving thin events and snapshot events.
+///
+/// For more details see [https://docs.stripe.com/api/v2/core/event_destinations](https://docs.stripe.com/api/v2/core/event_destinations).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EventDestination {
+    /// Unique identifier for the object.
+    pub id: EventDestinationId,
+
+    /// Time at which the object was created, as an RFC 3339 timestamp.
+    pub created: String,
+
+    /// An optional description of what the event destination is used for.
+    #[serde(default)]
+    pub description: String,
+
+    /// The list of events to enable for this endpoint.
+    pub enabled_events: Vec<String>,
+
+    /// Payload type of events being subscribed to.
+    pub event_payload: EventDestinationEventPayload,
+
+    /// Where events should be routed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events_from: Option<Vec<String>>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Event destination name.
+    pub name: String,
+
+    /// If using the snapshot event payload, the API version events are rendered as.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_api_version: Option<String>,
+
+    /// Status.
+    ///
+    /// It can be set to either enabled or disabled.
+    pub status: EventDestinationStatus,
+
+    /// Event destination type.
+    #[serde(rename = "type")]
+    pub type_: EventDestinationType,
+
+    /// Time at which the object was last updated, as an RFC 3339 timestamp.
+    pub updated: String,
+
+    /// Webhook endpoint configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_endpoint: Option<EventDestinationWebhookEndpoint>,
+}
+
+impl EventDestination {
+    /// Lists all event destinations.
+    pub fn list(
+        client: &Client,
+        params: ListEventDestinations,
+    ) -> Response<V2List<EventDestination>> {
+        client.get_query_v2("/core/event_destinations", &params)
+    }
+
+    /// Create a new event destination.
+    pub fn create(
+        client: &Client,
+        params: CreateEventDestination<'_>,
+    ) -> Response<EventDestination> {
+        client.post_json_v2("/core/event_destinations", &params)
+    }
+
+    /// Retrieves the details of an event destination.
+    ///
+    /// The fields listed in `include`, such as the webhook signing secret, are only returned when requested.
+    pub fn retrieve(
+        client: &Client,
+        id: &EventDestinationId,
+        include: &[EventDestinationInclude],
+    ) -> Response<EventDestination> {
+        let mut path = format!("/core/event_destinations/{}", id);
+        for (i, field) in include.iter().enumerate() {
+            path.push(if i == 0 { '?' } else { '&' });
+            path.push_str(&format!("include={}", field));
+        }
+        client.get_v2(&path)
+    }
+
+    /// Update the details of an event destination.
+    pub fn update(
+        client: &Client,
+        id: &EventDestinationId,
+        params: UpdateEventDestination<'_>,
+    ) -> Response<EventDestination> {
+        client.post_json_v2(&format!("/core/event_destinations/{}", id), &params)
+    }
+
+    /// Delete an event destination.
+    pub fn delete(client: &Client, id: &EventDestinationId) -> Response<DeletedEventDestination> {
+        client.delete_v2(&format!("/core/event_destinations/{}", id))
+    }
+
+    /// Enable an event destination.
+    pub fn enable(client: &Client, id: &EventDestinationId) -> Response<EventDestination> {
+        client.post_json_v2(&format!("/core/event_destinations/{}/enable", id), &Empty {})
+    }
+
+    /// Disable an event destination.
+    pub fn disable(client: &Client, id: &EventDestinationId) -> Response<EventDestination> {
+        client.post_json_v2(&format!("/core/event_destinations/{}/disable", id), &Empty {})
+    }
+
+    /// Send a `ping` event to an event destination.
+    #[cfg(feature = "events")]
+    pub fn ping(client: &Client, id: &EventDestinationId) -> Response<ThinEvent> {
+        client.post_json_v2(&format!("/core/event_destinations/{}/ping", id), &Empty {})
+    }
+}
+
+impl Object for EventDestination {
+    type Id = EventDestinationId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "v2.core.event_destination"
+    }
+}
+
+/// The body of the requests which take no parameters.
+#[derive(Serialize)]
+struct Empty {}
+
+/// The response to `EventDestination::delete`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeletedEventDestination {
+    /// The ID of the deleted event destination.
+    pub id: EventDestinationId,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EventDestinationWebhookEndpoint {
+    /// The signing secret of the webhook endpoint, only includable on creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_secret: Option<String>,
+
+    /// The URL of the webhook endpoint, includable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// The parameters for `EventDestination::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateEventDestination<'a> {
+    /// An optional description of what the event destination is used for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The list of events to enable for this endpoint.
+    pub enabled_events: Vec<&'a str>,
+
+    /// Payload type of events being subscribed to.
+    pub event_payload: EventDestinationEventPayload,
+
+    /// Additional fields to include in the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<EventDestinationInclude>>,
+
+    /// Metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Event destination name.
+    pub name: &'a str,
+
+    /// If using the snapshot event payload, the API version events are rendered as.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_api_version: Option<&'a str>,
+
+    /// Event destination type.
+    #[serde(rename = "type")]
+    pub type_: EventDestinationType,
+
+    /// Webhook endpoint configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_endpoint: Option<CreateEventDestinationWebhookEndpoint<'a>>,
+}
+
+impl<'a> CreateEventDestination<'a> {
+    pub fn new(
+        enabled_events: Vec<&'a str>,
+        event_payload: EventDestinationEventPayload,
+        name: &'a str,
+        type_: EventDestinationType,
+    ) -> Self {
+        CreateEventDestination {
+            description: Default::default(),
+            enabled_events,
+            event_payload,
+            include: Default::default(),
+            metadata: Default::default(),
+            name,
+            snapshot_api_version: Default::default(),
+            type_,
+            webhook_endpoint: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `EventDestination::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListEventDestinations {
+    /// The page size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// The requested page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+impl ListEventDestinations {
+    pub fn new() -> Self {
+        ListEventDestinations { limit: Default::default(), page: Default::default() }
+    }
+}
+
+/// The parameters for `EventDestination::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateEventDestination<'a> {
+    /// An optional description of what the event destination is used for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The list of events to enable for this endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_events: Option<Vec<&'a str>>,
+
+    /// Additional fields to include in the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<EventDestinationInclude>>,
+
+    /// Metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Event destination name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+
+    /// Webhook endpoint configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_endpoint: Option<CreateEventDestinationWebhookEndpoint<'a>>,
+}
+
+impl<'a> UpdateEventDestination<'a> {
+    pub fn new() -> Self {
+        UpdateEventDestination {
+            description: Default::default(),
+            enabled_events: Default::default(),
+            include: Default::default(),
+            metadata: Default::default(),
+            name: Default::default(),
+            webhook_endpoint: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateEventDestinationWebhookEndpoint<'a> {
+    /// The URL of the webhook endpoint.
+    pub url: &'a str,
+}
+
+/// An enum representing the possible values of an `EventDestination`'s `event_payload` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventDestinationEventPayload {
+    Snapshot,
+    Thin,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl EventDestinationEventPayload {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EventDestinationEventPayload::Snapshot => "snapshot",
+            EventDestinationEventPayload::Thin => "thin",
+            EventDestinationEventPayload::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for EventDestinationEventPayload {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for EventDestinationEventPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the fields which `EventDestination` only returns when included.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub enum EventDestinationInclude {
+    #[serde(rename = "webhook_endpoint.signing_secret")]
+    WebhookEndpointSigningSecret,
+    #[serde(rename = "webhook_endpoint.url")]
+    WebhookEndpointUrl,
+}
+
+impl EventDestinationInclude {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EventDestinationInclude::WebhookEndpointSigningSecret => {
+                "webhook_endpoint.signing_secret"
+            }
+            EventDestinationInclude::WebhookEndpointUrl => "webhook_endpoint.url",
+        }
+    }
+}
+
+impl AsRef<str> for EventDestinationInclude {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for EventDestinationInclude {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `EventDestination`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventDestinationStatus {
+    Disabled,
+    Enabled,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl EventDestinationStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EventDestinationStatus::Disabled => "disabled",
+            EventDestinationStatus::Enabled => "enabled",
+            EventDestinationStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for EventDestinationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for EventDestinationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `EventDestination`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventDestinationType {
+    AmazonEventbridge,
+    WebhookEndpoint,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl EventDestinationType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EventDestinationType::AmazonEventbridge => "amazon_eventbridge",
+            EventDestinationType::WebhookEndpoint => "webhook_endpoint",
+            EventDestinationType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for EventDestinationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for EventDestinationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -4,7 +4,7 @@
 
 use crate::config::{Client, Response};
 use crate::ids::FileId;
-use crate::params::{Expand, List, Object, RangeQuery, Timestamp};
+use crate::params::{Expand, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::FileLink;
 use serde_derive::{Deserialize, Serialize};
 
@@ -57,6 +57,13 @@ impl File {
         client.get_query("/files", &params)
     }
 
+    /// To upload a file to Stripe, you need to send a request of type `multipart/form-data`.
+    ///
+    /// The request is sent to `https://files.stripe.com/`, see `Client::set_files_url`.
+    pub fn create(client: &Client, params: CreateFile<'_>) -> Response<File> {
+        client.post_file("/files", &params, params.filename, params.file)
+    }
+
     /// Retrieves the details of an existing file object.
     ///
     /// Supply the unique file ID from a file, and Stripe will return the corresponding file object.
@@ -76,6 +83,41 @@ impl Object for File {
     }
 }
 
+/// The parameters for `File::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateFile<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The contents of the file to upload.
+    #[serde(skip)]
+    pub file: &'a [u8],
+
+    /// Optional parameters that automatically create a [file link](https://stripe.com/docs/api#file_links) for the newly created file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_link_data: Option<CreateFileFileLinkData>,
+
+    /// The name the file is uploaded with, which Stripe uses to infer its type.
+    #[serde(skip)]
+    pub filename: &'a str,
+
+    /// The [purpose](https://stripe.com/docs/file-upload#uploading-a-file) of the uploaded file.
+    pub purpose: FilePurpose,
+}
+
+impl<'a> CreateFile<'a> {
+    pub fn new(file: &'a [u8], filename: &'a str, purpose: FilePurpose) -> Self {
+        CreateFile {
+            expand: Default::default(),
+            file,
+            file_link_data: Default::default(),
+            filename,
+            purpose,
+        }
+    }
+}
+
 /// The parameters for `File::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListFiles<'a> {
@@ -126,6 +168,24 @@ impl<'a> ListFiles<'a> {
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateFileFileLinkData {
+    /// Set this to `true` to create a file link for the newly created file.
+    ///
+    /// Creating a link is only possible when the file's `purpose` is one of the following: `business_icon`, `business_logo`, `customer_signature`, `dispute_evidence`, `pci_document`, or `tax_document_user_upload`.
+    pub create: bool,
+
+    /// A future timestamp after which the link will no longer be usable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
 /// An enum representing the possible values of an `ListFiles`'s `purpose` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -140,6 +200,11 @@ pub enum FilePurpose {
     PciDocument,
     SigmaScheduledQuery,
     TaxDocumentUserUpload,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl FilePurpose {
@@ -155,6 +220,7 @@ impl FilePurpose {
             FilePurpose::PciDocument => "pci_document",
             FilePurpose::SigmaScheduledQuery => "sigma_scheduled_query",
             FilePurpose::TaxDocumentUserUpload => "tax_document_user_upload",
+            FilePurpose::Other => "other",
         }
     }
 }
@@ -0,0 +1,322 @@
+use crate::config::{Client, Response};
+use crate::ids::{ForwardingRequestId, PaymentMethodId};
+use crate::params::{Expand, List, Metadata, Object, RangeQuery, Timestamp};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "ForwardingRequest".
+///
+/// Instructs Stripe to make a request on your behalf using the destination URL.
+/// The destination URL is activated by Stripe at the time of onboarding.
+/// Stripe verifies requests with your credentials provided during onboarding, and injects card details from the payment_method into the request.
+///
+/// For more details see [https://stripe.com/docs/api/forwarding/request](https://stripe.com/docs/api/forwarding/request).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ForwardingRequest {
+    /// Unique identifier for the object.
+    pub id: ForwardingRequestId,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The PaymentMethod to insert into the forwarded request.
+    ///
+    /// Forwarding previously consumed PaymentMethods is allowed.
+    pub payment_method: PaymentMethodId,
+
+    /// The field kinds to be replaced in the forwarded request.
+    pub replacements: Vec<ForwardingRequestReplacement>,
+
+    /// Context about the request from Stripe's servers to the destination endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_context: Option<ForwardingRequestContext>,
+
+    /// The request that was sent to the destination endpoint.
+    ///
+    /// We redact any sensitive fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_details: Option<ForwardingRequestDetails>,
+
+    /// The response that the destination endpoint returned to us.
+    ///
+    /// We redact any sensitive fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_details: Option<ForwardingResponseDetails>,
+
+    /// The destination URL for the forwarded request.
+    ///
+    /// Must be supported by the config.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl ForwardingRequest {
+    /// Lists all ForwardingRequest objects.
+    pub fn list(
+        client: &Client,
+        params: ListForwardingRequests<'_>,
+    ) -> Response<List<ForwardingRequest>> {
+        client.get_query("/forwarding/requests", &params)
+    }
+
+    /// Creates a ForwardingRequest object.
+    pub fn create(
+        client: &Client,
+        params: CreateForwardingRequest<'_>,
+    ) -> Response<ForwardingRequest> {
+        client.post_form("/forwarding/requests", &params)
+    }
+
+    /// Retrieves a ForwardingRequest object.
+    pub fn retrieve(
+        client: &Client,
+        id: &ForwardingRequestId,
+        expand: &[&str],
+    ) -> Response<ForwardingRequest> {
+        client.get_query(&format!("/forwarding/requests/{}", id), &Expand { expand })
+    }
+}
+
+impl Object for ForwardingRequest {
+    type Id = ForwardingRequestId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "forwarding.request"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ForwardingRequestContext {
+    /// The time it took in milliseconds for the destination endpoint to respond.
+    pub destination_duration: i64,
+
+    /// The IP address of the destination.
+    pub destination_ip_address: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ForwardingRequestDetails {
+    /// The body payload to send to the destination endpoint.
+    pub body: String,
+
+    /// The headers to include in the forwarded request.
+    ///
+    /// Can be omitted if no additional headers (excluding Stripe-generated ones such as the Content-Type header) should be included.
+    pub headers: Vec<ForwardingRequestHeader>,
+
+    /// The HTTP method used to call the destination endpoint.
+    pub http_method: ForwardingRequestHttpMethod,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ForwardingResponseDetails {
+    /// The response body from the destination endpoint to Stripe.
+    pub body: String,
+
+    /// HTTP headers that the destination endpoint returned.
+    pub headers: Vec<ForwardingRequestHeader>,
+
+    /// The HTTP status code that the destination endpoint returned.
+    pub status: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ForwardingRequestHeader {
+    /// The header name.
+    pub name: String,
+
+    /// The header value.
+    pub value: String,
+}
+
+/// The parameters for `ForwardingRequest::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateForwardingRequest<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The PaymentMethod to insert into the forwarded request.
+    ///
+    /// Forwarding previously consumed PaymentMethods is allowed.
+    pub payment_method: PaymentMethodId,
+
+    /// The field kinds to be replaced in the forwarded request.
+    pub replacements: Vec<ForwardingRequestReplacement>,
+
+    /// The request body and headers to be sent to the destination endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<CreateForwardingRequestRequest>,
+
+    /// The destination URL for the forwarded request.
+    ///
+    /// Must be supported by the config.
+    pub url: &'a str,
+}
+
+impl<'a> CreateForwardingRequest<'a> {
+    pub fn new(
+        payment_method: PaymentMethodId,
+        replacements: Vec<ForwardingRequestReplacement>,
+        url: &'a str,
+    ) -> Self {
+        CreateForwardingRequest {
+            expand: Default::default(),
+            metadata: Default::default(),
+            payment_method,
+            replacements,
+            request: Default::default(),
+            url,
+        }
+    }
+}
+
+/// The parameters for `ForwardingRequest::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListForwardingRequests<'a> {
+    /// Similar to other List endpoints, filters results based on created timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<ForwardingRequestId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<ForwardingRequestId>,
+}
+
+impl<'a> ListForwardingRequests<'a> {
+    pub fn new() -> Self {
+        ListForwardingRequests {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateForwardingRequestRequest {
+    /// The body payload to send to the destination endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// The headers to include in the forwarded request.
+    ///
+    /// Can be omitted if no additional headers (excluding Stripe-generated ones such as the Content-Type header) should be included.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Vec<ForwardingRequestHeader>>,
+}
+
+/// An enum representing the possible values of an `ForwardingRequestDetails`'s `http_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForwardingRequestHttpMethod {
+    #[serde(rename = "POST")]
+    Post,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl ForwardingRequestHttpMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ForwardingRequestHttpMethod::Post => "POST",
+            ForwardingRequestHttpMethod::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for ForwardingRequestHttpMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ForwardingRequestHttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `ForwardingRequest`'s `replacements` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForwardingRequestReplacement {
+    CardCvc,
+    CardExpiry,
+    CardNumber,
+    CardholderName,
+    RequestSignature,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl ForwardingRequestReplacement {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ForwardingRequestReplacement::CardCvc => "card_cvc",
+            ForwardingRequestReplacement::CardExpiry => "card_expiry",
+            ForwardingRequestReplacement::CardNumber => "card_number",
+            ForwardingRequestReplacement::CardholderName => "cardholder_name",
+            ForwardingRequestReplacement::RequestSignature => "request_signature",
+            ForwardingRequestReplacement::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for ForwardingRequestReplacement {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ForwardingRequestReplacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -3,11 +3,14 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::{CustomerId, InvoiceId, SubscriptionId};
+use crate::ids::{
+    CustomerId, InvoiceId, InvoiceRenderingTemplateId, PaymentMethodId, PaymentSourceId,
+    SubscriptionId, TaxIdId,
+};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
     Address, Charge, Currency, CustomField, Customer, Discount, InvoiceLineItem, PaymentIntent,
-    PaymentMethod, PaymentSource, Shipping, Subscription, TaxRate,
+    PaymentMethod, PaymentSource, Shipping, Subscription, TaxId, TaxRate,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -26,6 +29,12 @@ pub struct Invoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub account_name: Option<String>,
 
+    /// The account tax IDs associated with the invoice.
+    ///
+    /// Only editable when the invoice is a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_tax_ids: Option<Vec<Expandable<TaxId>>>,
+
     /// Final amount due at this time for this invoice.
     ///
     /// If the invoice's total is smaller than the minimum charge amount, for example, or if there is account credit that can be applied to the invoice, the `amount_due` may be 0.
@@ -65,6 +74,9 @@ pub struct Invoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_advance: Option<bool>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<InvoiceAutomaticTax>,
+
     /// Indicates the reason why the invoice was created.
     ///
     /// `subscription_cycle` indicates an invoice created by a subscription advancing into a new period.
@@ -280,6 +292,10 @@ pub struct Invoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_number: Option<String>,
 
+    /// The rendering-related settings that control how the invoice is displayed on customer-facing surfaces such as PDF and Hosted Invoice Page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendering: Option<InvoiceRendering>,
+
     /// Starting customer balance before the invoice is finalized.
     ///
     /// If the invoice has not been finalized yet, this will be the current customer balance.
@@ -364,6 +380,13 @@ impl Invoice {
     pub fn retrieve(client: &Client, id: &InvoiceId, expand: &[&str]) -> Response<Invoice> {
         client.get_query(&format!("/invoices/{}", id), &Expand { expand })
     }
+
+    /// Draft invoices are fully editable.
+    ///
+    /// Once an invoice is finalized, monetary values, as well as `collection_method`, become uneditable.
+    pub fn update(client: &Client, id: &InvoiceId, params: UpdateInvoice<'_>) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}", id), &params)
+    }
 }
 
 impl Object for Invoice {
@@ -376,6 +399,47 @@ impl Object for Invoice {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InvoiceAutomaticTax {
+    /// Whether Stripe automatically computes tax on this invoice.
+    ///
+    /// Note that incompatible invoice items (invoice items with manually specified [tax rates](https://stripe.com/docs/api/tax_rates), negative amounts, or `tax_behavior=unspecified`) cannot be added to automatic tax invoices.
+    pub enabled: bool,
+
+    /// The status of the most recent automated tax calculation for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InvoiceAutomaticTaxStatus>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InvoiceRendering {
+    /// How line-item prices and amounts will be displayed with respect to tax on invoice PDFs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_tax_display: Option<InvoiceAmountTaxDisplay>,
+
+    /// Invoice pdf rendering options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf: Option<InvoiceRenderingPdf>,
+
+    /// ID of the rendering template that the invoice is formatted by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<InvoiceRenderingTemplateId>,
+
+    /// Version of the rendering template that the invoice is using.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_version: Option<i64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InvoiceRenderingPdf {
+    /// Page size of invoice pdf.
+    ///
+    /// Options include a4, letter, and auto.
+    /// If set to auto, page size will be switched to a4 or letter based on customer locale.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<InvoiceRenderingPdfPageSize>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InvoiceSettingCustomField {
     /// The name of the custom field.
@@ -449,6 +513,12 @@ pub struct InvoicesStatusTransitions {
 /// The parameters for `Invoice::create`.
 #[derive(Clone, Debug, Serialize)]
 pub struct CreateInvoice<'a> {
+    /// The account tax IDs associated with the invoice.
+    ///
+    /// Only editable when the invoice is a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_tax_ids: Option<Vec<TaxIdId>>,
+
     /// A fee in %s that will be applied to the invoice and transferred to the application owner's Stripe account.
     ///
     /// The request must be made with an OAuth key or the Stripe-Account header in order to take an application fee.
@@ -462,6 +532,10 @@ pub struct CreateInvoice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_advance: Option<bool>,
 
+    /// Settings for automatic tax lookup for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CreateInvoiceAutomaticTax>,
+
     /// Either `charge_automatically`, or `send_invoice`.
     ///
     /// When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer.
@@ -488,14 +562,14 @@ pub struct CreateInvoice<'a> {
     /// It must belong to the customer associated with the invoice.
     /// If not set, defaults to the subscription's default payment method, if any, or to the default payment method in the customer's invoice settings.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_payment_method: Option<&'a str>,
+    pub default_payment_method: Option<PaymentMethodId>,
 
     /// ID of the default payment source for the invoice.
     ///
     /// It must belong to the customer associated with the invoice and be in a chargeable state.
     /// If not set, defaults to the subscription's default source, if any, or to the customer's default source.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_source: Option<&'a str>,
+    pub default_source: Option<PaymentSourceId>,
 
     /// The tax rates that will apply to any line item that does not have `tax_rates` set.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -530,6 +604,10 @@ pub struct CreateInvoice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
 
+    /// The rendering-related settings that control how the invoice is displayed on customer-facing surfaces such as PDF and Hosted Invoice Page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendering: Option<CreateInvoiceRendering>,
+
     /// Extra information about a charge for the customer's credit card statement.
     ///
     /// It must contain at least one letter.
@@ -555,8 +633,10 @@ pub struct CreateInvoice<'a> {
 impl<'a> CreateInvoice<'a> {
     pub fn new(customer: CustomerId) -> Self {
         CreateInvoice {
+            account_tax_ids: Default::default(),
             application_fee_amount: Default::default(),
             auto_advance: Default::default(),
+            automatic_tax: Default::default(),
             collection_method: Default::default(),
             custom_fields: Default::default(),
             customer,
@@ -569,6 +649,7 @@ impl<'a> CreateInvoice<'a> {
             expand: Default::default(),
             footer: Default::default(),
             metadata: Default::default(),
+            rendering: Default::default(),
             statement_descriptor: Default::default(),
             subscription: Default::default(),
             tax_percent: Default::default(),
@@ -576,6 +657,152 @@ impl<'a> CreateInvoice<'a> {
     }
 }
 
+/// The parameters for `Invoice::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateInvoice<'a> {
+    /// The account tax IDs associated with the invoice.
+    ///
+    /// Only editable when the invoice is a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_tax_ids: Option<Vec<TaxIdId>>,
+
+    /// Controls whether Stripe will perform [automatic collection](https://stripe.com/docs/billing/invoices/workflow/#auto_advance) of the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+
+    /// Settings for automatic tax lookup for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CreateInvoiceAutomaticTax>,
+
+    /// Either `charge_automatically` or `send_invoice`.
+    ///
+    /// This field can be updated only on `draft` invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<CollectionMethod>,
+
+    /// A list of up to 4 custom fields to be displayed on the invoice.
+    ///
+    /// If a value for `custom_fields` is specified, the list specified will replace the existing custom field list on this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomField>>,
+
+    /// The number of days from which the invoice is created until it is due.
+    ///
+    /// Only valid for invoices where `collection_method=send_invoice`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>,
+
+    /// ID of the default payment method for the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<PaymentMethodId>,
+
+    /// ID of the default payment source for the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<PaymentSourceId>,
+
+    /// The tax rates that will apply to any line item that does not have `tax_rates` set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    /// Referenced as 'memo' in the Dashboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The date on which payment for this invoice is due.
+    ///
+    /// Only valid for invoices where `collection_method=send_invoice`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Timestamp>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Footer to be displayed on the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The rendering-related settings that control how the invoice is displayed on customer-facing surfaces such as PDF and Hosted Invoice Page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendering: Option<CreateInvoiceRendering>,
+
+    /// Extra information about a charge for the customer's credit card statement.
+    ///
+    /// It must contain at least one letter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl<'a> UpdateInvoice<'a> {
+    pub fn new() -> Self {
+        UpdateInvoice {
+            account_tax_ids: Default::default(),
+            auto_advance: Default::default(),
+            automatic_tax: Default::default(),
+            collection_method: Default::default(),
+            custom_fields: Default::default(),
+            days_until_due: Default::default(),
+            default_payment_method: Default::default(),
+            default_source: Default::default(),
+            default_tax_rates: Default::default(),
+            description: Default::default(),
+            due_date: Default::default(),
+            expand: Default::default(),
+            footer: Default::default(),
+            metadata: Default::default(),
+            rendering: Default::default(),
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoiceAutomaticTax {
+    /// Whether Stripe automatically computes tax on this invoice.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoiceRendering {
+    /// How line-item prices and amounts will be displayed with respect to tax on invoice PDFs.
+    ///
+    /// One of `exclude_tax` or `include_inclusive_tax`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_tax_display: Option<InvoiceAmountTaxDisplay>,
+
+    /// Invoice pdf rendering options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf: Option<CreateInvoiceRenderingPdf>,
+
+    /// ID of the invoice rendering template to use for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<InvoiceRenderingTemplateId>,
+
+    /// The specific version of invoice rendering template to use for this invoice.
+    ///
+    /// Defaults to the latest version of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_version: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoiceRenderingPdf {
+    /// Page size for invoice PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<InvoiceRenderingPdfPageSize>,
+}
+
 /// The parameters for `Invoice::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListInvoices<'a> {
@@ -653,6 +880,11 @@ impl<'a> ListInvoices<'a> {
 pub enum CollectionMethod {
     ChargeAutomatically,
     SendInvoice,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CollectionMethod {
@@ -660,6 +892,7 @@ impl CollectionMethod {
         match self {
             CollectionMethod::ChargeAutomatically => "charge_automatically",
             CollectionMethod::SendInvoice => "send_invoice",
+            CollectionMethod::Other => "other",
         }
     }
 }
@@ -676,6 +909,78 @@ impl std::fmt::Display for CollectionMethod {
     }
 }
 
+/// An enum representing the possible values of an `InvoiceRendering`'s `amount_tax_display` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceAmountTaxDisplay {
+    ExcludeTax,
+    IncludeInclusiveTax,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl InvoiceAmountTaxDisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InvoiceAmountTaxDisplay::ExcludeTax => "exclude_tax",
+            InvoiceAmountTaxDisplay::IncludeInclusiveTax => "include_inclusive_tax",
+            InvoiceAmountTaxDisplay::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for InvoiceAmountTaxDisplay {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoiceAmountTaxDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `InvoiceAutomaticTax`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceAutomaticTaxStatus {
+    Complete,
+    Failed,
+    RequiresLocationInputs,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl InvoiceAutomaticTaxStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InvoiceAutomaticTaxStatus::Complete => "complete",
+            InvoiceAutomaticTaxStatus::Failed => "failed",
+            InvoiceAutomaticTaxStatus::RequiresLocationInputs => "requires_location_inputs",
+            InvoiceAutomaticTaxStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for InvoiceAutomaticTaxStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoiceAutomaticTaxStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `Invoice`'s `billing_reason` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -688,6 +993,11 @@ pub enum InvoiceBillingReason {
     SubscriptionThreshold,
     SubscriptionUpdate,
     Upcoming,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl InvoiceBillingReason {
@@ -703,6 +1013,7 @@ impl InvoiceBillingReason {
             InvoiceBillingReason::SubscriptionThreshold => "subscription_threshold",
             InvoiceBillingReason::SubscriptionUpdate => "subscription_update",
             InvoiceBillingReason::Upcoming => "upcoming",
+            InvoiceBillingReason::Other => "other",
         }
     }
 }
@@ -726,6 +1037,11 @@ pub enum InvoiceCustomerTaxExempt {
     Exempt,
     None,
     Reverse,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl InvoiceCustomerTaxExempt {
@@ -734,6 +1050,7 @@ impl InvoiceCustomerTaxExempt {
             InvoiceCustomerTaxExempt::Exempt => "exempt",
             InvoiceCustomerTaxExempt::None => "none",
             InvoiceCustomerTaxExempt::Reverse => "reverse",
+            InvoiceCustomerTaxExempt::Other => "other",
         }
     }
 }
@@ -750,6 +1067,43 @@ impl std::fmt::Display for InvoiceCustomerTaxExempt {
     }
 }
 
+/// An enum representing the possible values of an `InvoiceRenderingPdf`'s `page_size` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceRenderingPdfPageSize {
+    A4,
+    Auto,
+    Letter,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl InvoiceRenderingPdfPageSize {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InvoiceRenderingPdfPageSize::A4 => "a4",
+            InvoiceRenderingPdfPageSize::Auto => "auto",
+            InvoiceRenderingPdfPageSize::Letter => "letter",
+            InvoiceRenderingPdfPageSize::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for InvoiceRenderingPdfPageSize {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoiceRenderingPdfPageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `Invoice`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -760,6 +1114,11 @@ pub enum InvoiceStatus {
     Paid,
     Uncollectible,
     Void,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl InvoiceStatus {
@@ -771,6 +1130,7 @@ impl InvoiceStatus {
             InvoiceStatus::Paid => "paid",
             InvoiceStatus::Uncollectible => "uncollectible",
             InvoiceStatus::Void => "void",
+            InvoiceStatus::Other => "other",
         }
     }
 }
@@ -796,6 +1156,11 @@ pub enum InvoiceStatusFilter {
     Paid,
     Uncollectible,
     Void,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl InvoiceStatusFilter {
@@ -806,6 +1171,7 @@ impl InvoiceStatusFilter {
             InvoiceStatusFilter::Paid => "paid",
             InvoiceStatusFilter::Uncollectible => "uncollectible",
             InvoiceStatusFilter::Void => "void",
+            InvoiceStatusFilter::Other => "other",
         }
     }
 }
@@ -852,6 +1218,11 @@ pub enum TaxIdType {
     Unknown,
     UsEin,
     ZaVat,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TaxIdType {
@@ -883,6 +1254,7 @@ impl TaxIdType {
             TaxIdType::Unknown => "unknown",
             TaxIdType::UsEin => "us_ein",
             TaxIdType::ZaVat => "za_vat",
+            TaxIdType::Other => "other",
         }
     }
 }
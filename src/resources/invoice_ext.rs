@@ -1,7 +1,10 @@
-use crate::config::{Client, Response};
-use crate::ids::{CouponId, CustomerId, InvoiceId, PlanId, SubscriptionId, SubscriptionItemId};
-use crate::params::{Metadata, Timestamp};
-use crate::resources::{CollectionMethod, Invoice};
+use crate::config::{err, Client, Response};
+use crate::error::Error;
+use crate::ids::{
+    CouponId, CustomerId, InvoiceId, InvoiceLineItemId, PlanId, SubscriptionId, SubscriptionItemId,
+};
+use crate::params::{Expand, List, Metadata, Timestamp};
+use crate::resources::{CollectionMethod, Invoice, InvoiceLineItem};
 use serde_derive::Serialize;
 
 #[deprecated(since = "0.12.0")]
@@ -21,6 +24,68 @@ impl Invoice {
     pub fn pay(client: &Client, invoice_id: &InvoiceId) -> Response<Invoice> {
         client.post(&format!("/invoices/{}/pay", invoice_id))
     }
+
+    /// Lists the line items of an invoice.
+    ///
+    /// An invoice only embeds its first 10 `lines`; use `List::get_all` on the result to fetch the rest.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/invoice_lines.
+    pub fn list_lines(
+        client: &Client,
+        invoice_id: &InvoiceId,
+        params: ListInvoiceLines<'_>,
+    ) -> Response<List<InvoiceLineItem>> {
+        client.get_query(&format!("/invoices/{}/lines", invoice_id), &params)
+    }
+
+    /// Downloads the PDF of a finalized invoice from its `invoice_pdf` url.
+    ///
+    /// Draft invoices have no PDF, so this returns an `Error::Unsupported` for them.
+    pub fn download_pdf(&self, client: &Client) -> Response<Vec<u8>> {
+        match &self.invoice_pdf {
+            Some(url) => client.get_bytes(url),
+            None => err(Error::Unsupported("the invoice has no PDF until it is finalized")),
+        }
+    }
+}
+
+/// The parameters for `Invoice::list_lines`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListInvoiceLines<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<InvoiceLineItemId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<InvoiceLineItemId>,
+}
+
+impl<'a> ListInvoiceLines<'a> {
+    pub fn new() -> Self {
+        ListInvoiceLines {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -182,6 +182,11 @@ pub enum IssuingAuthorizationStatus {
     Closed,
     Pending,
     Reversed,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingAuthorizationStatus {
@@ -190,6 +195,7 @@ impl IssuingAuthorizationStatus {
             IssuingAuthorizationStatus::Closed => "closed",
             IssuingAuthorizationStatus::Pending => "pending",
             IssuingAuthorizationStatus::Reversed => "reversed",
+            IssuingAuthorizationStatus::Other => "other",
         }
     }
 }
@@ -7,6 +7,11 @@ pub enum IssuingAuthorizationCheck {
     Match,
     Mismatch,
     NotProvided,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 /// An enum representing the possible values of the `IssuingAuthorization`'s `authorization_method` field.
@@ -18,6 +23,11 @@ pub enum IssuingAuthorizationMethod {
     Chip,
     Contactless,
     Online,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 /// An enum representing the possible values of the `IssuingAuthorizationRequest`'s `reason` field.
@@ -35,6 +45,11 @@ pub enum IssuingAuthorizationReason {
     WebhookApproved,
     WebhookDeclined,
     WebhookTimeout,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 /// An enum representing the possible values of an `IssuingAuthorization`'s `wallet_provider` field.
@@ -44,6 +59,11 @@ pub enum IssuingAuthorizationWalletProvider {
     ApplePay,
     GooglePay,
     SamsungPay,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingAuthorizationWalletProvider {
@@ -52,6 +72,7 @@ impl IssuingAuthorizationWalletProvider {
             IssuingAuthorizationWalletProvider::ApplePay => "apple_pay",
             IssuingAuthorizationWalletProvider::GooglePay => "google_pay",
             IssuingAuthorizationWalletProvider::SamsungPay => "samsung_pay",
+            IssuingAuthorizationWalletProvider::Other => "other",
         }
     }
 }
@@ -164,6 +164,11 @@ pub struct IssuingCardShipping {
 pub enum IssuingCardCancellationReason {
     Lost,
     Stolen,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardCancellationReason {
@@ -171,6 +176,7 @@ impl IssuingCardCancellationReason {
         match self {
             IssuingCardCancellationReason::Lost => "lost",
             IssuingCardCancellationReason::Stolen => "stolen",
+            IssuingCardCancellationReason::Other => "other",
         }
     }
 }
@@ -195,6 +201,11 @@ pub enum IssuingCardReplacementReason {
     Expired,
     Lost,
     Stolen,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardReplacementReason {
@@ -204,6 +215,7 @@ impl IssuingCardReplacementReason {
             IssuingCardReplacementReason::Expired => "expired",
             IssuingCardReplacementReason::Lost => "lost",
             IssuingCardReplacementReason::Stolen => "stolen",
+            IssuingCardReplacementReason::Other => "other",
         }
     }
 }
@@ -226,6 +238,11 @@ impl std::fmt::Display for IssuingCardReplacementReason {
 pub enum IssuingCardShippingCarrier {
     Fedex,
     Usps,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardShippingCarrier {
@@ -233,6 +250,7 @@ impl IssuingCardShippingCarrier {
         match self {
             IssuingCardShippingCarrier::Fedex => "fedex",
             IssuingCardShippingCarrier::Usps => "usps",
+            IssuingCardShippingCarrier::Other => "other",
         }
     }
 }
@@ -256,6 +274,11 @@ pub enum IssuingCardShippingService {
     Express,
     Priority,
     Standard,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardShippingService {
@@ -264,6 +287,7 @@ impl IssuingCardShippingService {
             IssuingCardShippingService::Express => "express",
             IssuingCardShippingService::Priority => "priority",
             IssuingCardShippingService::Standard => "standard",
+            IssuingCardShippingService::Other => "other",
         }
     }
 }
@@ -287,6 +311,11 @@ pub enum IssuingCardStatus {
     Active,
     Canceled,
     Inactive,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardStatus {
@@ -295,6 +324,7 @@ impl IssuingCardStatus {
             IssuingCardStatus::Active => "active",
             IssuingCardStatus::Canceled => "canceled",
             IssuingCardStatus::Inactive => "inactive",
+            IssuingCardStatus::Other => "other",
         }
     }
 }
@@ -6,6 +6,11 @@ use serde_derive::{Deserialize, Serialize};
 pub enum IssuingCardPinStatus {
     Active,
     Blocked,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardPinStatus {
@@ -13,6 +18,7 @@ impl IssuingCardPinStatus {
         match self {
             IssuingCardPinStatus::Active => "active",
             IssuingCardPinStatus::Blocked => "blocked",
+            IssuingCardPinStatus::Other => "other",
         }
     }
 }
@@ -39,6 +45,11 @@ pub enum IssuingCardShippingStatus {
     Pending,
     Returned,
     Shipped,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardShippingStatus {
@@ -50,6 +61,7 @@ impl IssuingCardShippingStatus {
             IssuingCardShippingStatus::Pending => "pending",
             IssuingCardShippingStatus::Returned => "returned",
             IssuingCardShippingStatus::Shipped => "shipped",
+            IssuingCardShippingStatus::Other => "other",
         }
     }
 }
@@ -72,6 +84,11 @@ impl std::fmt::Display for IssuingCardShippingStatus {
 pub enum IssuingCardShippingType {
     Bulk,
     Individual,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardShippingType {
@@ -79,6 +96,7 @@ impl IssuingCardShippingType {
         match self {
             IssuingCardShippingType::Bulk => "bulk",
             IssuingCardShippingType::Individual => "individual",
+            IssuingCardShippingType::Other => "other",
         }
     }
 }
@@ -101,6 +119,11 @@ impl std::fmt::Display for IssuingCardShippingType {
 pub enum IssuingCardType {
     Physical,
     Virtual,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardType {
@@ -108,6 +131,7 @@ impl IssuingCardType {
         match self {
             IssuingCardType::Physical => "physical",
             IssuingCardType::Virtual => "virtual",
+            IssuingCardType::Other => "other",
         }
     }
 }
@@ -174,6 +174,11 @@ pub enum IssuingCardholderRequirementsDisabledReason {
     #[serde(rename = "rejected.listed")]
     RejectedListed,
     UnderReview,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardholderRequirementsDisabledReason {
@@ -182,6 +187,7 @@ impl IssuingCardholderRequirementsDisabledReason {
             IssuingCardholderRequirementsDisabledReason::Listed => "listed",
             IssuingCardholderRequirementsDisabledReason::RejectedListed => "rejected.listed",
             IssuingCardholderRequirementsDisabledReason::UnderReview => "under_review",
+            IssuingCardholderRequirementsDisabledReason::Other => "other",
         }
     }
 }
@@ -216,6 +222,11 @@ pub enum IssuingCardholderRequirementsPastDue {
     IndividualLastName,
     #[serde(rename = "individual.verification.document")]
     IndividualVerificationDocument,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardholderRequirementsPastDue {
@@ -230,6 +241,7 @@ impl IssuingCardholderRequirementsPastDue {
             IssuingCardholderRequirementsPastDue::IndividualVerificationDocument => {
                 "individual.verification.document"
             }
+            IssuingCardholderRequirementsPastDue::Other => "other",
         }
     }
 }
@@ -253,6 +265,11 @@ pub enum IssuingCardholderStatus {
     Active,
     Blocked,
     Inactive,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardholderStatus {
@@ -261,6 +278,7 @@ impl IssuingCardholderStatus {
             IssuingCardholderStatus::Active => "active",
             IssuingCardholderStatus::Blocked => "blocked",
             IssuingCardholderStatus::Inactive => "inactive",
+            IssuingCardholderStatus::Other => "other",
         }
     }
 }
@@ -283,6 +301,11 @@ impl std::fmt::Display for IssuingCardholderStatus {
 pub enum IssuingCardholderType {
     Company,
     Individual,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingCardholderType {
@@ -290,6 +313,7 @@ impl IssuingCardholderType {
         match self {
             IssuingCardholderType::Company => "company",
             IssuingCardholderType::Individual => "individual",
+            IssuingCardholderType::Other => "other",
         }
     }
 }
@@ -5,6 +5,7 @@ use serde_derive::{Deserialize, Serialize};
 #[serde(rename_all = "snake_case")]
 pub enum IssuingDisputeReason {
     Fraudulent,
+    #[serde(other)]
     Other,
 }
 
@@ -37,6 +38,11 @@ pub enum IssuingDisputeStatus {
     UnderReview,
     Unsubmitted,
     Won,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingDisputeStatus {
@@ -46,6 +52,7 @@ impl IssuingDisputeStatus {
             IssuingDisputeStatus::UnderReview => "under_review",
             IssuingDisputeStatus::Unsubmitted => "unsubmitted",
             IssuingDisputeStatus::Won => "won",
+            IssuingDisputeStatus::Other => "other",
         }
     }
 }
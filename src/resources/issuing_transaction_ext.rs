@@ -10,6 +10,11 @@ pub enum IssuingTransactionType {
     DisputeLoss,
     Refund,
     RefundReversal,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl IssuingTransactionType {
@@ -21,6 +26,7 @@ impl IssuingTransactionType {
             IssuingTransactionType::DisputeLoss => "dispute_loss",
             IssuingTransactionType::Refund => "refund",
             IssuingTransactionType::RefundReversal => "refund_reversal",
+            IssuingTransactionType::Other => "other",
         }
     }
 }
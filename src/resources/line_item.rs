@@ -114,6 +114,11 @@ pub enum InvoiceLineItemType {
     #[serde(rename = "invoiceitem")]
     InvoiceItem,
     Subscription,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl InvoiceLineItemType {
@@ -121,6 +126,7 @@ impl InvoiceLineItemType {
         match self {
             InvoiceLineItemType::InvoiceItem => "invoiceitem",
             InvoiceLineItemType::Subscription => "subscription",
+            InvoiceLineItemType::Other => "other",
         }
     }
 }
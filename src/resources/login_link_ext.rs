@@ -0,0 +1,14 @@
+use crate::config::{Client, Response};
+use crate::ids::AccountId;
+use crate::resources::LoginLink;
+
+impl LoginLink {
+    /// Creates a single-use login link for an Express account to access their Stripe dashboard.
+    ///
+    /// You may only create login links for Express accounts connected to your platform.
+    ///
+    /// For more details see [https://stripe.com/docs/api/account/login_link](https://stripe.com/docs/api/account/login_link).
+    pub fn create(client: &Client, account_id: &AccountId) -> Response<LoginLink> {
+        client.post(&format!("/accounts/{}/login_links", account_id))
+    }
+}
@@ -147,6 +147,11 @@ pub struct OnlineAcceptance {
 pub enum CustomerAcceptanceType {
     Offline,
     Online,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CustomerAcceptanceType {
@@ -154,6 +159,7 @@ impl CustomerAcceptanceType {
         match self {
             CustomerAcceptanceType::Offline => "offline",
             CustomerAcceptanceType::Online => "online",
+            CustomerAcceptanceType::Other => "other",
         }
     }
 }
@@ -177,6 +183,11 @@ pub enum MandateStatus {
     Active,
     Inactive,
     Pending,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl MandateStatus {
@@ -185,6 +196,7 @@ impl MandateStatus {
             MandateStatus::Active => "active",
             MandateStatus::Inactive => "inactive",
             MandateStatus::Pending => "pending",
+            MandateStatus::Other => "other",
         }
     }
 }
@@ -207,6 +219,11 @@ impl std::fmt::Display for MandateStatus {
 pub enum MandateType {
     MultiUse,
     SingleUse,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl MandateType {
@@ -214,6 +231,7 @@ impl MandateType {
         match self {
             MandateType::MultiUse => "multi_use",
             MandateType::SingleUse => "single_use",
+            MandateType::Other => "other",
         }
     }
 }
@@ -0,0 +1,248 @@
+use crate::config::{Client, Response};
+use crate::error::Error;
+use crate::ids::AccountId;
+use crate::resources::{BusinessType, Currency};
+use serde_derive::{Deserialize, Serialize};
+
+/// The Stripe Connect OAuth endpoints served from `https://connect.stripe.com`.
+///
+/// For more details see [https://stripe.com/docs/connect/oauth-reference](https://stripe.com/docs/connect/oauth-reference).
+pub struct OAuth;
+
+impl OAuth {
+    /// Builds the `https://connect.stripe.com/oauth/authorize` url a user should be
+    /// redirected to in order to connect their account to your platform.
+    ///
+    /// For more details see [https://stripe.com/docs/connect/oauth-reference#get-authorize](https://stripe.com/docs/connect/oauth-reference#get-authorize).
+    pub fn authorize_url(params: &AuthorizeUrlParams<'_>) -> Result<String, Error> {
+        let query = serde_qs::to_string(params).map_err(Error::serialize)?;
+        Ok(format!("https://connect.stripe.com/oauth/authorize?{}", query))
+    }
+
+    /// Exchanges the authorization code returned to your `redirect_uri` for the
+    /// connected account's id and keys.
+    ///
+    /// For more details see [https://stripe.com/docs/connect/oauth-reference#post-token](https://stripe.com/docs/connect/oauth-reference#post-token).
+    pub fn token(client: &Client, code: &str) -> Response<OAuthToken> {
+        client.post_form_connect(
+            "/oauth/token",
+            TokenParams { grant_type: "authorization_code", code },
+        )
+    }
+
+    /// Revokes your platform's access to a connected account.
+    ///
+    /// For more details see [https://stripe.com/docs/connect/oauth-reference#post-deauthorize](https://stripe.com/docs/connect/oauth-reference#post-deauthorize).
+    pub fn deauthorize(
+        client: &Client,
+        client_id: &str,
+        stripe_user_id: &AccountId,
+    ) -> Response<OAuthDeauthorization> {
+        client.post_form_connect(
+            "/oauth/deauthorize",
+            DeauthorizeParams { client_id, stripe_user_id },
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct TokenParams<'a> {
+    grant_type: &'static str,
+    code: &'a str,
+}
+
+#[derive(Serialize)]
+struct DeauthorizeParams<'a> {
+    client_id: &'a str,
+    stripe_user_id: &'a AccountId,
+}
+
+/// The response from `OAuth::token`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OAuthToken {
+    /// Deprecated; the connected account's secret key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+
+    /// Whether the connected account was authorized in live mode.
+    pub livemode: bool,
+
+    /// A refresh token which can be used to generate new access tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+
+    /// The scope granted to the access token.
+    pub scope: OAuthScope,
+
+    /// The connected account's publishable key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_publishable_key: Option<String>,
+
+    /// The unique id of the connected account.
+    pub stripe_user_id: AccountId,
+
+    /// Always has the value `bearer`.
+    pub token_type: String,
+}
+
+/// The response from `OAuth::deauthorize`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OAuthDeauthorization {
+    /// The unique id of the disconnected account.
+    pub stripe_user_id: AccountId,
+}
+
+/// The parameters for `OAuth::authorize_url`.
+#[derive(Clone, Debug, Serialize)]
+pub struct AuthorizeUrlParams<'a> {
+    /// The unique identifier provided to your application, found in your application settings.
+    pub client_id: &'a str,
+
+    /// Always has the value `code`.
+    pub response_type: &'static str,
+
+    /// Boolean to indicate that the user should always be asked to connect, even if they're already connected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub always_prompt: Option<bool>,
+
+    /// The URL for the authorize response to be sent to.
+    ///
+    /// Must be one of the redirect URIs configured in your application settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<&'a str>,
+
+    /// The scope of the access being requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<OAuthScope>,
+
+    /// An arbitrary string value that will be passed back to your `redirect_uri`,
+    /// used to protect against CSRF attacks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<&'a str>,
+
+    /// Whether to show the user the sign in or sign up page first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_landing: Option<OAuthStripeLanding>,
+
+    /// Information used to prefill the account application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_user: Option<OAuthStripeUser<'a>>,
+}
+
+impl<'a> AuthorizeUrlParams<'a> {
+    pub fn new(client_id: &'a str) -> Self {
+        AuthorizeUrlParams {
+            client_id,
+            response_type: "code",
+            always_prompt: Default::default(),
+            redirect_uri: Default::default(),
+            scope: Default::default(),
+            state: Default::default(),
+            stripe_landing: Default::default(),
+            stripe_user: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OAuthStripeUser<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_type: Option<BusinessType>,
+
+    /// Two-letter country code (e.g., `US` or `CA`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+
+    /// The business phone number, without the country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+
+    /// The URL of the user's business website.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+/// An enum representing the possible values of an `AuthorizeUrlParams`'s `scope` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthScope {
+    ReadOnly,
+    ReadWrite,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl OAuthScope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OAuthScope::ReadOnly => "read_only",
+            OAuthScope::ReadWrite => "read_write",
+            OAuthScope::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for OAuthScope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for OAuthScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `AuthorizeUrlParams`'s `stripe_landing` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthStripeLanding {
+    Login,
+    Register,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl OAuthStripeLanding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OAuthStripeLanding::Login => "login",
+            OAuthStripeLanding::Register => "register",
+            OAuthStripeLanding::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for OAuthStripeLanding {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for OAuthStripeLanding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -414,7 +414,7 @@ impl<'a> UpdateOrder<'a> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ListOrdersStatusTransitions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub canceled: Option<RangeQuery<Timestamp>>,
@@ -459,6 +459,11 @@ pub enum OrderItemParamsType {
     Shipping,
     Sku,
     Tax,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl OrderItemParamsType {
@@ -468,6 +473,7 @@ impl OrderItemParamsType {
             OrderItemParamsType::Shipping => "shipping",
             OrderItemParamsType::Sku => "sku",
             OrderItemParamsType::Tax => "tax",
+            OrderItemParamsType::Other => "other",
         }
     }
 }
@@ -493,6 +499,11 @@ pub enum OrderStatus {
     Fulfilled,
     Paid,
     Returned,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl OrderStatus {
@@ -503,6 +514,7 @@ impl OrderStatus {
             OrderStatus::Fulfilled => "fulfilled",
             OrderStatus::Paid => "paid",
             OrderStatus::Returned => "returned",
+            OrderStatus::Other => "other",
         }
     }
 }
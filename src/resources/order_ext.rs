@@ -8,6 +8,11 @@ pub enum OrderStatusFilter {
     Fulfilled,
     Paid,
     Refunded,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl OrderStatusFilter {
@@ -17,6 +22,7 @@ impl OrderStatusFilter {
             OrderStatusFilter::Fulfilled => "fulfilled",
             OrderStatusFilter::Paid => "paid",
             OrderStatusFilter::Refunded => "refunded",
+            OrderStatusFilter::Other => "other",
         }
     }
 }
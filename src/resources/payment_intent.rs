@@ -1,11 +1,19 @@
 use crate::config::{Client, Response};
-use crate::ids::{CustomerId, PaymentIntentId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::error::{DeclineCode, ErrorCode};
+use crate::ids::{
+    AccountId, CustomerId, PaymentIntentId, PaymentMethodConfigurationId, PaymentMethodId,
+};
+use crate::params::{
+    Expand, Expandable, List, Metadata, Object, RangeQuery, SearchList, StatementDescriptor,
+    Timestamp,
+};
 use crate::resources::{
-    Account, Application, Charge, Currency, Customer, Invoice, PaymentIntentOffSession,
-    PaymentMethod, PaymentSource, Review, Shipping, TransferDataParams,
+    Account, Application, Charge, Currency, Customer, CustomerAcceptance, CustomerAcceptanceType,
+    Invoice, Money, OfflineAcceptance, OnlineAcceptance, PaymentIntentOffSession, PaymentMethod,
+    PaymentSource, Review, Shipping, TransferDataParams,
 };
 use serde_derive::{Deserialize, Serialize};
+use std::convert::TryFrom;
 
 /// The resource representing a Stripe "PaymentIntent".
 ///
@@ -36,6 +44,10 @@ pub struct PaymentIntent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_amount: Option<i64>,
 
+    /// Settings to configure compatible payment methods from the [Stripe Dashboard](https://dashboard.stripe.com/settings/payment_methods).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_payment_methods: Option<PaymentIntentAutomaticPaymentMethods>,
+
     /// Populated when `status` is `canceled`, this is the time at which the PaymentIntent was canceled.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -167,6 +179,11 @@ pub struct PaymentIntent {
 }
 
 impl PaymentIntent {
+    /// The amount intended to be collected, together with its currency.
+    pub fn money(&self) -> Money {
+        Money::new(self.amount, self.currency)
+    }
+
     /// Creates a new payment_intent.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/create](https://stripe.com/docs/api/payment_intents/create).
@@ -177,8 +194,12 @@ impl PaymentIntent {
     /// Retrieves the details of a payment_intent.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/retrieve](https://stripe.com/docs/api/payment_intents/retrieve).
-    pub fn retrieve(client: &Client, payment_intent_id: &str) -> Response<PaymentIntent> {
-        client.get(&format!("/payment_intents/{}", payment_intent_id))
+    pub fn retrieve(
+        client: &Client,
+        id: &PaymentIntentId,
+        expand: &[&str],
+    ) -> Response<PaymentIntent> {
+        client.get_query(&format!("/payment_intents/{}", id), &Expand { expand })
     }
 
     /// Updates a payment_intent's properties.
@@ -186,10 +207,10 @@ impl PaymentIntent {
     /// For more details see [https://stripe.com/docs/api/payment_intents/update](https://stripe.com/docs/api/payment_intents/update).
     pub fn update(
         client: &Client,
-        payment_intent_id: &str,
+        id: &PaymentIntentId,
         params: PaymentIntentUpdateParams<'_>,
     ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}", payment_intent_id), params)
+        client.post_form(&format!("/payment_intents/{}", id), params)
     }
 
     /// Confirm that customer intends to pay with current or provided source. Upon confirmation, the PaymentIntent will attempt to initiate a payment.
@@ -197,10 +218,10 @@ impl PaymentIntent {
     /// For more details see [https://stripe.com/docs/api/payment_intents/confirm](https://stripe.com/docs/api/payment_intents/confirm).
     pub fn confirm(
         client: &Client,
-        payment_intent_id: &str,
+        id: &PaymentIntentId,
         params: PaymentIntentConfirmParams<'_>,
     ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/confirm", payment_intent_id), params)
+        client.post_form(&format!("/payment_intents/{}/confirm", id), params)
     }
 
     /// Capture the funds of an existing uncaptured PaymentIntent where required_action="requires_capture".
@@ -208,10 +229,34 @@ impl PaymentIntent {
     /// For more details see [https://stripe.com/docs/api/payment_intents/capture](https://stripe.com/docs/api/payment_intents/capture).
     pub fn capture(
         client: &Client,
-        payment_intent_id: &str,
+        id: &PaymentIntentId,
         params: CapturePaymentIntent,
     ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/capture", payment_intent_id), params)
+        client.post_form(&format!("/payment_intents/{}/capture", id), params)
+    }
+
+    /// Increments the amount authorized on a PaymentIntent in `requires_capture` status,
+    /// which must have been created with `request_incremental_authorization` set to `if_available`.
+    ///
+    /// For more details see [https://stripe.com/docs/api/payment_intents/increment_authorization](https://stripe.com/docs/api/payment_intents/increment_authorization).
+    pub fn increment_authorization(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: IncrementAuthorizationPaymentIntent<'_>,
+    ) -> Response<PaymentIntent> {
+        client.post_form(&format!("/payment_intents/{}/increment_authorization", id), params)
+    }
+
+    /// Manually reconciles the remaining amount of a `customer_balance` PaymentIntent
+    /// using funds from the customer's cash balance.
+    ///
+    /// For more details see [https://stripe.com/docs/api/payment_intents/apply_customer_balance](https://stripe.com/docs/api/payment_intents/apply_customer_balance).
+    pub fn apply_customer_balance(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: ApplyCustomerBalancePaymentIntent,
+    ) -> Response<PaymentIntent> {
+        client.post_form(&format!("/payment_intents/{}/apply_customer_balance", id), params)
     }
 
     /// A PaymentIntent object can be canceled when it is in one of these statuses: requires_source, requires_capture, requires_confirmation, requires_source_action.
@@ -219,10 +264,10 @@ impl PaymentIntent {
     /// For more details see [https://stripe.com/docs/api/payment_intents/cancel](https://stripe.com/docs/api/payment_intents/cancel).
     pub fn cancel(
         client: &Client,
-        payment_intent_id: &str,
+        id: &PaymentIntentId,
         params: CancelPaymentIntent,
     ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
+        client.post_form(&format!("/payment_intents/{}/cancel", id), params)
     }
 
     /// List all payment_intents.
@@ -231,6 +276,16 @@ impl PaymentIntent {
     pub fn list(client: &Client, params: ListPaymentIntents) -> Response<List<PaymentIntent>> {
         client.get_query("/payment_intents", &params)
     }
+
+    /// Search for PaymentIntents you've previously created using Stripe's Search Query Language.
+    ///
+    /// For more details see [https://stripe.com/docs/api/payment_intents/search](https://stripe.com/docs/api/payment_intents/search).
+    pub fn search(
+        client: &Client,
+        params: SearchPaymentIntents<'_>,
+    ) -> Response<SearchList<PaymentIntent>> {
+        client.get_query("/payment_intents/search", &params)
+    }
 }
 
 impl Object for PaymentIntent {
@@ -251,8 +306,8 @@ pub struct PaymentError {
     #[serde(rename = "type")]
     pub payment_error_type: PaymentErrorType,
     pub charge: Option<String>,
-    pub code: Option<String>,
-    pub decline_code: Option<String>,
+    pub code: Option<ErrorCode>,
+    pub decline_code: Option<DeclineCode>,
     pub doc_url: Option<String>,
     pub message: Option<String>,
     pub param: Option<String>,
@@ -295,12 +350,20 @@ pub enum PaymentErrorType {
 pub enum PaymentIntentMethodType {
     /// This `PaymentIntent` needs to be fulfilled through credit card payment.
     Card,
+    /// This `PaymentIntent` needs to be fulfilled through a
+    /// [bank transfer](https://stripe.com/docs/payments/bank-transfers) funding the customer's cash balance.
+    CustomerBalance,
     /// This `PaymentIntent` needs to be fulfilled through an
     /// [iDeal](https://stripe.com/docs/payments/ideal) payment.
     Ideal,
     /// This `PaymentIntent` needs to be fulfilled through a
     /// [Sepa Direct Debit](https://stripe.com/docs/payments/sepa-debit) payment.
     SepaDebit,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 /// The resource representing a Stripe CaptureMethod object.
@@ -332,32 +395,231 @@ pub enum ConfirmationMethod {
     Other,
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug, Clone, Eq)]
-#[serde(rename_all = "snake_case")]
-pub enum PaymentIntentNextActionType {
-    RedirectToUrl,
-    UseStripeSdk,
+/// The action your customer must take to complete a PaymentIntent, keyed by its `type`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaymentIntentNextAction {
+    AlipayHandleRedirect {
+        alipay_handle_redirect: PaymentIntentNextActionAlipayHandleRedirect,
+    },
+    BoletoDisplayDetails {
+        boleto_display_details: PaymentIntentNextActionBoleto,
+    },
+    DisplayBankTransferInstructions {
+        display_bank_transfer_instructions: PaymentIntentNextActionDisplayBankTransferInstructions,
+    },
+    OxxoDisplayDetails {
+        oxxo_display_details: PaymentIntentNextActionOxxo,
+    },
+    RedirectToUrl {
+        redirect_to_url: PaymentIntentNextActionRedirectToUrl,
+    },
+
+    /// When confirming a PaymentIntent with Stripe.js, Stripe.js depends on the contents of this dictionary to invoke authentication flows.
+    ///
+    /// The shape of the contents is subject to change and is only intended to be used by Stripe.js.
+    UseStripeSdk {
+        use_stripe_sdk: serde_json::Value,
+    },
+    VerifyWithMicrodeposits {
+        verify_with_microdeposits: NextActionVerifyWithMicrodeposits,
+    },
+    WechatPayDisplayQrCode {
+        wechat_pay_display_qr_code: PaymentIntentNextActionWechatPayDisplayQrCode,
+    },
 
     /// A variant not yet supported by the library.
-    /// It is an error to send `Other` as part of a request.
     #[serde(other, skip_serializing)]
     Other,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct PaymentIntentNextAction {
-    /// Type of the next action to perform, one of `redirect_to_url` or `use_stripe_sdk`.
-    #[serde(rename = "type")]
-    pub type_: PaymentIntentNextActionType,
+pub struct PaymentIntentNextActionAlipayHandleRedirect {
+    /// The native data to be used with Alipay SDK you must redirect your customer to in order to authenticate the payment in an Android App.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native_data: Option<String>,
 
+    /// The native URL you must redirect your customer to in order to authenticate the payment in an iOS App.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub redirect_to_url: Option<PaymentIntentNextActionRedirectToUrl>,
+    pub native_url: Option<String>,
 
-    /// When confirming a PaymentIntent with Stripe.js, Stripe.js depends on the contents of this dictionary to invoke authentication flows.
+    /// If the customer does not exit their browser while authenticating, they will be redirected to this specified URL after completion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+
+    /// The URL you must redirect your customer to in order to authenticate the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionBoleto {
+    /// The timestamp after which the boleto expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// The URL to the hosted boleto voucher page, which allows customers to view the boleto voucher.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_voucher_url: Option<String>,
+
+    /// The boleto number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+
+    /// The URL to the downloadable boleto voucher PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionDisplayBankTransferInstructions {
+    /// The remaining amount that needs to be transferred to complete the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_remaining: Option<i64>,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// A list of financial addresses that can be used to fund the customer balance.
+    #[serde(default)]
+    pub financial_addresses: Vec<BankTransferFinancialAddress>,
+
+    /// A link to a hosted page that guides your customer through completing the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_instructions_url: Option<String>,
+
+    /// A string identifying this payment.
     ///
-    /// The shape of the contents is subject to change and is only intended to be used by Stripe.js.
+    /// Instruct your customer to include this code in the reference or memo field of their bank transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Type of bank transfer.
+    #[serde(rename = "type")]
+    pub type_: CustomerBalanceBankTransferType,
+}
+
+/// An address your customer can send a bank transfer to.
+///
+/// Only the details matching `type` are present.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BankTransferFinancialAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aba: Option<BankTransferAbaRecord>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iban: Option<BankTransferIbanRecord>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub use_stripe_sdk: Option<serde_json::Value>,
+    pub sort_code: Option<BankTransferSortCodeRecord>,
+
+    /// The payment networks supported by this financial address.
+    #[serde(default)]
+    pub supported_networks: Vec<String>,
+
+    /// The type of financial address, e.g. `aba`, `iban` or `sort_code`.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BankTransferAbaRecord {
+    /// The ABA account number.
+    pub account_number: String,
+
+    /// The bank name.
+    pub bank_name: String,
+
+    /// The ABA routing number.
+    pub routing_number: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BankTransferIbanRecord {
+    /// The name of the person or business that owns the bank account.
+    pub account_holder_name: String,
+
+    /// The BIC/SWIFT code of the account.
+    pub bic: String,
+
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: String,
+
+    /// The IBAN of the account.
+    pub iban: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BankTransferSortCodeRecord {
+    /// The name of the person or business that owns the bank account.
+    pub account_holder_name: String,
+
+    /// The account number.
+    pub account_number: String,
+
+    /// The six-digit sort code.
+    pub sort_code: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionOxxo {
+    /// The timestamp after which the OXXO voucher expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<Timestamp>,
+
+    /// The URL for the hosted OXXO voucher page, which allows customers to view and print an OXXO voucher.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_voucher_url: Option<String>,
+
+    /// OXXO reference number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionWechatPayDisplayQrCode {
+    /// The data being used to generate QR code.
+    pub data: String,
+
+    /// The URL to the hosted WeChat Pay instructions page, which allows customers to view the WeChat Pay QR code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_instructions_url: Option<String>,
+
+    /// The base64 image data for a pre-generated QR code.
+    pub image_data_url: String,
+
+    /// The image_url_png string used to render QR code.
+    pub image_url_png: String,
+
+    /// The image_url_svg string used to render QR code.
+    pub image_url_svg: String,
+}
+
+/// Details of the microdeposits sent to verify a bank account, shared by PaymentIntents and SetupIntents.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NextActionVerifyWithMicrodeposits {
+    /// The timestamp when the microdeposits are expected to land.
+    pub arrival_date: Timestamp,
+
+    /// The URL for the hosted verification page, which allows customers to verify their bank account.
+    pub hosted_verification_url: String,
+
+    /// The type of the microdeposit sent to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub microdeposit_type: Option<MicrodepositType>,
+}
+
+/// An enum representing the possible values of `verify_with_microdeposits`'s `microdeposit_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MicrodepositType {
+    Amounts,
+    DescriptorCode,
+
+    /// A variant not yet supported by the library.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -388,11 +650,16 @@ pub struct CreatePaymentIntent<'a> {
     pub payment_method_types: Vec<PaymentIntentMethodType>,
     pub amount: u64,
     pub currency: Currency,
-    pub payment_method: Option<&'a str>,
+    pub payment_method: Option<PaymentMethodId>,
     pub confirmation_method: Option<PaymentIntentConfirmationMethod>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_amount: Option<u64>,
+
+    /// When enabled, this PaymentIntent will accept payment methods enabled in the Dashboard
+    /// instead of those listed in `payment_method_types`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_payment_methods: Option<CreatePaymentIntentAutomaticPaymentMethods>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capture_method: Option<PaymentIntentCaptureMethod>,
 
@@ -401,13 +668,36 @@ pub struct CreatePaymentIntent<'a> {
     pub confirm: Option<bool>, // TODO: Is this the correct type?
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer: Option<&'a str>,
+    pub customer: Option<CustomerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
+
+    /// Set to `true` to fail the payment attempt if the PaymentIntent transitions into `requires_action`.
+    ///
+    /// Can only be used with `confirm=true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_on_requires_action: Option<bool>,
+
+    /// Details of the customer's acceptance of a mandate, used with `confirm=true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_data: Option<PaymentIntentMandateData>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
+
+    /// Indicates that the customer is not in your checkout flow during this payment attempt.
+    ///
+    /// Can only be used with `confirm=true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<PaymentIntentOffSession>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<AccountId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub on_behalf_of: Option<&'a str>,
+    pub payment_method_configuration: Option<PaymentMethodConfigurationId>,
+
+    /// Payment-method-specific configuration for this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<CreatePaymentIntentPaymentMethodOptions>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -419,7 +709,7 @@ pub struct CreatePaymentIntent<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statement_descriptor: Option<&'a str>,
+    pub statement_descriptor: Option<StatementDescriptor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transfer_data: Option<TransferDataParams>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -427,6 +717,16 @@ pub struct CreatePaymentIntent<'a> {
 }
 
 impl<'a> CreatePaymentIntent<'a> {
+    /// Creates the parameters for a PaymentIntent collecting `money`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount is negative.
+    pub fn from_money(money: Money) -> Self {
+        let amount = u64::try_from(money.amount_minor).expect("amount must not be negative");
+        CreatePaymentIntent::new(amount, money.currency)
+    }
+
     pub fn new(amount: u64, currency: Currency) -> Self {
         CreatePaymentIntent {
             payment_method_types: Default::default(),
@@ -435,12 +735,18 @@ impl<'a> CreatePaymentIntent<'a> {
             payment_method: Default::default(),
             confirmation_method: Default::default(),
             application_fee_amount: Default::default(),
+            automatic_payment_methods: Default::default(),
             capture_method: Default::default(),
             confirm: Default::default(),
             customer: Default::default(),
             description: Default::default(),
+            error_on_requires_action: Default::default(),
+            mandate_data: Default::default(),
             metadata: Default::default(),
+            off_session: Default::default(),
             on_behalf_of: Default::default(),
+            payment_method_configuration: Default::default(),
+            payment_method_options: Default::default(),
             receipt_email: Default::default(),
             return_url: Default::default(),
             save_source_to_customer: Default::default(),
@@ -453,6 +759,35 @@ impl<'a> CreatePaymentIntent<'a> {
     }
 }
 
+crate::params::impl_setters! {
+    CreatePaymentIntent<'a> {
+        application_fee_amount: u64,
+        automatic_payment_methods: CreatePaymentIntentAutomaticPaymentMethods,
+        capture_method: PaymentIntentCaptureMethod,
+        confirm: bool,
+        confirmation_method: PaymentIntentConfirmationMethod,
+        customer: CustomerId,
+        description: &'a str,
+        error_on_requires_action: bool,
+        mandate_data: PaymentIntentMandateData,
+        metadata: Metadata,
+        off_session: PaymentIntentOffSession,
+        on_behalf_of: AccountId,
+        payment_method: PaymentMethodId,
+        payment_method_configuration: PaymentMethodConfigurationId,
+        payment_method_options: CreatePaymentIntentPaymentMethodOptions,
+        payment_method_types = Vec<PaymentIntentMethodType>,
+        receipt_email: &'a str,
+        return_url: &'a str,
+        save_source_to_customer: bool,
+        shipping: Shipping,
+        source: &'a str,
+        statement_descriptor: StatementDescriptor,
+        transfer_data: TransferDataParams,
+        transfer_group: &'a str,
+    }
+}
+
 /// The set of parameters that can be used when updating a payment_intent object.
 ///
 /// For more details see [https://stripe.com/docs/api/payment_intents/update](https://stripe.com/docs/api/payment_intents/update)
@@ -465,12 +800,14 @@ pub struct PaymentIntentUpdateParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer: Option<&'a str>,
+    pub customer: Option<CustomerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<CreatePaymentIntentPaymentMethodOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_source_to_customer: Option<bool>,
@@ -487,6 +824,21 @@ pub struct PaymentIntentUpdateParams<'a> {
 /// For more details see [https://stripe.com/docs/api/payment_intents/confirm](https://stripe.com/docs/api/payment_intents/confirm)
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct PaymentIntentConfirmParams<'a> {
+    /// Set to `true` to fail the payment attempt if the PaymentIntent transitions into `requires_action`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_on_requires_action: Option<bool>,
+
+    /// Details of the customer's acceptance of a mandate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_data: Option<PaymentIntentMandateData>,
+
+    /// Indicates that the customer is not in your checkout flow during this payment attempt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<PaymentIntentOffSession>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PaymentMethodId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<CreatePaymentIntentPaymentMethodOptions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -504,10 +856,185 @@ pub struct PaymentIntentConfirmParams<'a> {
 /// For more details see [https://stripe.com/docs/api/payment_intents/capture](https://stripe.com/docs/api/payment_intents/capture)
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct CapturePaymentIntent {
+    /// The amount to capture, which must be less than or equal to the original amount.
+    ///
+    /// Any additional amount will be automatically refunded. Defaults to the full `amount_capturable` if not provided.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount_to_capture: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_amount: Option<u64>,
+
+    /// Whether this is the last capture of a PaymentIntent created with `request_multicapture`.
+    ///
+    /// Defaults to `true`, which releases any remaining uncaptured amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_capture: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when incrementing the authorization of a payment_intent object.
+///
+/// For more details see [https://stripe.com/docs/api/payment_intents/increment_authorization](https://stripe.com/docs/api/payment_intents/increment_authorization)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct IncrementAuthorizationPaymentIntent<'a> {
+    /// The updated total amount you intend to collect from the cardholder.
+    ///
+    /// This amount must be greater than the currently authorized amount.
+    pub amount: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<StatementDescriptor>,
+}
+
+impl<'a> IncrementAuthorizationPaymentIntent<'a> {
+    pub fn new(amount: u64) -> Self {
+        IncrementAuthorizationPaymentIntent {
+            amount,
+            application_fee_amount: Default::default(),
+            description: Default::default(),
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The set of parameters that can be used when applying a customer's cash balance to a payment_intent object.
+///
+/// For more details see [https://stripe.com/docs/api/payment_intents/apply_customer_balance](https://stripe.com/docs/api/payment_intents/apply_customer_balance)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ApplyCustomerBalancePaymentIntent {
+    /// The amount to apply, which defaults to the remaining `amount` of the PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+}
+
+/// The customer's acceptance of the mandate used for a merchant-initiated payment.
+#[derive(Clone, Debug, Serialize)]
+pub struct PaymentIntentMandateData {
+    pub customer_acceptance: CustomerAcceptance,
+}
+
+impl PaymentIntentMandateData {
+    /// A mandate accepted online, e.g. through a checkbox on your payment page.
+    pub fn online(ip_address: impl Into<String>, user_agent: impl Into<String>) -> Self {
+        PaymentIntentMandateData {
+            customer_acceptance: CustomerAcceptance {
+                accepted_at: None,
+                offline: None,
+                online: Some(OnlineAcceptance {
+                    ip_address: Some(ip_address.into()),
+                    user_agent: Some(user_agent.into()),
+                }),
+                type_: CustomerAcceptanceType::Online,
+            },
+        }
+    }
+
+    /// A mandate accepted offline, e.g. on a signed paper form.
+    pub fn offline(accepted_at: Timestamp) -> Self {
+        PaymentIntentMandateData {
+            customer_acceptance: CustomerAcceptance {
+                accepted_at: Some(accepted_at),
+                offline: Some(OfflineAcceptance {}),
+                online: None,
+                type_: CustomerAcceptanceType::Offline,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreatePaymentIntentAutomaticPaymentMethods {
+    /// Whether this feature is enabled.
+    pub enabled: bool,
+
+    /// Controls whether this PaymentIntent will accept redirect-based payment methods.
+    ///
+    /// Redirect-based payment methods may require your customer to be redirected to a payment method's app or site for authentication or additional steps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_redirects: Option<AutomaticPaymentMethodsAllowRedirects>,
+}
+
+impl CreatePaymentIntentAutomaticPaymentMethods {
+    pub fn new(enabled: bool) -> Self {
+        CreatePaymentIntentAutomaticPaymentMethods { enabled, allow_redirects: Default::default() }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentAutomaticPaymentMethods {
+    /// Automatically calculates compatible payment methods.
+    pub enabled: bool,
+
+    /// Controls whether this PaymentIntent will accept redirect-based payment methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_redirects: Option<AutomaticPaymentMethodsAllowRedirects>,
+}
+
+/// Payment-method-specific configuration, used when creating, updating or confirming a PaymentIntent.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreatePaymentIntentPaymentMethodOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<CreatePaymentIntentPaymentMethodOptionsCard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_balance: Option<CreatePaymentIntentPaymentMethodOptionsCustomerBalance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<CreatePaymentIntentPaymentMethodOptionsSepaDebit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<CreatePaymentIntentPaymentMethodOptionsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreatePaymentIntentPaymentMethodOptionsCard {
+    /// Request the ability to capture the card for longer than the standard authorization validity window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_extended_authorization: Option<CardFeatureRequest>,
+
+    /// Request the ability to increment the authorization with `PaymentIntent::increment_authorization`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_incremental_authorization: Option<CardFeatureRequest>,
+
+    /// Request the ability to make multiple captures, see `CapturePaymentIntent::final_capture`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_multicapture: Option<CardFeatureRequest>,
+
+    /// Request the ability to capture more than the authorized amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_overcapture: Option<CardFeatureRequest>,
+
+    /// When to request 3D Secure authentication, which by default follows Stripe's SCA engine.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_three_d_secure: Option<RequestThreeDSecure>,
+
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<PaymentMethodOptionsSetupFutureUsage>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreatePaymentIntentPaymentMethodOptionsSepaDebit {
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<PaymentMethodOptionsSetupFutureUsage>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreatePaymentIntentPaymentMethodOptionsUsBankAccount {
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<PaymentMethodOptionsSetupFutureUsage>,
+
+    /// How the customer's bank account is verified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<UsBankAccountVerificationMethod>,
 }
 
 /// The set of parameters that can be used when canceling a payment_intent object.
@@ -520,7 +1047,7 @@ pub struct CancelPaymentIntent {
 }
 
 /// The parameters for `PaymentIntent::list`.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Default)]
 pub struct ListPaymentIntents<'a> {
     /// A filter on the list, based on the object `created` field.
     ///
@@ -537,7 +1064,7 @@ pub struct ListPaymentIntents<'a> {
     /// `ending_before` is an object ID that defines your place in the list.
     /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ending_before: Option<&'a PaymentIntentId>,
+    pub ending_before: Option<PaymentIntentId>,
 
     /// Specifies which fields in the response should be expanded.
     #[serde(skip_serializing_if = "Expand::is_empty")]
@@ -554,7 +1081,197 @@ pub struct ListPaymentIntents<'a> {
     /// `starting_after` is an object ID that defines your place in the list.
     /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub starting_after: Option<&'a PaymentIntentId>,
+    pub starting_after: Option<PaymentIntentId>,
+}
+
+impl<'a> ListPaymentIntents<'a> {
+    pub fn new() -> Self {
+        ListPaymentIntents {
+            created: Default::default(),
+            customer: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `PaymentIntent::search`.
+#[derive(Clone, Debug, Serialize)]
+pub struct SearchPaymentIntents<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for pagination across multiple pages of results.
+    ///
+    /// Don't include this parameter on the first call.
+    /// Use the `next_page` value returned in a previous response to request subsequent results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<&'a str>,
+
+    /// The search query string.
+    ///
+    /// See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for payment intents](https://stripe.com/docs/search#query-fields-for-payment-intents).
+    pub query: &'a str,
+}
+
+impl<'a> SearchPaymentIntents<'a> {
+    pub fn new(query: &'a str) -> Self {
+        SearchPaymentIntents {
+            expand: Default::default(),
+            limit: Default::default(),
+            page: Default::default(),
+            query,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreatePaymentIntentPaymentMethodOptionsCustomerBalance {
+    /// Configuration for the bank transfer funding type, if `funding_type` is `bank_transfer`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_transfer: Option<CustomerBalanceBankTransferParams>,
+
+    /// The funding method type to be used when there are not enough funds in the customer balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_type: Option<CustomerBalanceFundingType>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CustomerBalanceBankTransferParams {
+    /// The list of bank transfer address types (e.g. `iban`, `sort_code`, `zengin`) to show the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_address_types: Option<Vec<String>>,
+
+    /// Configuration for `eu_bank_transfer`, which requires the country of the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eu_bank_transfer: Option<CustomerBalanceEuBankTransferParams>,
+
+    /// The bank transfer type that this PaymentIntent is allowed to use for funding.
+    #[serde(rename = "type")]
+    pub type_: CustomerBalanceBankTransferType,
+}
+
+impl CustomerBalanceBankTransferParams {
+    pub fn new(type_: CustomerBalanceBankTransferType) -> Self {
+        CustomerBalanceBankTransferParams {
+            requested_address_types: Default::default(),
+            eu_bank_transfer: Default::default(),
+            type_,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CustomerBalanceEuBankTransferParams {
+    /// The desired country code of the bank account information.
+    ///
+    /// Permitted values include: `BE`, `DE`, `ES`, `FR`, `IE`, or `NL`.
+    pub country: String,
+}
+
+/// An enum representing the possible values of a customer balance's `bank_transfer.type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerBalanceBankTransferType {
+    EuBankTransfer,
+    GbBankTransfer,
+    JpBankTransfer,
+    MxBankTransfer,
+    UsBankTransfer,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// An enum representing the possible values of a customer balance's `funding_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerBalanceFundingType {
+    BankTransfer,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// An enum representing the possible values of `automatic_payment_methods`'s `allow_redirects` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomaticPaymentMethodsAllowRedirects {
+    Always,
+    Never,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// An enum representing the possible values of a card's `request_three_d_secure` option.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestThreeDSecure {
+    Any,
+    Automatic,
+    Challenge,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// An enum representing the possible values of a payment method option's `setup_future_usage` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodOptionsSetupFutureUsage {
+    None,
+    OffSession,
+    OnSession,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// An enum representing the possible values of a US bank account's `verification_method` option.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UsBankAccountVerificationMethod {
+    Automatic,
+    Instant,
+    Microdeposits,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// Whether to request a card feature, such as incremental authorization, for a PaymentIntent.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CardFeatureRequest {
+    IfAvailable,
+    Never,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 /// An enum representing the possible values of an `PaymentIntent`'s `cancellation_reason` field.
@@ -568,6 +1285,11 @@ pub enum PaymentIntentCancellationReason {
     Fraudulent,
     RequestedByCustomer,
     VoidInvoice,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 /// An enum representing the possible values of an `PaymentIntent`'s `capture_method` field.
@@ -576,6 +1298,11 @@ pub enum PaymentIntentCancellationReason {
 pub enum PaymentIntentCaptureMethod {
     Automatic,
     Manual,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 /// An enum representing the possible values of an `PaymentIntent`'s `confirmation_method` field.
@@ -584,6 +1311,11 @@ pub enum PaymentIntentCaptureMethod {
 pub enum PaymentIntentConfirmationMethod {
     Automatic,
     Manual,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 /// An enum representing the possible values of an `PaymentIntent`'s `status` field.
@@ -598,4 +1330,9 @@ pub enum PaymentIntentStatus {
     RequiresPaymentMethod,
     RequiresSource,
     Succeeded,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
@@ -530,7 +530,7 @@ pub struct CreatePaymentMethodFpx {
     pub bank: CreatePaymentMethodFpxBank,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreatePaymentMethodIdeal {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bank: Option<CreatePaymentMethodIdealBank>,
@@ -541,7 +541,7 @@ pub struct CreatePaymentMethodSepaDebit {
     pub iban: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdatePaymentMethodAuBecsDebit {}
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -571,6 +571,11 @@ pub enum CreatePaymentMethodFpxBank {
     Rhb,
     StandardChartered,
     Uob,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreatePaymentMethodFpxBank {
@@ -596,6 +601,7 @@ impl CreatePaymentMethodFpxBank {
             CreatePaymentMethodFpxBank::Rhb => "rhb",
             CreatePaymentMethodFpxBank::StandardChartered => "standard_chartered",
             CreatePaymentMethodFpxBank::Uob => "uob",
+            CreatePaymentMethodFpxBank::Other => "other",
         }
     }
 }
@@ -628,6 +634,11 @@ pub enum CreatePaymentMethodIdealBank {
     SnsBank,
     TriodosBank,
     VanLanschot,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreatePaymentMethodIdealBank {
@@ -645,6 +656,7 @@ impl CreatePaymentMethodIdealBank {
             CreatePaymentMethodIdealBank::SnsBank => "sns_bank",
             CreatePaymentMethodIdealBank::TriodosBank => "triodos_bank",
             CreatePaymentMethodIdealBank::VanLanschot => "van_lanschot",
+            CreatePaymentMethodIdealBank::Other => "other",
         }
     }
 }
@@ -685,6 +697,11 @@ pub enum PaymentMethodFpxBank {
     Rhb,
     StandardChartered,
     Uob,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PaymentMethodFpxBank {
@@ -710,6 +727,7 @@ impl PaymentMethodFpxBank {
             PaymentMethodFpxBank::Rhb => "rhb",
             PaymentMethodFpxBank::StandardChartered => "standard_chartered",
             PaymentMethodFpxBank::Uob => "uob",
+            PaymentMethodFpxBank::Other => "other",
         }
     }
 }
@@ -742,6 +760,11 @@ pub enum PaymentMethodIdealBank {
     SnsBank,
     TriodosBank,
     VanLanschot,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PaymentMethodIdealBank {
@@ -759,6 +782,7 @@ impl PaymentMethodIdealBank {
             PaymentMethodIdealBank::SnsBank => "sns_bank",
             PaymentMethodIdealBank::TriodosBank => "triodos_bank",
             PaymentMethodIdealBank::VanLanschot => "van_lanschot",
+            PaymentMethodIdealBank::Other => "other",
         }
     }
 }
@@ -803,6 +827,11 @@ pub enum PaymentMethodIdealBic {
     Snsbnl2a,
     #[serde(rename = "TRIONL2U")]
     Trionl2u,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PaymentMethodIdealBic {
@@ -820,6 +849,7 @@ impl PaymentMethodIdealBic {
             PaymentMethodIdealBic::Rbrbnl21 => "RBRBNL21",
             PaymentMethodIdealBic::Snsbnl2a => "SNSBNL2A",
             PaymentMethodIdealBic::Trionl2u => "TRIONL2U",
+            PaymentMethodIdealBic::Other => "other",
         }
     }
 }
@@ -845,6 +875,11 @@ pub enum PaymentMethodType {
     Fpx,
     Ideal,
     SepaDebit,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PaymentMethodType {
@@ -855,6 +890,7 @@ impl PaymentMethodType {
             PaymentMethodType::Fpx => "fpx",
             PaymentMethodType::Ideal => "ideal",
             PaymentMethodType::SepaDebit => "sepa_debit",
+            PaymentMethodType::Other => "other",
         }
     }
 }
@@ -881,6 +917,11 @@ pub enum PaymentMethodTypeFilter {
     Fpx,
     Ideal,
     SepaDebit,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PaymentMethodTypeFilter {
@@ -892,6 +933,7 @@ impl PaymentMethodTypeFilter {
             PaymentMethodTypeFilter::Fpx => "fpx",
             PaymentMethodTypeFilter::Ideal => "ideal",
             PaymentMethodTypeFilter::SepaDebit => "sepa_debit",
+            PaymentMethodTypeFilter::Other => "other",
         }
     }
 }
@@ -918,6 +960,11 @@ pub enum WalletDetailsType {
     Masterpass,
     SamsungPay,
     VisaCheckout,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl WalletDetailsType {
@@ -929,6 +976,7 @@ impl WalletDetailsType {
             WalletDetailsType::Masterpass => "masterpass",
             WalletDetailsType::SamsungPay => "samsung_pay",
             WalletDetailsType::VisaCheckout => "visa_checkout",
+            WalletDetailsType::Other => "other",
         }
     }
 }
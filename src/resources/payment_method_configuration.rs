@@ -0,0 +1,643 @@
+use crate::config::{Client, Response};
+use crate::ids::{ApplicationId, PaymentMethodConfigurationId};
+use crate::params::{Expand, List, Object};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "PaymentMethodConfiguration".
+///
+/// PaymentMethodConfigurations control which payment methods are displayed to your customers when you don't explicitly specify payment method types.
+/// You can have multiple configurations with different sets of payment methods for different scenarios.
+///
+/// For more details see [https://stripe.com/docs/api/payment_method_configurations](https://stripe.com/docs/api/payment_method_configurations).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodConfiguration {
+    /// Unique identifier for the object.
+    pub id: PaymentMethodConfigurationId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<PaymentMethodConfigurationDetails>,
+
+    /// Whether the configuration can be used for new payments.
+    pub active: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affirm: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub afterpay_clearpay: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alipay: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apple_pay: Option<PaymentMethodConfigurationDetails>,
+
+    /// For child configs, the Connect application associated with the configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub au_becs_debit: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bacs_debit: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bancontact: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blik: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boleto: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cartes_bancaires: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cashapp: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eps: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fpx: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giropay: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_pay: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grabpay: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ideal: Option<PaymentMethodConfigurationDetails>,
+
+    /// The default configuration is used whenever a payment method configuration is not specified.
+    pub is_default: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jcb: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub klarna: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub konbini: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<PaymentMethodConfigurationDetails>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The configuration's name.
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oxxo: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p24: Option<PaymentMethodConfigurationDetails>,
+
+    /// For child configs, the configuration's parent configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<PaymentMethodConfigurationId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paynow: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paypal: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promptpay: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sofort: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<PaymentMethodConfigurationDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wechat_pay: Option<PaymentMethodConfigurationDetails>,
+}
+
+impl PaymentMethodConfiguration {
+    /// List payment method configurations.
+    pub fn list(
+        client: &Client,
+        params: ListPaymentMethodConfigurations<'_>,
+    ) -> Response<List<PaymentMethodConfiguration>> {
+        client.get_query("/payment_method_configurations", &params)
+    }
+
+    /// Creates a payment method configuration.
+    pub fn create(
+        client: &Client,
+        params: CreatePaymentMethodConfiguration<'_>,
+    ) -> Response<PaymentMethodConfiguration> {
+        client.post_form("/payment_method_configurations", &params)
+    }
+
+    /// Retrieve payment method configuration.
+    pub fn retrieve(
+        client: &Client,
+        id: &PaymentMethodConfigurationId,
+        expand: &[&str],
+    ) -> Response<PaymentMethodConfiguration> {
+        client.get_query(&format!("/payment_method_configurations/{}", id), &Expand { expand })
+    }
+
+    /// Update payment method configuration.
+    pub fn update(
+        client: &Client,
+        id: &PaymentMethodConfigurationId,
+        params: UpdatePaymentMethodConfiguration<'_>,
+    ) -> Response<PaymentMethodConfiguration> {
+        client.post_form(&format!("/payment_method_configurations/{}", id), &params)
+    }
+}
+
+impl Object for PaymentMethodConfiguration {
+    type Id = PaymentMethodConfigurationId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "payment_method_configuration"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodConfigurationDetails {
+    /// Whether this payment method may be offered at checkout.
+    ///
+    /// True if `display_preference` is `on` and the payment method's capability is active.
+    pub available: bool,
+
+    pub display_preference: PaymentMethodConfigurationDisplayPreference,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodConfigurationDisplayPreference {
+    /// For child configs, whether or not the account's preference will be observed.
+    ///
+    /// If `false`, the parent configuration's default is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overridable: Option<bool>,
+
+    /// The account's display preference.
+    pub preference: PaymentMethodConfigurationPreference,
+
+    /// The effective display preference value.
+    pub value: PaymentMethodConfigurationValue,
+}
+
+/// The parameters for `PaymentMethodConfiguration::create`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreatePaymentMethodConfiguration<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affirm: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub afterpay_clearpay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alipay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apple_pay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub au_becs_debit: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bacs_debit: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bancontact: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blik: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boleto: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cartes_bancaires: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cashapp: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eps: Option<PaymentMethodConfigurationParams>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fpx: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giropay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_pay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grabpay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ideal: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jcb: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub klarna: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub konbini: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<PaymentMethodConfigurationParams>,
+
+    /// Configuration name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oxxo: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p24: Option<PaymentMethodConfigurationParams>,
+
+    /// Configuration's parent configuration.
+    ///
+    /// Specify to create a child configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<PaymentMethodConfigurationId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paynow: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paypal: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promptpay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sofort: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wechat_pay: Option<PaymentMethodConfigurationParams>,
+}
+
+impl<'a> CreatePaymentMethodConfiguration<'a> {
+    pub fn new() -> Self {
+        CreatePaymentMethodConfiguration {
+            acss_debit: Default::default(),
+            affirm: Default::default(),
+            afterpay_clearpay: Default::default(),
+            alipay: Default::default(),
+            apple_pay: Default::default(),
+            au_becs_debit: Default::default(),
+            bacs_debit: Default::default(),
+            bancontact: Default::default(),
+            blik: Default::default(),
+            boleto: Default::default(),
+            card: Default::default(),
+            cartes_bancaires: Default::default(),
+            cashapp: Default::default(),
+            eps: Default::default(),
+            expand: Default::default(),
+            fpx: Default::default(),
+            giropay: Default::default(),
+            google_pay: Default::default(),
+            grabpay: Default::default(),
+            ideal: Default::default(),
+            jcb: Default::default(),
+            klarna: Default::default(),
+            konbini: Default::default(),
+            link: Default::default(),
+            name: Default::default(),
+            oxxo: Default::default(),
+            p24: Default::default(),
+            parent: Default::default(),
+            paynow: Default::default(),
+            paypal: Default::default(),
+            promptpay: Default::default(),
+            sepa_debit: Default::default(),
+            sofort: Default::default(),
+            us_bank_account: Default::default(),
+            wechat_pay: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `PaymentMethodConfiguration::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListPaymentMethodConfigurations<'a> {
+    /// The Connect application to filter by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<ApplicationId>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<PaymentMethodConfigurationId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<PaymentMethodConfigurationId>,
+}
+
+impl<'a> ListPaymentMethodConfigurations<'a> {
+    pub fn new() -> Self {
+        ListPaymentMethodConfigurations {
+            application: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `PaymentMethodConfiguration::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdatePaymentMethodConfiguration<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<PaymentMethodConfigurationParams>,
+
+    /// Whether the configuration can be used for new payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affirm: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub afterpay_clearpay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alipay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apple_pay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub au_becs_debit: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bacs_debit: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bancontact: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blik: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boleto: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cartes_bancaires: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cashapp: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eps: Option<PaymentMethodConfigurationParams>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fpx: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giropay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_pay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grabpay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ideal: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jcb: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub klarna: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub konbini: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<PaymentMethodConfigurationParams>,
+
+    /// Configuration name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oxxo: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p24: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paynow: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paypal: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promptpay: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sofort: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<PaymentMethodConfigurationParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wechat_pay: Option<PaymentMethodConfigurationParams>,
+}
+
+impl<'a> UpdatePaymentMethodConfiguration<'a> {
+    pub fn new() -> Self {
+        UpdatePaymentMethodConfiguration {
+            acss_debit: Default::default(),
+            active: Default::default(),
+            affirm: Default::default(),
+            afterpay_clearpay: Default::default(),
+            alipay: Default::default(),
+            apple_pay: Default::default(),
+            au_becs_debit: Default::default(),
+            bacs_debit: Default::default(),
+            bancontact: Default::default(),
+            blik: Default::default(),
+            boleto: Default::default(),
+            card: Default::default(),
+            cartes_bancaires: Default::default(),
+            cashapp: Default::default(),
+            eps: Default::default(),
+            expand: Default::default(),
+            fpx: Default::default(),
+            giropay: Default::default(),
+            google_pay: Default::default(),
+            grabpay: Default::default(),
+            ideal: Default::default(),
+            jcb: Default::default(),
+            klarna: Default::default(),
+            konbini: Default::default(),
+            link: Default::default(),
+            name: Default::default(),
+            oxxo: Default::default(),
+            p24: Default::default(),
+            paynow: Default::default(),
+            paypal: Default::default(),
+            promptpay: Default::default(),
+            sepa_debit: Default::default(),
+            sofort: Default::default(),
+            us_bank_account: Default::default(),
+            wechat_pay: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentMethodConfigurationParams {
+    /// Whether or not the payment method should be displayed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_preference: Option<PaymentMethodConfigurationDisplayPreferenceParams>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentMethodConfigurationDisplayPreferenceParams {
+    /// The account's preference for whether or not to display this payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preference: Option<PaymentMethodConfigurationPreference>,
+}
+
+/// An enum representing the possible values of an `PaymentMethodConfigurationDisplayPreference`'s `preference` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodConfigurationPreference {
+    None,
+    Off,
+    On,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl PaymentMethodConfigurationPreference {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentMethodConfigurationPreference::None => "none",
+            PaymentMethodConfigurationPreference::Off => "off",
+            PaymentMethodConfigurationPreference::On => "on",
+            PaymentMethodConfigurationPreference::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentMethodConfigurationPreference {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentMethodConfigurationPreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PaymentMethodConfigurationDisplayPreference`'s `value` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodConfigurationValue {
+    Off,
+    On,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl PaymentMethodConfigurationValue {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentMethodConfigurationValue::Off => "off",
+            PaymentMethodConfigurationValue::On => "on",
+            PaymentMethodConfigurationValue::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentMethodConfigurationValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentMethodConfigurationValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
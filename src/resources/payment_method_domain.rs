@@ -0,0 +1,243 @@
+use crate::config::{Client, Response};
+use crate::ids::PaymentMethodDomainId;
+use crate::params::{Expand, List, Object, Timestamp};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "PaymentMethodDomain".
+///
+/// A payment method domain represents a web domain that you have registered with Stripe.
+/// Stripe Elements use registered payment method domains to control where certain payment methods are shown.
+///
+/// For more details see [https://stripe.com/docs/api/payment_method_domains](https://stripe.com/docs/api/payment_method_domains).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodDomain {
+    /// Unique identifier for the object.
+    pub id: PaymentMethodDomainId,
+
+    pub apple_pay: PaymentMethodDomainStatus,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// The domain name that this payment method domain object represents.
+    pub domain_name: String,
+
+    /// Whether this payment method domain is enabled.
+    ///
+    /// If the domain is not enabled, payment methods that require a payment method domain will not appear in Elements.
+    pub enabled: bool,
+
+    pub google_pay: PaymentMethodDomainStatus,
+
+    pub link: PaymentMethodDomainStatus,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    pub paypal: PaymentMethodDomainStatus,
+}
+
+impl PaymentMethodDomain {
+    /// Lists the details of existing payment method domains.
+    pub fn list(
+        client: &Client,
+        params: ListPaymentMethodDomains<'_>,
+    ) -> Response<List<PaymentMethodDomain>> {
+        client.get_query("/payment_method_domains", &params)
+    }
+
+    /// Creates a payment method domain.
+    pub fn create(
+        client: &Client,
+        params: CreatePaymentMethodDomain<'_>,
+    ) -> Response<PaymentMethodDomain> {
+        client.post_form("/payment_method_domains", &params)
+    }
+
+    /// Retrieves the details of an existing payment method domain.
+    pub fn retrieve(
+        client: &Client,
+        id: &PaymentMethodDomainId,
+        expand: &[&str],
+    ) -> Response<PaymentMethodDomain> {
+        client.get_query(&format!("/payment_method_domains/{}", id), &Expand { expand })
+    }
+
+    /// Updates an existing payment method domain.
+    pub fn update(
+        client: &Client,
+        id: &PaymentMethodDomainId,
+        params: UpdatePaymentMethodDomain<'_>,
+    ) -> Response<PaymentMethodDomain> {
+        client.post_form(&format!("/payment_method_domains/{}", id), &params)
+    }
+
+    /// Some payment methods such as Apple Pay require additional steps to verify a domain.
+    ///
+    /// If the requirements weren't satisfied when the domain was created, the payment method will be inactive on the domain.
+    /// The payment method doesn't appear in Elements for this domain until it is active.
+    /// To activate a payment method on an existing payment method domain, complete the required validation steps specific to the payment method, and then validate the payment method domain with this endpoint.
+    pub fn validate(client: &Client, id: &PaymentMethodDomainId) -> Response<PaymentMethodDomain> {
+        client.post(&format!("/payment_method_domains/{}/validate", id))
+    }
+}
+
+impl Object for PaymentMethodDomain {
+    type Id = PaymentMethodDomainId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "payment_method_domain"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodDomainStatus {
+    /// The status of the payment method on the domain.
+    pub status: PaymentMethodDomainStatusStatus,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_details: Option<PaymentMethodDomainStatusDetails>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodDomainStatusDetails {
+    /// The error message associated with the status of the payment method on the domain.
+    pub error_message: String,
+}
+
+/// The parameters for `PaymentMethodDomain::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreatePaymentMethodDomain<'a> {
+    /// The domain name that this payment method domain object represents.
+    pub domain_name: &'a str,
+
+    /// Whether this payment method domain is enabled.
+    ///
+    /// If the domain is not enabled, payment methods that require a payment method domain will not appear in Elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CreatePaymentMethodDomain<'a> {
+    pub fn new(domain_name: &'a str) -> Self {
+        CreatePaymentMethodDomain {
+            domain_name,
+            enabled: Default::default(),
+            expand: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `PaymentMethodDomain::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListPaymentMethodDomains<'a> {
+    /// The domain name that this payment method domain object represents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<&'a str>,
+
+    /// Whether this payment method domain is enabled.
+    ///
+    /// If the domain is not enabled, payment methods will not appear in Elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<PaymentMethodDomainId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<PaymentMethodDomainId>,
+}
+
+impl<'a> ListPaymentMethodDomains<'a> {
+    pub fn new() -> Self {
+        ListPaymentMethodDomains {
+            domain_name: Default::default(),
+            enabled: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `PaymentMethodDomain::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdatePaymentMethodDomain<'a> {
+    /// Whether this payment method domain is enabled.
+    ///
+    /// If the domain is not enabled, payment methods that require a payment method domain will not appear in Elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> UpdatePaymentMethodDomain<'a> {
+    pub fn new() -> Self {
+        UpdatePaymentMethodDomain { enabled: Default::default(), expand: Default::default() }
+    }
+}
+
+/// An enum representing the possible values of an `PaymentMethodDomainStatus`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodDomainStatusStatus {
+    Active,
+    Inactive,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl PaymentMethodDomainStatusStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentMethodDomainStatusStatus::Active => "active",
+            PaymentMethodDomainStatusStatus::Inactive => "inactive",
+            PaymentMethodDomainStatusStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentMethodDomainStatusStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentMethodDomainStatusStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -296,6 +296,11 @@ pub enum PayoutDestination {
 pub enum PayoutMethod {
     Instant,
     Standard,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PayoutMethod {
@@ -303,6 +308,7 @@ impl PayoutMethod {
         match self {
             PayoutMethod::Instant => "instant",
             PayoutMethod::Standard => "standard",
+            PayoutMethod::Other => "other",
         }
     }
 }
@@ -326,6 +332,11 @@ pub enum PayoutSourceType {
     BankAccount,
     Card,
     Fpx,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PayoutSourceType {
@@ -334,6 +345,7 @@ impl PayoutSourceType {
             PayoutSourceType::BankAccount => "bank_account",
             PayoutSourceType::Card => "card",
             PayoutSourceType::Fpx => "fpx",
+            PayoutSourceType::Other => "other",
         }
     }
 }
@@ -356,6 +368,11 @@ impl std::fmt::Display for PayoutSourceType {
 pub enum PayoutType {
     BankAccount,
     Card,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PayoutType {
@@ -363,6 +380,7 @@ impl PayoutType {
         match self {
             PayoutType::BankAccount => "bank_account",
             PayoutType::Card => "card",
+            PayoutType::Other => "other",
         }
     }
 }
@@ -265,6 +265,11 @@ pub enum AccountRequirementsErrorCode {
     VerificationFailedKeyedMatch,
     VerificationFailedNameMatch,
     VerificationFailedOther,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl AccountRequirementsErrorCode {
@@ -378,6 +383,7 @@ impl AccountRequirementsErrorCode {
                 "verification_failed_name_match"
             }
             AccountRequirementsErrorCode::VerificationFailedOther => "verification_failed_other",
+            AccountRequirementsErrorCode::Other => "other",
         }
     }
 }
@@ -19,6 +19,23 @@ impl Object for Account {
     }
 }
 
+#[cfg(not(feature = "connect"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccountSession {
+    pub id: (),
+}
+
+#[cfg(not(feature = "connect"))]
+impl Object for AccountSession {
+    type Id = ();
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "account_session"
+    }
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Application {
@@ -53,6 +70,108 @@ impl Object for ApplicationFee {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingAlert {
+    pub id: BillingAlertId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for BillingAlert {
+    type Id = BillingAlertId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing.alert"
+    }
+}
+
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeter {
+    pub id: BillingMeterId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for BillingMeter {
+    type Id = BillingMeterId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing.meter"
+    }
+}
+
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterEvent {
+    pub id: (),
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for BillingMeterEvent {
+    type Id = ();
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing.meter_event"
+    }
+}
+
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterEventAdjustment {
+    pub id: (),
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for BillingMeterEventAdjustment {
+    type Id = ();
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing.meter_event_adjustment"
+    }
+}
+
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingMeterEventSummary {
+    pub id: BillingMeterEventSummaryId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for BillingMeterEventSummary {
+    type Id = BillingMeterEventSummaryId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing.meter_event_summary"
+    }
+}
+
+#[cfg(not(feature = "connect"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Capability {
+    pub id: CapabilityId,
+}
+
+#[cfg(not(feature = "connect"))]
+impl Object for Capability {
+    type Id = CapabilityId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "capability"
+    }
+}
+
 #[cfg(not(feature = "checkout"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CheckoutSession {
@@ -87,6 +206,23 @@ impl Object for ConnectCollectionTransfer {
     }
 }
 
+#[cfg(not(feature = "connect"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CountrySpec {
+    pub id: CountrySpecId,
+}
+
+#[cfg(not(feature = "connect"))]
+impl Object for CountrySpec {
+    type Id = CountrySpecId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "country_spec"
+    }
+}
+
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Coupon {
@@ -121,6 +257,40 @@ impl Object for Discount {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EntitlementsActiveEntitlement {
+    pub id: EntitlementsActiveEntitlementId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for EntitlementsActiveEntitlement {
+    type Id = EntitlementsActiveEntitlementId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "entitlements.active_entitlement"
+    }
+}
+
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EntitlementsFeature {
+    pub id: EntitlementsFeatureId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for EntitlementsFeature {
+    type Id = EntitlementsFeatureId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "entitlements.feature"
+    }
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ApplicationFeeRefund {
@@ -155,6 +325,23 @@ impl Object for Invoice {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InvoiceRenderingTemplate {
+    pub id: InvoiceRenderingTemplateId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for InvoiceRenderingTemplate {
+    type Id = InvoiceRenderingTemplateId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "invoice_rendering_template"
+    }
+}
+
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InvoiceItem {
@@ -274,6 +461,23 @@ impl Object for InvoiceLineItem {
     }
 }
 
+#[cfg(not(feature = "connect"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LoginLink {
+    pub id: (),
+}
+
+#[cfg(not(feature = "connect"))]
+impl Object for LoginLink {
+    type Id = ();
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "login_link"
+    }
+}
+
 #[cfg(not(feature = "orders"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Order {
@@ -359,6 +563,23 @@ impl Object for Plan {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProductFeature {
+    pub id: ProductFeatureId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for ProductFeature {
+    type Id = ProductFeatureId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "product_feature"
+    }
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Recipient {
@@ -495,6 +716,40 @@ impl Object for SubscriptionScheduleRevision {
     }
 }
 
+#[cfg(not(feature = "tax"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaxRegistration {
+    pub id: TaxRegistrationId,
+}
+
+#[cfg(not(feature = "tax"))]
+impl Object for TaxRegistration {
+    type Id = TaxRegistrationId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "tax.registration"
+    }
+}
+
+#[cfg(not(feature = "tax"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaxSettings {
+    pub id: (),
+}
+
+#[cfg(not(feature = "tax"))]
+impl Object for TaxSettings {
+    type Id = ();
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "tax.settings"
+    }
+}
+
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TaxId {
@@ -529,6 +784,23 @@ impl Object for TaxRate {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TestHelpersTestClock {
+    pub id: TestHelpersTestClockId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for TestHelpersTestClock {
+    type Id = TestHelpersTestClockId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "test_helpers.test_clock"
+    }
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Topup {
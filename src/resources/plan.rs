@@ -3,7 +3,7 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::PlanId;
+use crate::ids::{PlanId, ProductId};
 use crate::params::{
     Deleted, Expand, Expandable, IdOrCreate, List, Metadata, Object, RangeQuery, Timestamp,
 };
@@ -365,7 +365,7 @@ pub struct ListPlans<'a> {
 
     /// Only return plans for the given product.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product: Option<IdOrCreate<'a, CreateProduct<'a>>>,
+    pub product: Option<ProductId>,
 
     /// A cursor for use in pagination.
     ///
@@ -416,7 +416,7 @@ pub struct UpdatePlan<'a> {
     ///
     /// Note that after updating, statement descriptors and line items of the plan in active subscriptions will be affected.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product: Option<IdOrCreate<'a, CreateProduct<'a>>>,
+    pub product: Option<ProductId>,
 
     /// Default number of trial days when subscribing a customer to this plan using [`trial_from_plan=true`](https://stripe.com/docs/api#create_subscription-trial_from_plan).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -436,7 +436,7 @@ impl<'a> UpdatePlan<'a> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreatePlanTiers {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flat_amount: Option<i64>,
@@ -466,6 +466,11 @@ pub struct CreatePlanTransformUsage {
 pub enum CreatePlanTransformUsageRound {
     Down,
     Up,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreatePlanTransformUsageRound {
@@ -473,6 +478,7 @@ impl CreatePlanTransformUsageRound {
         match self {
             CreatePlanTransformUsageRound::Down => "down",
             CreatePlanTransformUsageRound::Up => "up",
+            CreatePlanTransformUsageRound::Other => "other",
         }
     }
 }
@@ -497,6 +503,11 @@ pub enum PlanAggregateUsage {
     LastEver,
     Max,
     Sum,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PlanAggregateUsage {
@@ -506,6 +517,7 @@ impl PlanAggregateUsage {
             PlanAggregateUsage::LastEver => "last_ever",
             PlanAggregateUsage::Max => "max",
             PlanAggregateUsage::Sum => "sum",
+            PlanAggregateUsage::Other => "other",
         }
     }
 }
@@ -528,6 +540,11 @@ impl std::fmt::Display for PlanAggregateUsage {
 pub enum PlanBillingScheme {
     PerUnit,
     Tiered,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PlanBillingScheme {
@@ -535,6 +552,7 @@ impl PlanBillingScheme {
         match self {
             PlanBillingScheme::PerUnit => "per_unit",
             PlanBillingScheme::Tiered => "tiered",
+            PlanBillingScheme::Other => "other",
         }
     }
 }
@@ -559,6 +577,11 @@ pub enum PlanInterval {
     Month,
     Week,
     Year,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PlanInterval {
@@ -568,6 +591,7 @@ impl PlanInterval {
             PlanInterval::Month => "month",
             PlanInterval::Week => "week",
             PlanInterval::Year => "year",
+            PlanInterval::Other => "other",
         }
     }
 }
@@ -590,6 +614,11 @@ impl std::fmt::Display for PlanInterval {
 pub enum PlanTiersMode {
     Graduated,
     Volume,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PlanTiersMode {
@@ -597,6 +626,7 @@ impl PlanTiersMode {
         match self {
             PlanTiersMode::Graduated => "graduated",
             PlanTiersMode::Volume => "volume",
+            PlanTiersMode::Other => "other",
         }
     }
 }
@@ -619,6 +649,11 @@ impl std::fmt::Display for PlanTiersMode {
 pub enum PlanUsageType {
     Licensed,
     Metered,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PlanUsageType {
@@ -626,6 +661,7 @@ impl PlanUsageType {
         match self {
             PlanUsageType::Licensed => "licensed",
             PlanUsageType::Metered => "metered",
+            PlanUsageType::Other => "other",
         }
     }
 }
@@ -648,6 +684,11 @@ impl std::fmt::Display for PlanUsageType {
 pub enum TransformUsageRound {
     Down,
     Up,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TransformUsageRound {
@@ -655,6 +696,7 @@ impl TransformUsageRound {
         match self {
             TransformUsageRound::Down => "down",
             TransformUsageRound::Up => "up",
+            TransformUsageRound::Other => "other",
         }
     }
 }
@@ -3,11 +3,9 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::PriceId;
-use crate::params::{
-    Expand, Expandable, IdOrCreate, List, Metadata, Object, RangeQuery, Timestamp,
-};
-use crate::resources::{CreateProduct, Currency, Product, UpTo};
+use crate::ids::{PriceId, ProductId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::resources::{Currency, Product, UpTo};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "Price".
@@ -248,7 +246,7 @@ pub struct CreatePrice<'a> {
 
     /// The ID of the product that this price will belong to.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product: Option<IdOrCreate<'a, CreateProduct<'a>>>,
+    pub product: Option<ProductId>,
 
     /// These fields can be used to create a new product that this price will belong to.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -355,7 +353,7 @@ pub struct ListPrices<'a> {
 
     /// Only return prices for the given product.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product: Option<IdOrCreate<'a, CreateProduct<'a>>>,
+    pub product: Option<ProductId>,
 
     /// Only return prices with these recurring fields.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -481,7 +479,7 @@ pub struct CreatePriceRecurring {
     pub usage_type: Option<CreatePriceRecurringUsageType>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreatePriceTiers {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flat_amount: Option<i64>,
@@ -505,7 +503,7 @@ pub struct CreatePriceTransformQuantity {
     pub round: CreatePriceTransformQuantityRound,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ListPricesRecurring {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval: Option<ListPricesRecurringInterval>,
@@ -514,7 +512,7 @@ pub struct ListPricesRecurring {
     pub usage_type: Option<ListPricesRecurringUsageType>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdatePriceRecurring {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u32>,
@@ -528,6 +526,11 @@ pub enum CreatePriceRecurringAggregateUsage {
     LastEver,
     Max,
     Sum,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreatePriceRecurringAggregateUsage {
@@ -537,6 +540,7 @@ impl CreatePriceRecurringAggregateUsage {
             CreatePriceRecurringAggregateUsage::LastEver => "last_ever",
             CreatePriceRecurringAggregateUsage::Max => "max",
             CreatePriceRecurringAggregateUsage::Sum => "sum",
+            CreatePriceRecurringAggregateUsage::Other => "other",
         }
     }
 }
@@ -561,6 +565,11 @@ pub enum CreatePriceRecurringInterval {
     Month,
     Week,
     Year,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreatePriceRecurringInterval {
@@ -570,6 +579,7 @@ impl CreatePriceRecurringInterval {
             CreatePriceRecurringInterval::Month => "month",
             CreatePriceRecurringInterval::Week => "week",
             CreatePriceRecurringInterval::Year => "year",
+            CreatePriceRecurringInterval::Other => "other",
         }
     }
 }
@@ -592,6 +602,11 @@ impl std::fmt::Display for CreatePriceRecurringInterval {
 pub enum CreatePriceRecurringUsageType {
     Licensed,
     Metered,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreatePriceRecurringUsageType {
@@ -599,6 +614,7 @@ impl CreatePriceRecurringUsageType {
         match self {
             CreatePriceRecurringUsageType::Licensed => "licensed",
             CreatePriceRecurringUsageType::Metered => "metered",
+            CreatePriceRecurringUsageType::Other => "other",
         }
     }
 }
@@ -621,6 +637,11 @@ impl std::fmt::Display for CreatePriceRecurringUsageType {
 pub enum CreatePriceTransformQuantityRound {
     Down,
     Up,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreatePriceTransformQuantityRound {
@@ -628,6 +649,7 @@ impl CreatePriceTransformQuantityRound {
         match self {
             CreatePriceTransformQuantityRound::Down => "down",
             CreatePriceTransformQuantityRound::Up => "up",
+            CreatePriceTransformQuantityRound::Other => "other",
         }
     }
 }
@@ -652,6 +674,11 @@ pub enum ListPricesRecurringInterval {
     Month,
     Week,
     Year,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl ListPricesRecurringInterval {
@@ -661,6 +688,7 @@ impl ListPricesRecurringInterval {
             ListPricesRecurringInterval::Month => "month",
             ListPricesRecurringInterval::Week => "week",
             ListPricesRecurringInterval::Year => "year",
+            ListPricesRecurringInterval::Other => "other",
         }
     }
 }
@@ -683,6 +711,11 @@ impl std::fmt::Display for ListPricesRecurringInterval {
 pub enum ListPricesRecurringUsageType {
     Licensed,
     Metered,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl ListPricesRecurringUsageType {
@@ -690,6 +723,7 @@ impl ListPricesRecurringUsageType {
         match self {
             ListPricesRecurringUsageType::Licensed => "licensed",
             ListPricesRecurringUsageType::Metered => "metered",
+            ListPricesRecurringUsageType::Other => "other",
         }
     }
 }
@@ -712,6 +746,11 @@ impl std::fmt::Display for ListPricesRecurringUsageType {
 pub enum PriceBillingScheme {
     PerUnit,
     Tiered,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PriceBillingScheme {
@@ -719,6 +758,7 @@ impl PriceBillingScheme {
         match self {
             PriceBillingScheme::PerUnit => "per_unit",
             PriceBillingScheme::Tiered => "tiered",
+            PriceBillingScheme::Other => "other",
         }
     }
 }
@@ -741,6 +781,11 @@ impl std::fmt::Display for PriceBillingScheme {
 pub enum PriceTiersMode {
     Graduated,
     Volume,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PriceTiersMode {
@@ -748,6 +793,7 @@ impl PriceTiersMode {
         match self {
             PriceTiersMode::Graduated => "graduated",
             PriceTiersMode::Volume => "volume",
+            PriceTiersMode::Other => "other",
         }
     }
 }
@@ -770,6 +816,11 @@ impl std::fmt::Display for PriceTiersMode {
 pub enum PriceType {
     OneTime,
     Recurring,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PriceType {
@@ -777,6 +828,7 @@ impl PriceType {
         match self {
             PriceType::OneTime => "one_time",
             PriceType::Recurring => "recurring",
+            PriceType::Other => "other",
         }
     }
 }
@@ -801,6 +853,11 @@ pub enum RecurringAggregateUsage {
     LastEver,
     Max,
     Sum,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl RecurringAggregateUsage {
@@ -810,6 +867,7 @@ impl RecurringAggregateUsage {
             RecurringAggregateUsage::LastEver => "last_ever",
             RecurringAggregateUsage::Max => "max",
             RecurringAggregateUsage::Sum => "sum",
+            RecurringAggregateUsage::Other => "other",
         }
     }
 }
@@ -834,6 +892,11 @@ pub enum RecurringInterval {
     Month,
     Week,
     Year,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl RecurringInterval {
@@ -843,6 +906,7 @@ impl RecurringInterval {
             RecurringInterval::Month => "month",
             RecurringInterval::Week => "week",
             RecurringInterval::Year => "year",
+            RecurringInterval::Other => "other",
         }
     }
 }
@@ -865,6 +929,11 @@ impl std::fmt::Display for RecurringInterval {
 pub enum RecurringUsageType {
     Licensed,
     Metered,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl RecurringUsageType {
@@ -872,6 +941,7 @@ impl RecurringUsageType {
         match self {
             RecurringUsageType::Licensed => "licensed",
             RecurringUsageType::Metered => "metered",
+            RecurringUsageType::Other => "other",
         }
     }
 }
@@ -894,6 +964,11 @@ impl std::fmt::Display for RecurringUsageType {
 pub enum TransformQuantityRound {
     Down,
     Up,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TransformQuantityRound {
@@ -901,6 +976,7 @@ impl TransformQuantityRound {
         match self {
             TransformQuantityRound::Down => "down",
             TransformQuantityRound::Up => "up",
+            TransformQuantityRound::Other => "other",
         }
     }
 }
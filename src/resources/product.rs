@@ -474,6 +474,11 @@ impl<'a> UpdateProduct<'a> {
 pub enum ProductType {
     Good,
     Service,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl ProductType {
@@ -481,6 +486,7 @@ impl ProductType {
         match self {
             ProductType::Good => "good",
             ProductType::Service => "service",
+            ProductType::Other => "other",
         }
     }
 }
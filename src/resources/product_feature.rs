@@ -0,0 +1,127 @@
+use crate::config::{Client, Response};
+use crate::ids::{EntitlementsFeatureId, ProductFeatureId, ProductId};
+use crate::params::{Deleted, Expand, List, Object};
+use crate::resources::EntitlementsFeature;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "ProductFeature".
+///
+/// A product feature represents an attachment between a feature and a product.
+/// When a product is purchased that has a feature attached, Stripe will create an entitlement to the feature for the purchasing customer.
+///
+/// For more details see [https://stripe.com/docs/api/product-feature](https://stripe.com/docs/api/product-feature).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProductFeature {
+    /// Unique identifier for the object.
+    pub id: ProductFeatureId,
+
+    pub entitlement_feature: EntitlementsFeature,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+}
+
+impl ProductFeature {
+    /// Retrieve a list of features for a product.
+    pub fn list(
+        client: &Client,
+        product_id: &ProductId,
+        params: ListProductFeatures<'_>,
+    ) -> Response<List<ProductFeature>> {
+        client.get_query(&format!("/products/{}/features", product_id), &params)
+    }
+
+    /// Creates a product_feature, which represents a feature attachment to a product.
+    pub fn attach(
+        client: &Client,
+        product_id: &ProductId,
+        params: AttachProductFeature<'_>,
+    ) -> Response<ProductFeature> {
+        client.post_form(&format!("/products/{}/features", product_id), &params)
+    }
+
+    /// Retrieves a product_feature, which represents a feature attachment to a product.
+    pub fn retrieve(
+        client: &Client,
+        product_id: &ProductId,
+        id: &ProductFeatureId,
+        expand: &[&str],
+    ) -> Response<ProductFeature> {
+        client.get_query(&format!("/products/{}/features/{}", product_id, id), &Expand { expand })
+    }
+
+    /// Deletes the feature attachment to a product.
+    pub fn detach(
+        client: &Client,
+        product_id: &ProductId,
+        id: &ProductFeatureId,
+    ) -> Response<Deleted<ProductFeatureId>> {
+        client.delete(&format!("/products/{}/features/{}", product_id, id))
+    }
+}
+
+impl Object for ProductFeature {
+    type Id = ProductFeatureId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "product_feature"
+    }
+}
+
+/// The parameters for `ProductFeature::attach`.
+#[derive(Clone, Debug, Serialize)]
+pub struct AttachProductFeature<'a> {
+    /// The ID of the [Feature](https://stripe.com/docs/api/entitlements/feature) object attached to this product.
+    pub entitlement_feature: EntitlementsFeatureId,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> AttachProductFeature<'a> {
+    pub fn new(entitlement_feature: EntitlementsFeatureId) -> Self {
+        AttachProductFeature { entitlement_feature, expand: Default::default() }
+    }
+}
+
+/// The parameters for `ProductFeature::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListProductFeatures<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<ProductFeatureId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<ProductFeatureId>,
+}
+
+impl<'a> ListProductFeatures<'a> {
+    pub fn new() -> Self {
+        ListProductFeatures {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
@@ -301,6 +301,11 @@ impl<'a> UpdateRecipient<'a> {
 pub enum RecipientType {
     Corporation,
     Individual,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl RecipientType {
@@ -308,6 +313,7 @@ impl RecipientType {
         match self {
             RecipientType::Corporation => "corporation",
             RecipientType::Individual => "individual",
+            RecipientType::Other => "other",
         }
     }
 }
@@ -42,6 +42,10 @@ pub struct Refund {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// Where the refunded funds were sent, with any reference the customer can use to trace them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_details: Option<RefundDestinationDetails>,
+
     /// If the refund failed, this balance transaction describes the adjustment made on your account balance that reverses the initial balance transaction.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub failure_balance_transaction: Option<Expandable<BalanceTransaction>>,
@@ -128,6 +132,72 @@ impl Object for Refund {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RefundDestinationDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<RefundDestinationDetailsCard>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eu_bank_transfer: Option<RefundDestinationDetailsReference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gb_bank_transfer: Option<RefundDestinationDetailsReference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jp_bank_transfer: Option<RefundDestinationDetailsReference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mx_bank_transfer: Option<RefundDestinationDetailsReference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p24: Option<RefundDestinationDetailsReference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_transfer: Option<RefundDestinationDetailsReference>,
+
+    /// The type of transaction-specific details of the payment method used in the refund (e.g., `card`).
+    ///
+    /// An additional hash is included on `destination_details` with a name matching this value.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RefundDestinationDetailsCard {
+    /// Value of the reference number assigned to the refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Status of the reference number on the refund.
+    ///
+    /// This can be `pending`, `available` or `unavailable`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_status: Option<String>,
+
+    /// Type of the reference number assigned to the refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_type: Option<String>,
+
+    /// The type of refund.
+    ///
+    /// This can be `refund`, `reversal`, or `pending`.
+    #[serde(rename = "type")]
+    pub type_: RefundDestinationDetailsCardType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RefundDestinationDetailsReference {
+    /// The reference assigned to the refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Status of the reference on the refund.
+    ///
+    /// This can be `pending`, `available` or `unavailable`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_status: Option<String>,
+}
+
 /// The parameters for `Refund::create`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct CreateRefund<'a> {
@@ -141,6 +211,10 @@ pub struct CreateRefund<'a> {
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
 
+    /// For payment methods without native refund support (e.g., Konbini, PromptPay), use this email from the customer to receive refund instructions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions_email: Option<&'a str>,
+
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
@@ -155,9 +229,18 @@ pub struct CreateRefund<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<RefundReason>,
 
+    /// Boolean indicating whether the application fee should be refunded when refunding this charge.
+    ///
+    /// If a full charge refund is given, the full application fee will be refunded.
+    /// Otherwise, the application fee will be refunded in an amount proportional to the amount of the charge refunded.
+    /// An application fee can be refunded only by the application that created the charge.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub refund_application_fee: Option<bool>,
 
+    /// Boolean indicating whether the transfer should be reversed when refunding this charge.
+    ///
+    /// The transfer will be reversed proportionally to the amount being refunded (either the entire or partial amount).
+    /// A transfer can be reversed only by the application that created the charge.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reverse_transfer: Option<bool>,
 }
@@ -168,6 +251,7 @@ impl<'a> CreateRefund<'a> {
             amount: Default::default(),
             charge: Default::default(),
             expand: Default::default(),
+            instructions_email: Default::default(),
             metadata: Default::default(),
             payment_intent: Default::default(),
             reason: Default::default(),
@@ -259,6 +343,11 @@ pub enum RefundReason {
     Duplicate,
     Fraudulent,
     RequestedByCustomer,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl RefundReason {
@@ -267,6 +356,7 @@ impl RefundReason {
             RefundReason::Duplicate => "duplicate",
             RefundReason::Fraudulent => "fraudulent",
             RefundReason::RequestedByCustomer => "requested_by_customer",
+            RefundReason::Other => "other",
         }
     }
 }
@@ -282,3 +372,40 @@ impl std::fmt::Display for RefundReason {
         self.as_str().fmt(f)
     }
 }
+
+/// An enum representing the possible values of an `RefundDestinationDetailsCard`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundDestinationDetailsCardType {
+    Pending,
+    Refund,
+    Reversal,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl RefundDestinationDetailsCardType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RefundDestinationDetailsCardType::Pending => "pending",
+            RefundDestinationDetailsCardType::Refund => "refund",
+            RefundDestinationDetailsCardType::Reversal => "reversal",
+            RefundDestinationDetailsCardType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for RefundDestinationDetailsCardType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for RefundDestinationDetailsCardType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
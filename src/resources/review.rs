@@ -185,6 +185,11 @@ pub enum ReviewClosedReason {
     Disputed,
     Refunded,
     RefundedAsFraud,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl ReviewClosedReason {
@@ -194,6 +199,7 @@ impl ReviewClosedReason {
             ReviewClosedReason::Disputed => "disputed",
             ReviewClosedReason::Refunded => "refunded",
             ReviewClosedReason::RefundedAsFraud => "refunded_as_fraud",
+            ReviewClosedReason::Other => "other",
         }
     }
 }
@@ -216,6 +222,11 @@ impl std::fmt::Display for ReviewClosedReason {
 pub enum ReviewOpenedReason {
     Manual,
     Rule,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl ReviewOpenedReason {
@@ -223,6 +234,7 @@ impl ReviewOpenedReason {
         match self {
             ReviewOpenedReason::Manual => "manual",
             ReviewOpenedReason::Rule => "rule",
+            ReviewOpenedReason::Other => "other",
         }
     }
 }
@@ -10,6 +10,11 @@ pub enum ReviewReason {
     Refunded,
     RefundedAsFraud,
     Rule,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl ReviewReason {
@@ -21,6 +26,7 @@ impl ReviewReason {
             ReviewReason::Refunded => "refunded",
             ReviewReason::RefundedAsFraud => "refunded_as_fraud",
             ReviewReason::Rule => "rule",
+            ReviewReason::Other => "other",
         }
     }
 }
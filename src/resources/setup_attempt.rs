@@ -0,0 +1,343 @@
+use crate::config::{Client, Response};
+use crate::ids::{SetupAttemptId, SetupIntentId};
+use crate::params::{Expand, Expandable, List, Object, RangeQuery, Timestamp};
+use crate::resources::{Account, ApiErrors, Customer, PaymentMethod, SetupIntent};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "SetupAttempt".
+///
+/// A SetupAttempt describes one attempted confirmation of a SetupIntent,
+/// whether that confirmation is successful or unsuccessful.
+///
+/// For more details see [https://stripe.com/docs/api/setup_attempts](https://stripe.com/docs/api/setup_attempts).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SetupAttempt {
+    /// Unique identifier for the object.
+    pub id: SetupAttemptId,
+
+    /// The value of [application](https://stripe.com/docs/api/setup_intents/object#setup_intent_object-application) on the SetupIntent at the time of this confirmation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<String>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// The value of [customer](https://stripe.com/docs/api/setup_intents/object#setup_intent_object-customer) on the SetupIntent at the time of this confirmation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Expandable<Customer>>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The value of [on_behalf_of](https://stripe.com/docs/api/setup_intents/object#setup_intent_object-on_behalf_of) on the SetupIntent at the time of this confirmation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Expandable<Account>>,
+
+    /// ID of the payment method used with this SetupAttempt.
+    pub payment_method: Expandable<PaymentMethod>,
+
+    pub payment_method_details: SetupAttemptPaymentMethodDetails,
+
+    /// The error encountered during this attempt to confirm the SetupIntent, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_error: Option<SetupAttemptError>,
+
+    /// ID of the SetupIntent that this attempt belongs to.
+    pub setup_intent: Expandable<SetupIntent>,
+
+    /// Status of this SetupAttempt, one of `requires_confirmation`, `requires_action`, `processing`, `succeeded`, `failed`, or `abandoned`.
+    pub status: SetupAttemptStatus,
+
+    /// The value of [usage](https://stripe.com/docs/api/setup_intents/object#setup_intent_object-usage) on the SetupIntent at the time of this confirmation, one of `off_session` or `on_session`.
+    pub usage: String,
+}
+
+impl SetupAttempt {
+    /// Returns a list of SetupAttempts that associate with a provided SetupIntent.
+    pub fn list(client: &Client, params: ListSetupAttempts<'_>) -> Response<List<SetupAttempt>> {
+        client.get_query("/setup_attempts", &params)
+    }
+}
+
+impl Object for SetupAttempt {
+    type Id = SetupAttemptId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "setup_attempt"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SetupAttemptError {
+    /// For some errors that could be handled programmatically, a short string indicating the [error code](https://stripe.com/docs/error-codes) reported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// For card errors resulting from a card issuer decline, a short string indicating the [card issuer's reason for the decline](https://stripe.com/docs/declines#issuer-declines) if they provide one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decline_code: Option<String>,
+
+    /// A human-readable message providing more details about the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// If the error is parameter-specific, the parameter related to the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+
+    /// The type of error returned.
+    #[serde(rename = "type")]
+    pub type_: ApiErrors,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SetupAttemptPaymentMethodDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub au_becs_debit: Option<SetupAttemptPaymentMethodDetailsEmpty>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bacs_debit: Option<SetupAttemptPaymentMethodDetailsEmpty>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bancontact: Option<SetupAttemptPaymentMethodDetailsBankRedirect>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<SetupAttemptPaymentMethodDetailsCard>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_present: Option<SetupAttemptPaymentMethodDetailsCardPresent>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ideal: Option<SetupAttemptPaymentMethodDetailsBankRedirect>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<SetupAttemptPaymentMethodDetailsEmpty>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sofort: Option<SetupAttemptPaymentMethodDetailsBankRedirect>,
+
+    /// The type of the payment method used in the SetupIntent (e.g., `card`).
+    ///
+    /// An additional hash is included on `payment_method_details` with a name matching this value.
+    /// It contains confirmation-specific information for the payment method.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupAttemptPaymentMethodDetailsEmpty {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupAttemptPaymentMethodDetailsBankRedirect {
+    /// Bank code of bank associated with the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_code: Option<String>,
+
+    /// Name of the bank associated with the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_name: Option<String>,
+
+    /// Bank Identifier Code of the bank associated with the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bic: Option<String>,
+
+    /// The ID of the SEPA Direct Debit PaymentMethod which was generated by this SetupAttempt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit: Option<Expandable<PaymentMethod>>,
+
+    /// Last four characters of the IBAN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iban_last4: Option<String>,
+
+    /// Owner's verified full name.
+    ///
+    /// Values are verified or provided by the bank directly (if supported) at the time of authorization or settlement.
+    /// They cannot be set or mutated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupAttemptPaymentMethodDetailsCard {
+    /// Card brand.
+    ///
+    /// Can be `amex`, `diners`, `discover`, `jcb`, `mastercard`, `unionpay`, `visa`, or `unknown`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+
+    /// Check results by Card networks on Card address and CVC at the time of authorization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checks: Option<SetupAttemptPaymentMethodDetailsCardChecks>,
+
+    /// Two-letter ISO code representing the country of the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// Two-digit number representing the card's expiration month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<i64>,
+
+    /// Four-digit number representing the card's expiration year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<i64>,
+
+    /// Uniquely identifies this particular card number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+
+    /// Card funding type.
+    ///
+    /// Can be `credit`, `debit`, `prepaid`, or `unknown`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding: Option<String>,
+
+    /// The last four digits of the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<String>,
+
+    /// Identifies which network this charge was processed on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+
+    /// Populated if this authorization used 3D Secure authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub three_d_secure: Option<SetupAttemptPaymentMethodDetailsCardThreeDSecure>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupAttemptPaymentMethodDetailsCardChecks {
+    /// If a address line1 was provided, results of the check, one of `pass`, `fail`, `unavailable`, or `unchecked`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1_check: Option<String>,
+
+    /// If a address postal code was provided, results of the check, one of `pass`, `fail`, `unavailable`, or `unchecked`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_postal_code_check: Option<String>,
+
+    /// If a CVC was provided, results of the check, one of `pass`, `fail`, `unavailable`, or `unchecked`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvc_check: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupAttemptPaymentMethodDetailsCardThreeDSecure {
+    /// For authenticated transactions: how the customer was authenticated by the issuing bank.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_flow: Option<String>,
+
+    /// Indicates the outcome of 3D Secure authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+
+    /// Additional information about why 3D Secure succeeded or failed based on the `result`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_reason: Option<String>,
+
+    /// The version of 3D Secure that was used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupAttemptPaymentMethodDetailsCardPresent {
+    /// The ID of the Card PaymentMethod which was generated by this SetupAttempt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_card: Option<Expandable<PaymentMethod>>,
+}
+
+/// The parameters for `SetupAttempt::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListSetupAttempts<'a> {
+    /// A filter on the list, based on the object `created` field.
+    ///
+    /// The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<SetupAttemptId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Only return SetupAttempts created by the SetupIntent specified by this ID.
+    pub setup_intent: SetupIntentId,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<SetupAttemptId>,
+}
+
+impl<'a> ListSetupAttempts<'a> {
+    pub fn new(setup_intent: SetupIntentId) -> Self {
+        ListSetupAttempts {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            setup_intent,
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// An enum representing the possible values of an `SetupAttempt`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupAttemptStatus {
+    Abandoned,
+    Failed,
+    Processing,
+    RequiresAction,
+    RequiresConfirmation,
+    Succeeded,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl SetupAttemptStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetupAttemptStatus::Abandoned => "abandoned",
+            SetupAttemptStatus::Failed => "failed",
+            SetupAttemptStatus::Processing => "processing",
+            SetupAttemptStatus::RequiresAction => "requires_action",
+            SetupAttemptStatus::RequiresConfirmation => "requires_confirmation",
+            SetupAttemptStatus::Succeeded => "succeeded",
+            SetupAttemptStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for SetupAttemptStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetupAttemptStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
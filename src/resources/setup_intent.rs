@@ -3,10 +3,11 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::{CustomerId, PaymentMethodId, SetupIntentId};
+use crate::ids::{AccountId, CustomerId, PaymentMethodId, SetupIntentId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
-    Account, ApiErrors, Application, Currency, Customer, Mandate, PaymentMethod,
+    Account, ApiErrors, Application, Currency, Customer, Mandate,
+    NextActionVerifyWithMicrodeposits, PaymentMethod,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -148,20 +149,27 @@ impl Object for SetupIntent {
     }
 }
 
+/// The action your customer must take to complete a SetupIntent, keyed by its `type`.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct SetupIntentNextAction {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub redirect_to_url: Option<SetupIntentNextActionRedirectToUrl>,
-
-    /// Type of the next action to perform, one of `redirect_to_url` or `use_stripe_sdk`.
-    #[serde(rename = "type")]
-    pub type_: String,
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SetupIntentNextAction {
+    RedirectToUrl {
+        redirect_to_url: SetupIntentNextActionRedirectToUrl,
+    },
 
     /// When confirming a SetupIntent with Stripe.js, Stripe.js depends on the contents of this dictionary to invoke authentication flows.
     ///
     /// The shape of the contents is subject to change and is only intended to be used by Stripe.js.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub use_stripe_sdk: Option<serde_json::Value>,
+    UseStripeSdk {
+        use_stripe_sdk: serde_json::Value,
+    },
+    VerifyWithMicrodeposits {
+        verify_with_microdeposits: NextActionVerifyWithMicrodeposits,
+    },
+
+    /// A variant not yet supported by the library.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -237,7 +245,7 @@ pub struct CreateSetupIntent<'a> {
 
     /// The Stripe account ID for which this SetupIntent is created.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub on_behalf_of: Option<&'a str>,
+    pub on_behalf_of: Option<AccountId>,
 
     /// ID of the payment method (a PaymentMethod, Card, or saved Source object) to attach to this SetupIntent.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -405,7 +413,7 @@ pub struct CreateSetupIntentMandateData {
     pub customer_acceptance: CreateSetupIntentMandateDataCustomerAcceptance,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSetupIntentPaymentMethodOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card: Option<CreateSetupIntentPaymentMethodOptionsCard>,
@@ -418,7 +426,7 @@ pub struct CreateSetupIntentSingleUse {
     pub currency: Currency,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdateSetupIntentPaymentMethodOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card: Option<UpdateSetupIntentPaymentMethodOptionsCard>,
@@ -439,14 +447,14 @@ pub struct CreateSetupIntentMandateDataCustomerAcceptance {
     pub type_: CreateSetupIntentMandateDataCustomerAcceptanceType,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSetupIntentPaymentMethodOptionsCard {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_three_d_secure:
         Option<CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdateSetupIntentPaymentMethodOptionsCard {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_three_d_secure:
@@ -469,6 +477,11 @@ pub struct CreateSetupIntentMandateDataCustomerAcceptanceOnline {
 pub enum CreateSetupIntentMandateDataCustomerAcceptanceType {
     Offline,
     Online,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreateSetupIntentMandateDataCustomerAcceptanceType {
@@ -476,6 +489,7 @@ impl CreateSetupIntentMandateDataCustomerAcceptanceType {
         match self {
             CreateSetupIntentMandateDataCustomerAcceptanceType::Offline => "offline",
             CreateSetupIntentMandateDataCustomerAcceptanceType::Online => "online",
+            CreateSetupIntentMandateDataCustomerAcceptanceType::Other => "other",
         }
     }
 }
@@ -498,6 +512,11 @@ impl std::fmt::Display for CreateSetupIntentMandateDataCustomerAcceptanceType {
 pub enum CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
     Any,
     Automatic,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
@@ -505,6 +524,7 @@ impl CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
         match self {
             CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure::Any => "any",
             CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure::Automatic => "automatic",
+            CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure::Other => "other",
         }
     }
 }
@@ -528,6 +548,11 @@ pub enum SetupIntentCancellationReason {
     Abandoned,
     Duplicate,
     RequestedByCustomer,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SetupIntentCancellationReason {
@@ -536,6 +561,7 @@ impl SetupIntentCancellationReason {
             SetupIntentCancellationReason::Abandoned => "abandoned",
             SetupIntentCancellationReason::Duplicate => "duplicate",
             SetupIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
+            SetupIntentCancellationReason::Other => "other",
         }
     }
 }
@@ -559,6 +585,11 @@ pub enum SetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
     Any,
     Automatic,
     ChallengeOnly,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
@@ -569,6 +600,7 @@ impl SetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
             SetupIntentPaymentMethodOptionsCardRequestThreeDSecure::ChallengeOnly => {
                 "challenge_only"
             }
+            SetupIntentPaymentMethodOptionsCardRequestThreeDSecure::Other => "other",
         }
     }
 }
@@ -595,6 +627,11 @@ pub enum SetupIntentStatus {
     RequiresConfirmation,
     RequiresPaymentMethod,
     Succeeded,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SetupIntentStatus {
@@ -606,6 +643,7 @@ impl SetupIntentStatus {
             SetupIntentStatus::RequiresConfirmation => "requires_confirmation",
             SetupIntentStatus::RequiresPaymentMethod => "requires_payment_method",
             SetupIntentStatus::Succeeded => "succeeded",
+            SetupIntentStatus::Other => "other",
         }
     }
 }
@@ -628,6 +666,11 @@ impl std::fmt::Display for SetupIntentStatus {
 pub enum UpdateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
     Any,
     Automatic,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl UpdateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
@@ -635,6 +678,7 @@ impl UpdateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure {
         match self {
             UpdateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure::Any => "any",
             UpdateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure::Automatic => "automatic",
+            UpdateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure::Other => "other",
         }
     }
 }
@@ -3,9 +3,9 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::SkuId;
-use crate::params::{Deleted, Expand, Expandable, IdOrCreate, List, Metadata, Object, Timestamp};
-use crate::resources::{CreateProduct, Currency, PackageDimensions, Product};
+use crate::ids::{ProductId, SkuId};
+use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, Timestamp};
+use crate::resources::{Currency, PackageDimensions, Product};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "SKU".
@@ -203,7 +203,7 @@ pub struct CreateSku<'a> {
     /// The ID of the product this SKU is associated with.
     ///
     /// Must be a product with type `good`.
-    pub product: IdOrCreate<'a, CreateProduct<'a>>,
+    pub product: ProductId,
 }
 
 impl<'a> CreateSku<'a> {
@@ -211,7 +211,7 @@ impl<'a> CreateSku<'a> {
         currency: Currency,
         inventory: Option<Inventory>,
         price: i64,
-        product: IdOrCreate<'a, CreateProduct<'a>>,
+        product: ProductId,
     ) -> Self {
         CreateSku {
             active: Default::default(),
@@ -274,7 +274,7 @@ pub struct ListSkus<'a> {
     ///
     /// Must be a product with type `good`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product: Option<IdOrCreate<'a, CreateProduct<'a>>>,
+    pub product: Option<ProductId>,
 
     /// A cursor for use in pagination.
     ///
@@ -351,7 +351,7 @@ pub struct UpdateSku<'a> {
     ///
     /// The product must exist, have the same set of attribute names as the SKU's current product, and be of type `good`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product: Option<IdOrCreate<'a, CreateProduct<'a>>>,
+    pub product: Option<ProductId>,
 }
 
 impl<'a> UpdateSku<'a> {
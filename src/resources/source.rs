@@ -985,7 +985,7 @@ impl<'a> UpdateSource<'a> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSourceReceiver {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub refund_attributes_method: Option<SourceRefundNotificationMethod>,
@@ -996,7 +996,7 @@ pub struct CreateSourceRedirect {
     pub return_url: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSourceSourceOrder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<CreateSourceSourceOrderItems>>,
@@ -1023,7 +1023,7 @@ pub struct SourceMandateParams {
     pub notification_method: Option<SourceMandateNotificationMethod>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdateSourceSourceOrder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<UpdateSourceSourceOrderItems>>,
@@ -1032,7 +1032,7 @@ pub struct UpdateSourceSourceOrder {
     pub shipping: Option<UpdateSourceSourceOrderShipping>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSourceSourceOrderItems {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount: Option<i64>,
@@ -1095,7 +1095,7 @@ pub struct SourceAcceptanceParams {
     pub user_agent: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdateSourceSourceOrderItems {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount: Option<i64>,
@@ -1199,6 +1199,11 @@ pub enum CreateSourceSourceOrderItemsType {
     Shipping,
     Sku,
     Tax,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl CreateSourceSourceOrderItemsType {
@@ -1208,6 +1213,7 @@ impl CreateSourceSourceOrderItemsType {
             CreateSourceSourceOrderItemsType::Shipping => "shipping",
             CreateSourceSourceOrderItemsType::Sku => "sku",
             CreateSourceSourceOrderItemsType::Tax => "tax",
+            CreateSourceSourceOrderItemsType::Other => "other",
         }
     }
 }
@@ -1232,6 +1238,11 @@ pub enum SourceAcceptanceParamsStatus {
     Pending,
     Refused,
     Revoked,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceAcceptanceParamsStatus {
@@ -1241,6 +1252,7 @@ impl SourceAcceptanceParamsStatus {
             SourceAcceptanceParamsStatus::Pending => "pending",
             SourceAcceptanceParamsStatus::Refused => "refused",
             SourceAcceptanceParamsStatus::Revoked => "revoked",
+            SourceAcceptanceParamsStatus::Other => "other",
         }
     }
 }
@@ -1263,6 +1275,11 @@ impl std::fmt::Display for SourceAcceptanceParamsStatus {
 pub enum SourceAcceptanceParamsType {
     Offline,
     Online,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceAcceptanceParamsType {
@@ -1270,6 +1287,7 @@ impl SourceAcceptanceParamsType {
         match self {
             SourceAcceptanceParamsType::Offline => "offline",
             SourceAcceptanceParamsType::Online => "online",
+            SourceAcceptanceParamsType::Other => "other",
         }
     }
 }
@@ -1294,6 +1312,11 @@ pub enum SourceFlow {
     None,
     Receiver,
     Redirect,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceFlow {
@@ -1303,6 +1326,7 @@ impl SourceFlow {
             SourceFlow::None => "none",
             SourceFlow::Receiver => "receiver",
             SourceFlow::Redirect => "redirect",
+            SourceFlow::Other => "other",
         }
     }
 }
@@ -1326,6 +1350,11 @@ pub enum SourceMandateInterval {
     OneTime,
     Scheduled,
     Variable,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceMandateInterval {
@@ -1334,6 +1363,7 @@ impl SourceMandateInterval {
             SourceMandateInterval::OneTime => "one_time",
             SourceMandateInterval::Scheduled => "scheduled",
             SourceMandateInterval::Variable => "variable",
+            SourceMandateInterval::Other => "other",
         }
     }
 }
@@ -1359,6 +1389,11 @@ pub enum SourceMandateNotificationMethod {
     Manual,
     None,
     StripeEmail,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceMandateNotificationMethod {
@@ -1369,6 +1404,7 @@ impl SourceMandateNotificationMethod {
             SourceMandateNotificationMethod::Manual => "manual",
             SourceMandateNotificationMethod::None => "none",
             SourceMandateNotificationMethod::StripeEmail => "stripe_email",
+            SourceMandateNotificationMethod::Other => "other",
         }
     }
 }
@@ -1392,6 +1428,11 @@ pub enum SourceRefundNotificationMethod {
     Email,
     Manual,
     None,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceRefundNotificationMethod {
@@ -1400,6 +1441,7 @@ impl SourceRefundNotificationMethod {
             SourceRefundNotificationMethod::Email => "email",
             SourceRefundNotificationMethod::Manual => "manual",
             SourceRefundNotificationMethod::None => "none",
+            SourceRefundNotificationMethod::Other => "other",
         }
     }
 }
@@ -1437,6 +1479,11 @@ pub enum SourceType {
     Sofort,
     ThreeDSecure,
     Wechat,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceType {
@@ -1459,6 +1506,7 @@ impl SourceType {
             SourceType::Sofort => "sofort",
             SourceType::ThreeDSecure => "three_d_secure",
             SourceType::Wechat => "wechat",
+            SourceType::Other => "other",
         }
     }
 }
@@ -1483,6 +1531,11 @@ pub enum UpdateSourceSourceOrderItemsType {
     Shipping,
     Sku,
     Tax,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl UpdateSourceSourceOrderItemsType {
@@ -1492,6 +1545,7 @@ impl UpdateSourceSourceOrderItemsType {
             UpdateSourceSourceOrderItemsType::Shipping => "shipping",
             UpdateSourceSourceOrderItemsType::Sku => "sku",
             UpdateSourceSourceOrderItemsType::Tax => "tax",
+            UpdateSourceSourceOrderItemsType::Other => "other",
         }
     }
 }
@@ -1,5 +1,61 @@
+use crate::config::{Client, Response};
+use crate::ids::{SourceId, SourceTransactionId};
+use crate::params::{Expand, List};
+use crate::resources::{Source, SourceTransaction};
 use serde_derive::{Deserialize, Serialize};
 
+impl Source {
+    /// List source transactions for a given source.
+    ///
+    /// For more details see https://stripe.com/docs/sources/ach-credit-transfer#source-transactions.
+    pub fn list_source_transactions(
+        client: &Client,
+        id: &SourceId,
+        params: ListSourceTransactions<'_>,
+    ) -> Response<List<SourceTransaction>> {
+        client.get_query(&format!("/sources/{}/source_transactions", id), &params)
+    }
+}
+
+/// The parameters for `Source::list_source_transactions`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListSourceTransactions<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<SourceTransactionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<SourceTransactionId>,
+}
+
+impl<'a> ListSourceTransactions<'a> {
+    pub fn new() -> Self {
+        ListSourceTransactions {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
 /// An enum representing the possible values of an `Source`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -9,6 +65,11 @@ pub enum SourceStatus {
     Consumed,
     Failed,
     Pending,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceStatus {
@@ -19,6 +80,7 @@ impl SourceStatus {
             SourceStatus::Consumed => "consumed",
             SourceStatus::Failed => "failed",
             SourceStatus::Pending => "pending",
+            SourceStatus::Other => "other",
         }
     }
 }
@@ -41,6 +103,11 @@ impl std::fmt::Display for SourceStatus {
 pub enum SourceUsage {
     Reusable,
     SingleUse,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceUsage {
@@ -48,6 +115,7 @@ impl SourceUsage {
         match self {
             SourceUsage::Reusable => "reusable",
             SourceUsage::SingleUse => "single_use",
+            SourceUsage::Other => "other",
         }
     }
 }
@@ -71,6 +139,11 @@ pub enum SourceRedirectFlowFailureReason {
     Declined,
     ProcessingError,
     UserAbort,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceRedirectFlowFailureReason {
@@ -79,6 +152,7 @@ impl SourceRedirectFlowFailureReason {
             SourceRedirectFlowFailureReason::Declined => "declined",
             SourceRedirectFlowFailureReason::ProcessingError => "processing_error",
             SourceRedirectFlowFailureReason::UserAbort => "user_abort",
+            SourceRedirectFlowFailureReason::Other => "other",
         }
     }
 }
@@ -103,6 +177,11 @@ pub enum SourceRedirectFlowStatus {
     NotRequired,
     Pending,
     Succeeded,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SourceRedirectFlowStatus {
@@ -112,6 +191,7 @@ impl SourceRedirectFlowStatus {
             SourceRedirectFlowStatus::NotRequired => "not_required",
             SourceRedirectFlowStatus::Pending => "pending",
             SourceRedirectFlowStatus::Succeeded => "succeeded",
+            SourceRedirectFlowStatus::Other => "other",
         }
     }
 }
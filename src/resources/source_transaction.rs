@@ -0,0 +1,170 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::ids::{SourceId, SourceTransactionId};
+use crate::params::{Object, Timestamp};
+use crate::resources::{Currency, SourceType};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "SourceTransaction".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransaction {
+    /// Unique identifier for the object.
+    pub id: SourceTransactionId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach_credit_transfer: Option<SourceTransactionAchCreditTransferData>,
+
+    /// A positive integer in the smallest currency unit (that is, 100 cents for $1.00, or 1 for ¥1, Japanese Yen being a zero-decimal currency) representing the amount your customer has pushed to the receiver.
+    pub amount: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chf_credit_transfer: Option<SourceTransactionChfCreditTransferData>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gbp_credit_transfer: Option<SourceTransactionGbpCreditTransferData>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paper_check: Option<SourceTransactionPaperCheckData>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_credit_transfer: Option<SourceTransactionSepaCreditTransferData>,
+
+    /// The ID of the source this transaction is attached to.
+    pub source: SourceId,
+
+    /// The status of the transaction, one of `succeeded`, `pending`, or `failed`.
+    pub status: String,
+
+    /// The type of source this transaction is attached to.
+    #[serde(rename = "type")]
+    pub type_: SourceType,
+}
+
+impl Object for SourceTransaction {
+    type Id = SourceTransactionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "source_transaction"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionAchCreditTransferData {
+    /// Customer data associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_data: Option<String>,
+
+    /// Bank account fingerprint associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+
+    /// Last 4 digits of the account number associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<String>,
+
+    /// Routing number associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionChfCreditTransferData {
+    /// Reference associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Sender's country address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_address_country: Option<String>,
+
+    /// Sender's line 1 address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_address_line1: Option<String>,
+
+    /// Sender's bank account IBAN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_iban: Option<String>,
+
+    /// Sender's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionGbpCreditTransferData {
+    /// Bank account fingerprint associated with the Stripe owned bank account receiving the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+
+    /// The credit transfer rails the sender used to push this transfer.
+    ///
+    /// The possible rails are: Faster Payments, BACS, CHAPS, and wire transfers.
+    /// Currently only Faster Payments is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_method: Option<String>,
+
+    /// Last 4 digits of sender account number associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<String>,
+
+    /// Sender entered arbitrary information about the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Sender account number associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_account_number: Option<String>,
+
+    /// Sender name associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+
+    /// Sender sort code associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_sort_code: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionPaperCheckData {
+    /// Time at which the deposited funds will be available for use.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_at: Option<String>,
+
+    /// Comma-separated list of invoice IDs associated with the paper check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoices: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionSepaCreditTransferData {
+    /// Reference associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Sender's bank account IBAN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_iban: Option<String>,
+
+    /// Sender's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+}
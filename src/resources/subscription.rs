@@ -3,7 +3,9 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::{CouponId, CustomerId, PlanId, PriceId, SubscriptionId};
+use crate::ids::{
+    CouponId, CustomerId, PaymentMethodId, PaymentSourceId, PlanId, PriceId, SubscriptionId,
+};
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
     CollectionMethod, Currency, Customer, Discount, Invoice, PaymentMethod, PaymentSource, Plan,
@@ -46,6 +48,10 @@ pub struct Subscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub canceled_at: Option<Timestamp>,
 
+    /// Details about why this subscription was cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_details: Option<CancellationDetails>,
+
     /// Either `charge_automatically`, or `send_invoice`.
     ///
     /// When charging automatically, Stripe will attempt to pay this subscription at the end of the cycle using the default source attached to the customer.
@@ -128,6 +134,10 @@ pub struct Subscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pause_collection: Option<SubscriptionsResourcePauseCollection>,
 
+    /// Payment settings passed on to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<SubscriptionsResourcePaymentSettings>,
+
     /// Specifies an interval for how often to bill for any pending invoice items.
     ///
     /// It is analogous to calling [Create an invoice](https://stripe.com/docs/api#create_invoice) for the given subscription at the specified interval.
@@ -188,6 +198,10 @@ pub struct Subscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_end: Option<Timestamp>,
 
+    /// Settings related to subscription trials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_settings: Option<SubscriptionsTrialsResourceTrialSettings>,
+
     /// If the subscription has a trial, the beginning of that trial.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_start: Option<Timestamp>,
@@ -252,6 +266,21 @@ impl Object for Subscription {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CancellationDetails {
+    /// Additional comments about why the user canceled the subscription, if the subscription was canceled explicitly by the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    /// The customer submitted reason for why they canceled, if the subscription was canceled explicitly by the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<CancellationDetailsFeedback>,
+
+    /// Why this subscription was canceled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<CancellationDetailsReason>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SubscriptionPendingInvoiceItemInterval {
     /// Specifies invoicing frequency.
@@ -278,6 +307,31 @@ pub struct SubscriptionsResourcePauseCollection {
     pub resumes_at: Option<Timestamp>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubscriptionsTrialsResourceTrialSettings {
+    /// Defines how a subscription behaves when a free trial ends.
+    pub end_behavior: SubscriptionsTrialsResourceEndBehavior,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubscriptionsTrialsResourceEndBehavior {
+    /// Indicates how the subscription should change when the trial ends if the user did not provide a payment method.
+    pub missing_payment_method: SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubscriptionsResourcePaymentSettings {
+    /// The list of payment method types to provide to every invoice created by the subscription.
+    ///
+    /// If not set, Stripe attempts to automatically determine the types to use by looking at the invoice's default payment method, the subscription's default payment method, the customer's default payment method, and your [invoice template settings](https://dashboard.stripe.com/settings/billing/invoice).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<SubscriptionPaymentMethodType>>,
+
+    /// Whether the subscription's `default_payment_method` is set to the payment method that paid its latest invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_default_payment_method: Option<SubscriptionSaveDefaultPaymentMethod>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SubscriptionsResourcePendingUpdate {
     /// If the update is applied, determines the date of the first full invoice, and, for plans with `month` or `year` intervals, the day of the month for subsequent invoices.
@@ -378,14 +432,14 @@ pub struct CreateSubscription<'a> {
     /// It must belong to the customer associated with the subscription.
     /// If not set, invoices will use the default payment method in the customer's invoice settings.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_payment_method: Option<&'a str>,
+    pub default_payment_method: Option<PaymentMethodId>,
 
     /// ID of the default payment source for the subscription.
     ///
     /// It must belong to the customer associated with the subscription and be in a chargeable state.
     /// If not set, defaults to the customer's default source.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_source: Option<&'a str>,
+    pub default_source: Option<PaymentSourceId>,
 
     /// The tax rates that will apply to any subscription item that does not have `tax_rates` set.
     ///
@@ -425,6 +479,10 @@ pub struct CreateSubscription<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_behavior: Option<SubscriptionPaymentBehavior>,
 
+    /// Payment settings passed on to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<CreateSubscriptionPaymentSettings>,
+
     /// Specifies an interval for how often to bill for any pending invoice items.
     ///
     /// It is analogous to calling [Create an invoice](https://stripe.com/docs/api#create_invoice) for the given subscription at the specified interval.
@@ -475,6 +533,10 @@ pub struct CreateSubscription<'a> {
     /// This will always overwrite any trials that might apply via a subscribed plan.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u32>,
+
+    /// Settings related to subscription trials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_settings: Option<CreateSubscriptionTrialSettings>,
 }
 
 impl<'a> CreateSubscription<'a> {
@@ -499,6 +561,7 @@ impl<'a> CreateSubscription<'a> {
             metadata: Default::default(),
             off_session: Default::default(),
             payment_behavior: Default::default(),
+            payment_settings: Default::default(),
             pending_invoice_item_interval: Default::default(),
             prorate: Default::default(),
             proration_behavior: Default::default(),
@@ -506,10 +569,43 @@ impl<'a> CreateSubscription<'a> {
             trial_end: Default::default(),
             trial_from_plan: Default::default(),
             trial_period_days: Default::default(),
+            trial_settings: Default::default(),
         }
     }
 }
 
+crate::params::impl_setters! {
+    CreateSubscription<'a> {
+        add_invoice_items: Vec<AddInvoiceItems>,
+        application_fee_percent: f64,
+        backdate_start_date: Timestamp,
+        billing_cycle_anchor: Timestamp,
+        billing_thresholds: SubscriptionBillingThresholds,
+        cancel_at: Timestamp,
+        cancel_at_period_end: bool,
+        collection_method: CollectionMethod,
+        coupon: CouponId,
+        days_until_due: u32,
+        default_payment_method: PaymentMethodId,
+        default_source: PaymentSourceId,
+        default_tax_rates: Vec<String>,
+        expand = &'a [&'a str],
+        items: Vec<CreateSubscriptionItems>,
+        metadata: Metadata,
+        off_session: bool,
+        payment_behavior: SubscriptionPaymentBehavior,
+        payment_settings: CreateSubscriptionPaymentSettings,
+        pending_invoice_item_interval: CreateSubscriptionPendingInvoiceItemInterval,
+        prorate: bool,
+        proration_behavior: SubscriptionProrationBehavior,
+        tax_percent: f64,
+        trial_end: Scheduled,
+        trial_from_plan: bool,
+        trial_period_days: u32,
+        trial_settings: CreateSubscriptionTrialSettings,
+    }
+}
+
 /// The parameters for `Subscription::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListSubscriptions<'a> {
@@ -658,14 +754,14 @@ pub struct UpdateSubscription<'a> {
     /// It must belong to the customer associated with the subscription.
     /// If not set, invoices will use the default payment method in the customer's invoice settings.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_payment_method: Option<&'a str>,
+    pub default_payment_method: Option<PaymentMethodId>,
 
     /// ID of the default payment source for the subscription.
     ///
     /// It must belong to the customer associated with the subscription and be in a chargeable state.
     /// If not set, defaults to the customer's default source.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_source: Option<&'a str>,
+    pub default_source: Option<PaymentSourceId>,
 
     /// The tax rates that will apply to any subscription item that does not have `tax_rates` set.
     ///
@@ -711,6 +807,10 @@ pub struct UpdateSubscription<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_behavior: Option<SubscriptionPaymentBehavior>,
 
+    /// Payment settings passed on to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<UpdateSubscriptionPaymentSettings>,
+
     /// Specifies an interval for how often to bill for any pending invoice items.
     ///
     /// It is analogous to calling [Create an invoice](https://stripe.com/docs/api#create_invoice) for the given subscription at the specified interval.
@@ -762,6 +862,10 @@ pub struct UpdateSubscription<'a> {
     /// Setting this flag to `true` together with `trial_end` is not allowed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_from_plan: Option<bool>,
+
+    /// Settings related to subscription trials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_settings: Option<UpdateSubscriptionTrialSettings>,
 }
 
 impl<'a> UpdateSubscription<'a> {
@@ -785,6 +889,7 @@ impl<'a> UpdateSubscription<'a> {
             off_session: Default::default(),
             pause_collection: Default::default(),
             payment_behavior: Default::default(),
+            payment_settings: Default::default(),
             pending_invoice_item_interval: Default::default(),
             prorate: Default::default(),
             proration_behavior: Default::default(),
@@ -792,6 +897,7 @@ impl<'a> UpdateSubscription<'a> {
             tax_percent: Default::default(),
             trial_end: Default::default(),
             trial_from_plan: Default::default(),
+            trial_settings: Default::default(),
         }
     }
 }
@@ -808,7 +914,7 @@ pub struct AddInvoiceItems {
     pub quantity: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSubscriptionItems {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub billing_thresholds: Option<CreateSubscriptionItemsBillingThresholds>,
@@ -840,7 +946,7 @@ pub struct CreateSubscriptionPendingInvoiceItemInterval {
     pub interval_count: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdateSubscriptionItems {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub billing_thresholds: Option<SubscriptionItemBillingThresholds>,
@@ -889,6 +995,34 @@ pub struct UpdateSubscriptionPendingInvoiceItemInterval {
     pub interval_count: Option<u64>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateSubscriptionPaymentSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<SubscriptionPaymentMethodType>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_default_payment_method: Option<SubscriptionSaveDefaultPaymentMethod>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateSubscriptionPaymentSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<SubscriptionPaymentMethodType>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_default_payment_method: Option<SubscriptionSaveDefaultPaymentMethod>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateSubscriptionTrialSettings {
+    pub end_behavior: CreateSubscriptionTrialSettingsEndBehavior,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UpdateSubscriptionTrialSettings {
+    pub end_behavior: UpdateSubscriptionTrialSettingsEndBehavior,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateSubscriptionItemsBillingThresholds {
     pub usage_gte: i64,
@@ -922,6 +1056,16 @@ pub struct SubscriptionItemPriceData {
     pub unit_amount_decimal: Option<String>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateSubscriptionTrialSettingsEndBehavior {
+    pub missing_payment_method: CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UpdateSubscriptionTrialSettingsEndBehavior {
+    pub missing_payment_method: UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SubscriptionItemPriceDataRecurring {
     pub interval: PlanInterval,
@@ -930,6 +1074,126 @@ pub struct SubscriptionItemPriceDataRecurring {
     pub interval_count: Option<u64>,
 }
 
+/// An enum representing the possible values of an `CancellationDetails`'s `feedback` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CancellationDetailsFeedback {
+    CustomerService,
+    LowQuality,
+    MissingFeatures,
+    SwitchedService,
+    TooComplex,
+    TooExpensive,
+    Unused,
+
+    /// Other feedback, or a value not yet supported by the library.
+    #[serde(other)]
+    Other,
+}
+
+impl CancellationDetailsFeedback {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CancellationDetailsFeedback::CustomerService => "customer_service",
+            CancellationDetailsFeedback::LowQuality => "low_quality",
+            CancellationDetailsFeedback::MissingFeatures => "missing_features",
+            CancellationDetailsFeedback::SwitchedService => "switched_service",
+            CancellationDetailsFeedback::TooComplex => "too_complex",
+            CancellationDetailsFeedback::TooExpensive => "too_expensive",
+            CancellationDetailsFeedback::Unused => "unused",
+            CancellationDetailsFeedback::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CancellationDetailsFeedback {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CancellationDetailsFeedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CancellationDetails`'s `reason` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CancellationDetailsReason {
+    CancellationRequested,
+    PaymentDisputed,
+    PaymentFailed,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CancellationDetailsReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CancellationDetailsReason::CancellationRequested => "cancellation_requested",
+            CancellationDetailsReason::PaymentDisputed => "payment_disputed",
+            CancellationDetailsReason::PaymentFailed => "payment_failed",
+            CancellationDetailsReason::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CancellationDetailsReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CancellationDetailsReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CreateSubscriptionTrialSettingsEndBehavior`'s `missing_payment_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    Cancel,
+    CreateInvoice,
+    Pause,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Cancel => "cancel",
+            CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::CreateInvoice => {
+                "create_invoice"
+            }
+            CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Pause => "pause",
+            CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `SubscriptionPendingInvoiceItemInterval`'s `interval` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -938,6 +1202,11 @@ pub enum PlanInterval {
     Month,
     Week,
     Year,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PlanInterval {
@@ -947,6 +1216,7 @@ impl PlanInterval {
             PlanInterval::Month => "month",
             PlanInterval::Week => "week",
             PlanInterval::Year => "year",
+            PlanInterval::Other => "other",
         }
     }
 }
@@ -969,6 +1239,11 @@ impl std::fmt::Display for PlanInterval {
 pub enum SubscriptionBillingCycleAnchor {
     Now,
     Unchanged,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionBillingCycleAnchor {
@@ -976,6 +1251,7 @@ impl SubscriptionBillingCycleAnchor {
         match self {
             SubscriptionBillingCycleAnchor::Now => "now",
             SubscriptionBillingCycleAnchor::Unchanged => "unchanged",
+            SubscriptionBillingCycleAnchor::Other => "other",
         }
     }
 }
@@ -999,6 +1275,11 @@ pub enum SubscriptionPaymentBehavior {
     AllowIncomplete,
     ErrorIfIncomplete,
     PendingIfIncomplete,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionPaymentBehavior {
@@ -1007,6 +1288,7 @@ impl SubscriptionPaymentBehavior {
             SubscriptionPaymentBehavior::AllowIncomplete => "allow_incomplete",
             SubscriptionPaymentBehavior::ErrorIfIncomplete => "error_if_incomplete",
             SubscriptionPaymentBehavior::PendingIfIncomplete => "pending_if_incomplete",
+            SubscriptionPaymentBehavior::Other => "other",
         }
     }
 }
@@ -1030,6 +1312,11 @@ pub enum SubscriptionProrationBehavior {
     AlwaysInvoice,
     CreateProrations,
     None,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionProrationBehavior {
@@ -1038,6 +1325,7 @@ impl SubscriptionProrationBehavior {
             SubscriptionProrationBehavior::AlwaysInvoice => "always_invoice",
             SubscriptionProrationBehavior::CreateProrations => "create_prorations",
             SubscriptionProrationBehavior::None => "none",
+            SubscriptionProrationBehavior::Other => "other",
         }
     }
 }
@@ -1054,6 +1342,128 @@ impl std::fmt::Display for SubscriptionProrationBehavior {
     }
 }
 
+/// An enum representing the possible values of an `SubscriptionsResourcePaymentSettings`'s `payment_method_types` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionPaymentMethodType {
+    AchCreditTransfer,
+    AchDebit,
+    AcssDebit,
+    AuBecsDebit,
+    BacsDebit,
+    Bancontact,
+    Boleto,
+    Card,
+    Cashapp,
+    CustomerBalance,
+    Eps,
+    Fpx,
+    Giropay,
+    Grabpay,
+    Ideal,
+    Konbini,
+    Link,
+    Multibanco,
+    P24,
+    Paynow,
+    Paypal,
+    Promptpay,
+    RevolutPay,
+    SepaDebit,
+    Sofort,
+    Swish,
+    UsBankAccount,
+    WechatPay,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl SubscriptionPaymentMethodType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubscriptionPaymentMethodType::AchCreditTransfer => "ach_credit_transfer",
+            SubscriptionPaymentMethodType::AchDebit => "ach_debit",
+            SubscriptionPaymentMethodType::AcssDebit => "acss_debit",
+            SubscriptionPaymentMethodType::AuBecsDebit => "au_becs_debit",
+            SubscriptionPaymentMethodType::BacsDebit => "bacs_debit",
+            SubscriptionPaymentMethodType::Bancontact => "bancontact",
+            SubscriptionPaymentMethodType::Boleto => "boleto",
+            SubscriptionPaymentMethodType::Card => "card",
+            SubscriptionPaymentMethodType::Cashapp => "cashapp",
+            SubscriptionPaymentMethodType::CustomerBalance => "customer_balance",
+            SubscriptionPaymentMethodType::Eps => "eps",
+            SubscriptionPaymentMethodType::Fpx => "fpx",
+            SubscriptionPaymentMethodType::Giropay => "giropay",
+            SubscriptionPaymentMethodType::Grabpay => "grabpay",
+            SubscriptionPaymentMethodType::Ideal => "ideal",
+            SubscriptionPaymentMethodType::Konbini => "konbini",
+            SubscriptionPaymentMethodType::Link => "link",
+            SubscriptionPaymentMethodType::Multibanco => "multibanco",
+            SubscriptionPaymentMethodType::P24 => "p24",
+            SubscriptionPaymentMethodType::Paynow => "paynow",
+            SubscriptionPaymentMethodType::Paypal => "paypal",
+            SubscriptionPaymentMethodType::Promptpay => "promptpay",
+            SubscriptionPaymentMethodType::RevolutPay => "revolut_pay",
+            SubscriptionPaymentMethodType::SepaDebit => "sepa_debit",
+            SubscriptionPaymentMethodType::Sofort => "sofort",
+            SubscriptionPaymentMethodType::Swish => "swish",
+            SubscriptionPaymentMethodType::UsBankAccount => "us_bank_account",
+            SubscriptionPaymentMethodType::WechatPay => "wechat_pay",
+            SubscriptionPaymentMethodType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionPaymentMethodType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionPaymentMethodType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `SubscriptionsResourcePaymentSettings`'s `save_default_payment_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionSaveDefaultPaymentMethod {
+    Off,
+    OnSubscription,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl SubscriptionSaveDefaultPaymentMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubscriptionSaveDefaultPaymentMethod::Off => "off",
+            SubscriptionSaveDefaultPaymentMethod::OnSubscription => "on_subscription",
+            SubscriptionSaveDefaultPaymentMethod::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionSaveDefaultPaymentMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionSaveDefaultPaymentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `Subscription`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1065,6 +1475,11 @@ pub enum SubscriptionStatus {
     PastDue,
     Trialing,
     Unpaid,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionStatus {
@@ -1077,6 +1492,7 @@ impl SubscriptionStatus {
             SubscriptionStatus::PastDue => "past_due",
             SubscriptionStatus::Trialing => "trialing",
             SubscriptionStatus::Unpaid => "unpaid",
+            SubscriptionStatus::Other => "other",
         }
     }
 }
@@ -1106,6 +1522,11 @@ pub enum SubscriptionStatusFilter {
     PastDue,
     Trialing,
     Unpaid,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionStatusFilter {
@@ -1120,6 +1541,7 @@ impl SubscriptionStatusFilter {
             SubscriptionStatusFilter::PastDue => "past_due",
             SubscriptionStatusFilter::Trialing => "trialing",
             SubscriptionStatusFilter::Unpaid => "unpaid",
+            SubscriptionStatusFilter::Other => "other",
         }
     }
 }
@@ -1143,6 +1565,11 @@ pub enum SubscriptionsResourcePauseCollectionBehavior {
     KeepAsDraft,
     MarkUncollectible,
     Void,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionsResourcePauseCollectionBehavior {
@@ -1151,6 +1578,7 @@ impl SubscriptionsResourcePauseCollectionBehavior {
             SubscriptionsResourcePauseCollectionBehavior::KeepAsDraft => "keep_as_draft",
             SubscriptionsResourcePauseCollectionBehavior::MarkUncollectible => "mark_uncollectible",
             SubscriptionsResourcePauseCollectionBehavior::Void => "void",
+            SubscriptionsResourcePauseCollectionBehavior::Other => "other",
         }
     }
 }
@@ -1167,6 +1595,45 @@ impl std::fmt::Display for SubscriptionsResourcePauseCollectionBehavior {
     }
 }
 
+/// An enum representing the possible values of an `SubscriptionsTrialsResourceEndBehavior`'s `missing_payment_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod {
+    Cancel,
+    CreateInvoice,
+    Pause,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod::Cancel => "cancel",
+            SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod::CreateInvoice => {
+                "create_invoice"
+            }
+            SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod::Pause => "pause",
+            SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionsTrialsResourceEndBehaviorMissingPaymentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `UpdateSubscriptionPauseCollection`'s `behavior` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1174,6 +1641,11 @@ pub enum UpdateSubscriptionPauseCollectionBehavior {
     KeepAsDraft,
     MarkUncollectible,
     Void,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl UpdateSubscriptionPauseCollectionBehavior {
@@ -1182,6 +1654,7 @@ impl UpdateSubscriptionPauseCollectionBehavior {
             UpdateSubscriptionPauseCollectionBehavior::KeepAsDraft => "keep_as_draft",
             UpdateSubscriptionPauseCollectionBehavior::MarkUncollectible => "mark_uncollectible",
             UpdateSubscriptionPauseCollectionBehavior::Void => "void",
+            UpdateSubscriptionPauseCollectionBehavior::Other => "other",
         }
     }
 }
@@ -1197,3 +1670,42 @@ impl std::fmt::Display for UpdateSubscriptionPauseCollectionBehavior {
         self.as_str().fmt(f)
     }
 }
+
+/// An enum representing the possible values of an `UpdateSubscriptionTrialSettingsEndBehavior`'s `missing_payment_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    Cancel,
+    CreateInvoice,
+    Pause,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Cancel => "cancel",
+            UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::CreateInvoice => {
+                "create_invoice"
+            }
+            UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Pause => "pause",
+            UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for UpdateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -1,20 +1,55 @@
 use crate::config::{Client, Response};
 use crate::ids::SubscriptionId;
-use crate::resources::{CreateSubscriptionItems, Subscription};
+use crate::resources::{
+    CancellationDetailsFeedback, CreateSubscriptionItems, Discount, Subscription,
+};
 use serde_derive::Serialize;
 
+/// The parameters for `Subscription::cancel`.
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct CancelSubscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub at_period_end: Option<bool>,
+
+    /// Details about why this subscription was cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_details: Option<CancelSubscriptionCancellationDetails>,
+
+    /// Will generate a final invoice that invoices for any un-invoiced metered usage and new/pending proration invoice items.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_now: Option<bool>,
+
+    /// Will generate a proration invoice item that credits remaining unused time until the subscription period end.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
 }
 
 impl CancelSubscription {
     pub fn new() -> CancelSubscription {
-        CancelSubscription { at_period_end: None }
+        CancelSubscription {
+            at_period_end: None,
+            cancellation_details: None,
+            invoice_now: None,
+            prorate: None,
+        }
     }
 }
 
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CancelSubscriptionCancellationDetails {
+    /// Additional comments about why the user canceled the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    /// The customer submitted reason for why they canceled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<CancellationDetailsFeedback>,
+}
+
 impl Subscription {
     /// Cancels a subscription.
     ///
@@ -26,6 +61,16 @@ impl Subscription {
     ) -> Response<Subscription> {
         client.delete_query(&format!("/subscriptions/{}", subscription_id), params)
     }
+
+    /// Removes the currently applied discount on a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api/discounts/subscription_delete.
+    pub fn delete_discount(
+        client: &Client,
+        subscription_id: &SubscriptionId,
+    ) -> Response<Discount> {
+        client.delete(&format!("/subscriptions/{}/discount", subscription_id))
+    }
 }
 
 impl CreateSubscriptionItems {
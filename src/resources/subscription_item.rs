@@ -390,6 +390,11 @@ pub enum PlanInterval {
     Month,
     Week,
     Year,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PlanInterval {
@@ -399,6 +404,7 @@ impl PlanInterval {
             PlanInterval::Month => "month",
             PlanInterval::Week => "week",
             PlanInterval::Year => "year",
+            PlanInterval::Other => "other",
         }
     }
 }
@@ -422,6 +428,11 @@ pub enum SubscriptionPaymentBehavior {
     AllowIncomplete,
     ErrorIfIncomplete,
     PendingIfIncomplete,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionPaymentBehavior {
@@ -430,6 +441,7 @@ impl SubscriptionPaymentBehavior {
             SubscriptionPaymentBehavior::AllowIncomplete => "allow_incomplete",
             SubscriptionPaymentBehavior::ErrorIfIncomplete => "error_if_incomplete",
             SubscriptionPaymentBehavior::PendingIfIncomplete => "pending_if_incomplete",
+            SubscriptionPaymentBehavior::Other => "other",
         }
     }
 }
@@ -453,6 +465,11 @@ pub enum SubscriptionProrationBehavior {
     AlwaysInvoice,
     CreateProrations,
     None,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionProrationBehavior {
@@ -461,6 +478,7 @@ impl SubscriptionProrationBehavior {
             SubscriptionProrationBehavior::AlwaysInvoice => "always_invoice",
             SubscriptionProrationBehavior::CreateProrations => "create_prorations",
             SubscriptionProrationBehavior::None => "none",
+            SubscriptionProrationBehavior::Other => "other",
         }
     }
 }
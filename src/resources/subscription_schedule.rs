@@ -3,7 +3,7 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::{CustomerId, SubscriptionScheduleId};
+use crate::ids::{CustomerId, PaymentMethodId, SubscriptionScheduleId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
     CollectionMethod, Coupon, Currency, Customer, PaymentMethod, Plan, Price, Scheduled,
@@ -458,7 +458,7 @@ impl<'a> UpdateSubscriptionSchedule<'a> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSubscriptionSchedulePhases {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub add_invoice_items: Option<Vec<AddInvoiceItems>>,
@@ -476,7 +476,7 @@ pub struct CreateSubscriptionSchedulePhases {
     pub coupon: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_payment_method: Option<String>,
+    pub default_payment_method: Option<PaymentMethodId>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_tax_rates: Option<Vec<String>>,
@@ -514,13 +514,13 @@ pub struct SubscriptionScheduleDefaultSettingsParams {
     pub collection_method: Option<CollectionMethod>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_payment_method: Option<String>,
+    pub default_payment_method: Option<PaymentMethodId>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice_settings: Option<SubscriptionScheduleInvoiceSettings>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdateSubscriptionSchedulePhases {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub add_invoice_items: Option<Vec<AddInvoiceItems>>,
@@ -538,7 +538,7 @@ pub struct UpdateSubscriptionSchedulePhases {
     pub coupon: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_payment_method: Option<String>,
+    pub default_payment_method: Option<PaymentMethodId>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_tax_rates: Option<Vec<String>>,
@@ -662,6 +662,11 @@ pub enum PlanInterval {
     Month,
     Week,
     Year,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl PlanInterval {
@@ -671,6 +676,7 @@ impl PlanInterval {
             PlanInterval::Month => "month",
             PlanInterval::Week => "week",
             PlanInterval::Year => "year",
+            PlanInterval::Other => "other",
         }
     }
 }
@@ -694,6 +700,11 @@ pub enum SubscriptionProrationBehavior {
     AlwaysInvoice,
     CreateProrations,
     None,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionProrationBehavior {
@@ -702,6 +713,7 @@ impl SubscriptionProrationBehavior {
             SubscriptionProrationBehavior::AlwaysInvoice => "always_invoice",
             SubscriptionProrationBehavior::CreateProrations => "create_prorations",
             SubscriptionProrationBehavior::None => "none",
+            SubscriptionProrationBehavior::Other => "other",
         }
     }
 }
@@ -724,6 +736,11 @@ impl std::fmt::Display for SubscriptionProrationBehavior {
 pub enum SubscriptionScheduleDefaultSettingsCollectionMethod {
     ChargeAutomatically,
     SendInvoice,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionScheduleDefaultSettingsCollectionMethod {
@@ -733,6 +750,7 @@ impl SubscriptionScheduleDefaultSettingsCollectionMethod {
                 "charge_automatically"
             }
             SubscriptionScheduleDefaultSettingsCollectionMethod::SendInvoice => "send_invoice",
+            SubscriptionScheduleDefaultSettingsCollectionMethod::Other => "other",
         }
     }
 }
@@ -757,6 +775,11 @@ pub enum SubscriptionScheduleEndBehavior {
     None,
     Release,
     Renew,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionScheduleEndBehavior {
@@ -766,6 +789,7 @@ impl SubscriptionScheduleEndBehavior {
             SubscriptionScheduleEndBehavior::None => "none",
             SubscriptionScheduleEndBehavior::Release => "release",
             SubscriptionScheduleEndBehavior::Renew => "renew",
+            SubscriptionScheduleEndBehavior::Other => "other",
         }
     }
 }
@@ -791,6 +815,11 @@ pub enum SubscriptionScheduleStatus {
     Completed,
     NotStarted,
     Released,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl SubscriptionScheduleStatus {
@@ -801,6 +830,7 @@ impl SubscriptionScheduleStatus {
             SubscriptionScheduleStatus::Completed => "completed",
             SubscriptionScheduleStatus::NotStarted => "not_started",
             SubscriptionScheduleStatus::Released => "released",
+            SubscriptionScheduleStatus::Other => "other",
         }
     }
 }
@@ -104,6 +104,11 @@ pub enum TaxIdType {
     Unknown,
     UsEin,
     ZaVat,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TaxIdType {
@@ -135,6 +140,7 @@ impl TaxIdType {
             TaxIdType::Unknown => "unknown",
             TaxIdType::UsEin => "us_ein",
             TaxIdType::ZaVat => "za_vat",
+            TaxIdType::Other => "other",
         }
     }
 }
@@ -159,6 +165,11 @@ pub enum TaxIdVerificationStatus {
     Unavailable,
     Unverified,
     Verified,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TaxIdVerificationStatus {
@@ -168,6 +179,7 @@ impl TaxIdVerificationStatus {
             TaxIdVerificationStatus::Unavailable => "unavailable",
             TaxIdVerificationStatus::Unverified => "unverified",
             TaxIdVerificationStatus::Verified => "verified",
+            TaxIdVerificationStatus::Other => "other",
         }
     }
 }
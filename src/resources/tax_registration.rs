@@ -0,0 +1,412 @@
+use std::collections::HashMap;
+
+use crate::config::{Client, Response};
+use crate::ids::TaxRegistrationId;
+use crate::params::{Expand, List, Object, Timestamp};
+use crate::resources::Scheduled;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "TaxRegistration".
+///
+/// For more details see [https://stripe.com/docs/api/tax/registrations](https://stripe.com/docs/api/tax/registrations).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaxRegistration {
+    /// Unique identifier for the object.
+    pub id: TaxRegistrationId,
+
+    /// Time at which the registration becomes active.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub active_from: Timestamp,
+
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: String,
+
+    /// Specific options for a registration in the specified `country`.
+    ///
+    /// Keyed by the lowercase two-letter country code.
+    #[serde(default)]
+    pub country_options: HashMap<String, TaxRegistrationCountryOptions>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// If set, the registration stops being active at this time.
+    ///
+    /// If not set, the registration will be active indefinitely.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The status of the registration.
+    ///
+    /// This field is present for convenience and can be deduced from `active_from` and `expires_at`.
+    pub status: TaxRegistrationStatus,
+}
+
+impl TaxRegistration {
+    /// Returns a list of Tax `Registration` objects.
+    pub fn list(
+        client: &Client,
+        params: ListTaxRegistrations<'_>,
+    ) -> Response<List<TaxRegistration>> {
+        client.get_query("/tax/registrations", &params)
+    }
+
+    /// Creates a new Tax `Registration` object.
+    pub fn create(client: &Client, params: CreateTaxRegistration<'_>) -> Response<TaxRegistration> {
+        client.post_form("/tax/registrations", &params)
+    }
+
+    /// Returns a Tax `Registration` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &TaxRegistrationId,
+        expand: &[&str],
+    ) -> Response<TaxRegistration> {
+        client.get_query(&format!("/tax/registrations/{}", id), &Expand { expand })
+    }
+
+    /// Updates an existing Tax `Registration` object.
+    ///
+    /// A registration cannot be deleted after it has been created.
+    /// If you wish to end a registration you may do so by setting `expires_at`.
+    pub fn update(
+        client: &Client,
+        id: &TaxRegistrationId,
+        params: UpdateTaxRegistration<'_>,
+    ) -> Response<TaxRegistration> {
+        client.post_form(&format!("/tax/registrations/{}", id), &params)
+    }
+}
+
+impl Object for TaxRegistration {
+    type Id = TaxRegistrationId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "tax.registration"
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxRegistrationCountryOptions {
+    /// Options for the local amusement tax registration (US only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_amusement_tax: Option<TaxRegistrationJurisdiction>,
+
+    /// Options for the local lease tax registration (US only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_lease_tax: Option<TaxRegistrationJurisdiction>,
+
+    /// Options for the standard registration scheme (EU countries only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standard: Option<TaxRegistrationStandard>,
+
+    /// Two-letter US state code ([ISO 3166-2](https://en.wikipedia.org/wiki/ISO_3166-2)).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// Type of registration to be created in the country.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<TaxRegistrationType>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxRegistrationJurisdiction {
+    /// A [FIPS code](https://www.census.gov/library/reference/code-lists/ansi.html) representing the local jurisdiction.
+    pub jurisdiction: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxRegistrationStandard {
+    /// Place of supply scheme used in an EU standard registration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_of_supply_scheme: Option<TaxRegistrationPlaceOfSupplyScheme>,
+}
+
+/// The parameters for `TaxRegistration::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxRegistration<'a> {
+    /// Time at which the Tax Registration becomes active.
+    ///
+    /// It can be either `now` to indicate the current time, or a future timestamp measured in seconds since the Unix epoch.
+    pub active_from: Scheduled,
+
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: &'a str,
+
+    /// Specific options for a registration in the specified `country`.
+    ///
+    /// Keyed by the lowercase two-letter country code.
+    pub country_options: HashMap<String, TaxRegistrationCountryOptions>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// If set, the Tax Registration stops being active at this time.
+    ///
+    /// If not set, the Tax Registration will be active indefinitely.
+    /// Timestamp measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
+
+impl<'a> CreateTaxRegistration<'a> {
+    pub fn new(
+        active_from: Scheduled,
+        country: &'a str,
+        country_options: HashMap<String, TaxRegistrationCountryOptions>,
+    ) -> Self {
+        CreateTaxRegistration {
+            active_from,
+            country,
+            country_options,
+            expand: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TaxRegistration::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTaxRegistrations<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TaxRegistrationId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TaxRegistrationId>,
+
+    /// The status of the Tax Registration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ListTaxRegistrationsStatus>,
+}
+
+impl<'a> ListTaxRegistrations<'a> {
+    pub fn new() -> Self {
+        ListTaxRegistrations {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TaxRegistration::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTaxRegistration<'a> {
+    /// Time at which the registration becomes active.
+    ///
+    /// It can be either `now` to indicate the current time, or a timestamp measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_from: Option<Scheduled>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// If set, the registration stops being active at this time.
+    ///
+    /// It can be either `now` to indicate the current time, or a timestamp measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Scheduled>,
+}
+
+impl<'a> UpdateTaxRegistration<'a> {
+    pub fn new() -> Self {
+        UpdateTaxRegistration {
+            active_from: Default::default(),
+            expand: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+/// An enum representing the possible values of an `ListTaxRegistrations`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListTaxRegistrationsStatus {
+    Active,
+    All,
+    Expired,
+    Scheduled,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl ListTaxRegistrationsStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ListTaxRegistrationsStatus::Active => "active",
+            ListTaxRegistrationsStatus::All => "all",
+            ListTaxRegistrationsStatus::Expired => "expired",
+            ListTaxRegistrationsStatus::Scheduled => "scheduled",
+            ListTaxRegistrationsStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for ListTaxRegistrationsStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ListTaxRegistrationsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `TaxRegistrationStandard`'s `place_of_supply_scheme` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxRegistrationPlaceOfSupplyScheme {
+    SmallSeller,
+    Standard,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl TaxRegistrationPlaceOfSupplyScheme {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxRegistrationPlaceOfSupplyScheme::SmallSeller => "small_seller",
+            TaxRegistrationPlaceOfSupplyScheme::Standard => "standard",
+            TaxRegistrationPlaceOfSupplyScheme::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for TaxRegistrationPlaceOfSupplyScheme {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxRegistrationPlaceOfSupplyScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `TaxRegistration`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxRegistrationStatus {
+    Active,
+    Expired,
+    Scheduled,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl TaxRegistrationStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxRegistrationStatus::Active => "active",
+            TaxRegistrationStatus::Expired => "expired",
+            TaxRegistrationStatus::Scheduled => "scheduled",
+            TaxRegistrationStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for TaxRegistrationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxRegistrationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `TaxRegistrationCountryOptions`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxRegistrationType {
+    Ioss,
+    LocalAmusementTax,
+    LocalLeaseTax,
+    OssNonUnion,
+    OssUnion,
+    ProvinceStandard,
+    Simplified,
+    Standard,
+    StateCommunicationsTax,
+    StateSalesTax,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl TaxRegistrationType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxRegistrationType::Ioss => "ioss",
+            TaxRegistrationType::LocalAmusementTax => "local_amusement_tax",
+            TaxRegistrationType::LocalLeaseTax => "local_lease_tax",
+            TaxRegistrationType::OssNonUnion => "oss_non_union",
+            TaxRegistrationType::OssUnion => "oss_union",
+            TaxRegistrationType::ProvinceStandard => "province_standard",
+            TaxRegistrationType::Simplified => "simplified",
+            TaxRegistrationType::Standard => "standard",
+            TaxRegistrationType::StateCommunicationsTax => "state_communications_tax",
+            TaxRegistrationType::StateSalesTax => "state_sales_tax",
+            TaxRegistrationType::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for TaxRegistrationType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxRegistrationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
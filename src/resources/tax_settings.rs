@@ -0,0 +1,201 @@
+use crate::config::{Client, Response};
+use crate::params::{Expand, Object};
+use crate::resources::Address;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "TaxSettings".
+///
+/// For more details see [https://stripe.com/docs/api/tax/settings](https://stripe.com/docs/api/tax/settings).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaxSettings {
+    pub defaults: TaxSettingsDefaults,
+
+    /// The place where your business is located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_office: Option<TaxSettingsHeadOffice>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The `active` status indicates you have all required settings to calculate tax.
+    ///
+    /// A status can transition out of `active` when new required settings are introduced.
+    pub status: TaxSettingsStatus,
+
+    pub status_details: TaxSettingsStatusDetails,
+}
+
+impl TaxSettings {
+    /// Retrieves Tax `Settings` for a merchant.
+    pub fn retrieve(client: &Client, expand: &[&str]) -> Response<TaxSettings> {
+        client.get_query("/tax/settings", &Expand { expand })
+    }
+
+    /// Updates Tax `Settings` parameters used in tax calculations.
+    ///
+    /// All parameters are editable but none can be removed once set.
+    pub fn update(client: &Client, params: UpdateTaxSettings<'_>) -> Response<TaxSettings> {
+        client.post_form("/tax/settings", &params)
+    }
+}
+
+impl Object for TaxSettings {
+    type Id = ();
+    fn id(&self) -> Self::Id {}
+    fn object(&self) -> &'static str {
+        "tax.settings"
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxSettingsDefaults {
+    /// Default [tax behavior](https://stripe.com/docs/tax/products-prices-tax-categories-tax-behavior#tax-behavior) used to specify whether the price is considered inclusive of taxes or exclusive of taxes.
+    ///
+    /// If the item's price has a tax behavior set, it will take precedence over the default tax behavior.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxSettingsTaxBehavior>,
+
+    /// Default [tax code](https://stripe.com/docs/tax/tax-categories) used to classify your products and prices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaxSettingsHeadOffice {
+    pub address: Address,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxSettingsStatusDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<TaxSettingsStatusDetailsActive>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending: Option<TaxSettingsStatusDetailsPending>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxSettingsStatusDetailsActive {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxSettingsStatusDetailsPending {
+    /// The list of missing fields that are required to perform calculations.
+    ///
+    /// It includes the entry `head_office` when the status is `pending`.
+    /// It is recommended to set the optional values even if they aren't listed as required for calculating taxes.
+    /// Calculations can fail if missing fields aren't explicitly provided on every call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_fields: Option<Vec<String>>,
+}
+
+/// The parameters for `TaxSettings::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTaxSettings<'a> {
+    /// Default configuration to be used on Stripe Tax calculations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<UpdateTaxSettingsDefaults<'a>>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The place where your business is located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_office: Option<TaxSettingsHeadOffice>,
+}
+
+impl<'a> UpdateTaxSettings<'a> {
+    pub fn new() -> Self {
+        UpdateTaxSettings {
+            defaults: Default::default(),
+            expand: Default::default(),
+            head_office: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateTaxSettingsDefaults<'a> {
+    /// Specifies the default [tax behavior](https://stripe.com/docs/tax/tax-behavior) to be used when the item's price has unspecified tax behavior.
+    ///
+    /// One of inclusive, exclusive, or inferred_by_currency.
+    /// Once specified, it cannot be changed back to null.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxSettingsTaxBehavior>,
+
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<&'a str>,
+}
+
+/// An enum representing the possible values of an `TaxSettings`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxSettingsStatus {
+    Active,
+    Pending,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl TaxSettingsStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxSettingsStatus::Active => "active",
+            TaxSettingsStatus::Pending => "pending",
+            TaxSettingsStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for TaxSettingsStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxSettingsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `TaxSettingsDefaults`'s `tax_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxSettingsTaxBehavior {
+    Exclusive,
+    Inclusive,
+    InferredByCurrency,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl TaxSettingsTaxBehavior {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxSettingsTaxBehavior::Exclusive => "exclusive",
+            TaxSettingsTaxBehavior::Inclusive => "inclusive",
+            TaxSettingsTaxBehavior::InferredByCurrency => "inferred_by_currency",
+            TaxSettingsTaxBehavior::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for TaxSettingsTaxBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxSettingsTaxBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -0,0 +1,110 @@
+//! Sandbox-only endpoints used to drive objects through states that would
+//! otherwise require customer or bank interaction.
+//!
+//! These requests are rejected by Stripe when made with a live mode key.
+
+use crate::config::{Client, Response};
+use crate::ids::{PaymentIntentId, PaymentMethodId, RefundId};
+use crate::params::Expand;
+use crate::resources::{
+    ConfirmationToken, ConfirmationTokenSetupFutureUsage, PaymentIntent,
+    PaymentIntentConfirmParams, Refund, ShippingParams,
+};
+use serde_derive::Serialize;
+
+#[cfg(feature = "connect")]
+use crate::resources::{Currency, Topup};
+
+impl PaymentIntent {
+    /// Confirms a test mode PaymentIntent with one of Stripe's [test payment methods](https://stripe.com/docs/testing#cards)
+    /// (e.g. `pm_card_visa` or `pm_card_chargeDeclined`).
+    ///
+    /// For more details see [https://stripe.com/docs/api/payment_intents/confirm](https://stripe.com/docs/api/payment_intents/confirm).
+    pub fn confirm_with_test_payment_method(
+        client: &Client,
+        id: &PaymentIntentId,
+        payment_method: PaymentMethodId,
+    ) -> Response<PaymentIntent> {
+        let mut params = PaymentIntentConfirmParams::default();
+        params.payment_method = Some(payment_method);
+        PaymentIntent::confirm(client, id, params)
+    }
+}
+
+impl Refund {
+    /// Expire a refund with a status of `requires_action`.
+    ///
+    /// For more details see [https://stripe.com/docs/api/refunds/test_mode_expire](https://stripe.com/docs/api/refunds/test_mode_expire).
+    pub fn expire(client: &Client, id: &RefundId) -> Response<Refund> {
+        client.post(&format!("/test_helpers/refunds/{}/expire", id))
+    }
+}
+
+impl ConfirmationToken {
+    /// Creates a test mode Confirmation Token server side for your integration tests.
+    ///
+    /// For more details see [https://stripe.com/docs/api/confirmation_tokens/test_create](https://stripe.com/docs/api/confirmation_tokens/test_create).
+    pub fn create(
+        client: &Client,
+        params: CreateConfirmationToken<'_>,
+    ) -> Response<ConfirmationToken> {
+        client.post_form("/test_helpers/confirmation_tokens", &params)
+    }
+}
+
+#[cfg(feature = "connect")]
+impl Topup {
+    /// Adds funds to the test mode Issuing balance by creating a top-up with `destination_balance=issuing`.
+    ///
+    /// For more details see [https://stripe.com/docs/issuing/funding/balance](https://stripe.com/docs/issuing/funding/balance).
+    pub fn fund_issuing_balance(
+        client: &Client,
+        amount: i64,
+        currency: Currency,
+    ) -> Response<Topup> {
+        #[derive(Serialize)]
+        struct FundIssuingBalance {
+            amount: i64,
+            currency: Currency,
+            destination_balance: &'static str,
+        }
+        let params = FundIssuingBalance { amount, currency, destination_balance: "issuing" };
+        client.post_form("/topups", params)
+    }
+}
+
+/// The parameters for `ConfirmationToken::create`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateConfirmationToken<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// ID of an existing PaymentMethod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PaymentMethodId>,
+
+    /// Return URL used to confirm the Intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+
+    /// Indicates that you intend to make future payments with this ConfirmationToken's payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<ConfirmationTokenSetupFutureUsage>,
+
+    /// Shipping information for this ConfirmationToken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingParams>,
+}
+
+impl<'a> CreateConfirmationToken<'a> {
+    pub fn new() -> Self {
+        CreateConfirmationToken {
+            expand: Default::default(),
+            payment_method: Default::default(),
+            return_url: Default::default(),
+            setup_future_usage: Default::default(),
+            shipping: Default::default(),
+        }
+    }
+}
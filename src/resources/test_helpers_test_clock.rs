@@ -0,0 +1,230 @@
+use crate::config::{Client, Response};
+use crate::ids::TestHelpersTestClockId;
+use crate::params::{Deleted, Expand, List, Object, Timestamp};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "TestClock".
+///
+/// A test clock enables deterministic control over objects in testmode by
+/// letting you simulate the passage of time.
+///
+/// For more details see [https://stripe.com/docs/api/test_clocks](https://stripe.com/docs/api/test_clocks).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TestHelpersTestClock {
+    /// Unique identifier for the object.
+    pub id: TestHelpersTestClockId,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Time at which this clock is scheduled to auto delete.
+    pub deletes_after: Timestamp,
+
+    /// Time at which all objects belonging to this clock are frozen.
+    pub frozen_time: Timestamp,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The custom name supplied at creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The status of the Test Clock.
+    pub status: TestHelpersTestClockStatus,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_details: Option<TestHelpersTestClockStatusDetails>,
+}
+
+impl TestHelpersTestClock {
+    /// Returns a list of your test clocks.
+    pub fn list(
+        client: &Client,
+        params: ListTestHelpersTestClocks<'_>,
+    ) -> Response<List<TestHelpersTestClock>> {
+        client.get_query("/test_helpers/test_clocks", &params)
+    }
+
+    /// Creates a new test clock that can be attached to new customers and quotes.
+    pub fn create(
+        client: &Client,
+        params: CreateTestHelpersTestClock<'_>,
+    ) -> Response<TestHelpersTestClock> {
+        client.post_form("/test_helpers/test_clocks", &params)
+    }
+
+    /// Retrieves a test clock.
+    pub fn retrieve(
+        client: &Client,
+        id: &TestHelpersTestClockId,
+        expand: &[&str],
+    ) -> Response<TestHelpersTestClock> {
+        client.get_query(&format!("/test_helpers/test_clocks/{}", id), &Expand { expand })
+    }
+
+    /// Deletes a test clock.
+    pub fn delete(
+        client: &Client,
+        id: &TestHelpersTestClockId,
+    ) -> Response<Deleted<TestHelpersTestClockId>> {
+        client.delete(&format!("/test_helpers/test_clocks/{}", id))
+    }
+
+    /// Starts advancing a test clock to a specified time in the future.
+    ///
+    /// Advancement is done when status changes to `ready`.
+    pub fn advance(
+        client: &Client,
+        id: &TestHelpersTestClockId,
+        params: AdvanceTestHelpersTestClock<'_>,
+    ) -> Response<TestHelpersTestClock> {
+        client.post_form(&format!("/test_helpers/test_clocks/{}/advance", id), &params)
+    }
+}
+
+impl Object for TestHelpersTestClock {
+    type Id = TestHelpersTestClockId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "test_helpers.test_clock"
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TestHelpersTestClockStatusDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advancing: Option<TestHelpersTestClockStatusDetailsAdvancing>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TestHelpersTestClockStatusDetailsAdvancing {
+    /// The `frozen_time` that the Test Clock is advancing towards.
+    pub target_frozen_time: Timestamp,
+}
+
+/// The parameters for `TestHelpersTestClock::advance`.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdvanceTestHelpersTestClock<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The time to advance the test clock.
+    ///
+    /// Must be after the test clock's current frozen time.
+    /// Cannot be more than two intervals in the future from the shortest subscription in this test clock.
+    /// If there are no subscriptions in this test clock, it cannot be more than two years in the future.
+    pub frozen_time: Timestamp,
+}
+
+impl<'a> AdvanceTestHelpersTestClock<'a> {
+    pub fn new(frozen_time: Timestamp) -> Self {
+        AdvanceTestHelpersTestClock { expand: Default::default(), frozen_time }
+    }
+}
+
+/// The parameters for `TestHelpersTestClock::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTestHelpersTestClock<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The initial frozen time for this test clock.
+    pub frozen_time: Timestamp,
+
+    /// The name for this test clock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+impl<'a> CreateTestHelpersTestClock<'a> {
+    pub fn new(frozen_time: Timestamp) -> Self {
+        CreateTestHelpersTestClock {
+            expand: Default::default(),
+            frozen_time,
+            name: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TestHelpersTestClock::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTestHelpersTestClocks<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TestHelpersTestClockId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TestHelpersTestClockId>,
+}
+
+impl<'a> ListTestHelpersTestClocks<'a> {
+    pub fn new() -> Self {
+        ListTestHelpersTestClocks {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// An enum representing the possible values of an `TestHelpersTestClock`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TestHelpersTestClockStatus {
+    Advancing,
+    InternalFailure,
+    Ready,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl TestHelpersTestClockStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TestHelpersTestClockStatus::Advancing => "advancing",
+            TestHelpersTestClockStatus::InternalFailure => "internal_failure",
+            TestHelpersTestClockStatus::Ready => "ready",
+            TestHelpersTestClockStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for TestHelpersTestClockStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TestHelpersTestClockStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -0,0 +1,119 @@
+use serde::de::DeserializeOwned;
+
+use crate::config::{err, Client, Response};
+use crate::error::Error;
+use crate::ids::EventId;
+use crate::params::{Object, RequestOptions};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe v2 "Event" as delivered to a thin event destination.
+///
+/// A thin event only references the object which changed, rather than carrying a snapshot of it.
+/// Use `ThinEvent::pull_related_object` to fetch the object's current state, or
+/// `ThinEvent::retrieve` to fetch the event's `data`.
+///
+/// For more details see [https://docs.stripe.com/api/v2/core/events](https://docs.stripe.com/api/v2/core/events).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThinEvent {
+    /// Unique identifier for the event.
+    pub id: EventId,
+
+    /// The type of the event, e.g. `v1.billing.meter.error_report_triggered`.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Authentication context needed to fetch the event or related object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+
+    /// Time at which the object was created, as an RFC 3339 timestamp.
+    pub created: String,
+
+    /// Additional data about the event, only present on a retrieved event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    #[serde(default)]
+    pub livemode: bool,
+
+    /// Object containing the reference to API resource relevant to the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related_object: Option<ThinEventRelatedObject>,
+}
+
+impl ThinEvent {
+    /// Retrieves the details of an event, including its `data`.
+    ///
+    /// Events from a connected account must be retrieved on its behalf, e.g. with a client made
+    /// by `Client::with_options` for the event's `context`, or with `ThinEvent::fetch`.
+    pub fn retrieve(client: &Client, id: &EventId) -> Response<ThinEvent> {
+        client.get_v2(&format!("/core/events/{}", id))
+    }
+
+    /// Retrieves this event in full, including its `data`.
+    ///
+    /// The request is made on behalf of the account in `context`, if any.
+    pub fn fetch(&self, client: &Client) -> Response<ThinEvent> {
+        ThinEvent::retrieve(&self.context_client(client), &self.id)
+    }
+
+    /// Fetches the current state of the object this event relates to.
+    ///
+    /// The request is made on behalf of the account in `context`, if any.
+    /// `T` is the type of the related object, e.g. `BillingMeter` for `v1.billing.meter.*` events.
+    pub fn pull_related_object<T: DeserializeOwned + Send + 'static>(
+        &self,
+        client: &Client,
+    ) -> Response<T> {
+        let url = match &self.related_object {
+            Some(related_object) => &related_object.url,
+            None => return err(Error::Unsupported("event has no related object")),
+        };
+        let client = self.context_client(client);
+        if let Some(path) = url.strip_prefix("/v1/") {
+            client.get(path)
+        } else if let Some(path) = url.strip_prefix("/v2/") {
+            client.get_v2(path)
+        } else {
+            err(Error::Unsupported(
+                "URL for fetching the related object uses different API version",
+            ))
+        }
+    }
+
+    /// Clones `client` to make requests on behalf of the account the event occurred in.
+    fn context_client(&self, client: &Client) -> Client {
+        match &self.context {
+            Some(context) => {
+                let mut options = RequestOptions::new();
+                options.stripe_account = Some(context.clone());
+                client.with_options(options)
+            }
+            None => client.clone(),
+        }
+    }
+}
+
+impl Object for ThinEvent {
+    type Id = EventId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "v2.core.event"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThinEventRelatedObject {
+    /// Unique identifier for the object relevant to the event.
+    pub id: String,
+
+    /// Type of the object relevant to the event.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// URL to retrieve the resource.
+    pub url: String,
+}
@@ -8,6 +8,11 @@ pub enum TokenType {
     BankAccount,
     Card,
     Pii,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TokenType {
@@ -17,6 +22,7 @@ impl TokenType {
             TokenType::BankAccount => "bank_account",
             TokenType::Card => "card",
             TokenType::Pii => "pii",
+            TokenType::Other => "other",
         }
     }
 }
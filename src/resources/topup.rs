@@ -209,6 +209,11 @@ pub enum TopupStatus {
     Pending,
     Reversed,
     Succeeded,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TopupStatus {
@@ -219,6 +224,7 @@ impl TopupStatus {
             TopupStatus::Pending => "pending",
             TopupStatus::Reversed => "reversed",
             TopupStatus::Succeeded => "succeeded",
+            TopupStatus::Other => "other",
         }
     }
 }
@@ -243,6 +249,11 @@ pub enum TopupStatusFilter {
     Failed,
     Pending,
     Succeeded,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TopupStatusFilter {
@@ -252,6 +263,7 @@ impl TopupStatusFilter {
             TopupStatusFilter::Failed => "failed",
             TopupStatusFilter::Pending => "pending",
             TopupStatusFilter::Succeeded => "succeeded",
+            TopupStatusFilter::Other => "other",
         }
     }
 }
@@ -276,6 +276,11 @@ pub enum TransferSourceType {
     BankAccount,
     Card,
     Fpx,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl TransferSourceType {
@@ -284,6 +289,7 @@ impl TransferSourceType {
             TransferSourceType::BankAccount => "bank_account",
             TransferSourceType::Card => "card",
             TransferSourceType::Fpx => "fpx",
+            TransferSourceType::Other => "other",
         }
     }
 }
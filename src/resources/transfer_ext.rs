@@ -0,0 +1,18 @@
+use crate::config::{Client, Response};
+use crate::params::List;
+use crate::resources::{ListTransfers, Transfer};
+
+impl Transfer {
+    /// Lists the transfers in a transfer group, e.g. to reconcile them with the charges
+    /// which funded them using `Charge::list_by_transfer_group`.
+    ///
+    /// Any `transfer_group` already set on `params` is replaced.
+    pub fn list_by_transfer_group<'a>(
+        client: &Client,
+        transfer_group: &'a str,
+        mut params: ListTransfers<'a>,
+    ) -> Response<List<Transfer>> {
+        params.transfer_group = Some(transfer_group);
+        Transfer::list(client, params)
+    }
+}
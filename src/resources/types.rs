@@ -1,195 +1,121 @@
 use crate::params::Timestamp;
-use crate::resources::card::{CardBrand, CardType};
+use crate::resources::card::CardType;
 use serde_derive::{Deserialize, Serialize};
 
 /// An enum representing the versions of the Stripe API.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+///
+/// Versions released after this library was last updated can be specified with `ApiVersion::Other`.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ApiVersion {
-    #[serde(rename = "2011-01-01")]
     V2011_01_01,
-    #[serde(rename = "2011-06-21")]
     V2011_06_21,
-    #[serde(rename = "2011-06-28")]
     V2011_06_28,
-    #[serde(rename = "2011-08-01")]
     V2011_08_01,
-    #[serde(rename = "2011-09-15")]
     V2011_09_15,
-    #[serde(rename = "2011-11-17")]
     V2011_11_17,
-    #[serde(rename = "2012-02-23")]
     V2012_02_23,
-    #[serde(rename = "2012-03-25")]
     V2012_03_25,
-    #[serde(rename = "2012-06-18")]
     V2012_06_18,
-    #[serde(rename = "2012-06-28")]
     V2012_06_28,
-    #[serde(rename = "2012-07-09")]
     V2012_07_09,
-    #[serde(rename = "2012-09-24")]
     V2012_09_24,
-    #[serde(rename = "2012-10-26")]
     V2012_10_26,
-    #[serde(rename = "2012-11-07")]
     V2012_11_07,
-    #[serde(rename = "2013-02-11")]
     V2013_02_11,
-    #[serde(rename = "2013-02-13")]
     V2013_02_13,
-    #[serde(rename = "2013-07-05")]
     V2013_07_05,
-    #[serde(rename = "2013-08-12")]
     V2013_08_12,
-    #[serde(rename = "2013-08-13")]
     V2013_08_13,
-    #[serde(rename = "2013-10-29")]
     V2013_10_29,
-    #[serde(rename = "2013-12-03")]
     V2013_12_03,
-    #[serde(rename = "2014-01-31")]
     V2014_01_31,
-    #[serde(rename = "2014-03-13")]
     V2014_03_13,
-    #[serde(rename = "2014-03-28")]
     V2014_03_28,
-    #[serde(rename = "2014-05-19")]
     V2014_05_19,
-    #[serde(rename = "2014-06-13")]
     V2014_06_13,
-    #[serde(rename = "2014-06-17")]
     V2014_06_17,
-    #[serde(rename = "2014-07-22")]
     V2014_07_22,
-    #[serde(rename = "2014-07-26")]
     V2014_07_26,
-    #[serde(rename = "2014-08-04")]
     V2014_08_04,
-    #[serde(rename = "2014-08-20")]
     V2014_08_20,
-    #[serde(rename = "2014-09-08")]
     V2014_09_08,
-    #[serde(rename = "2014-10-07")]
     V2014_10_07,
-    #[serde(rename = "2014-11-05")]
     V2014_11_05,
-    #[serde(rename = "2014-11-20")]
     V2014_11_20,
-    #[serde(rename = "2014-12-08")]
     V2014_12_08,
-    #[serde(rename = "2014-12-17")]
     V2014_12_17,
-    #[serde(rename = "2014-12-22")]
     V2014_12_22,
-    #[serde(rename = "2015-01-11")]
     V2015_01_11,
-    #[serde(rename = "2015-01-26")]
     V2015_01_26,
-    #[serde(rename = "2015-02-10")]
     V2015_02_10,
-    #[serde(rename = "2015-02-16")]
     V2015_02_16,
-    #[serde(rename = "2015-02-18")]
     V2015_02_18,
-    #[serde(rename = "2015-03-24")]
     V2015_03_24,
-    #[serde(rename = "2015-04-07")]
     V2015_04_07,
-    #[serde(rename = "2015-06-15")]
     V2015_06_15,
-    #[serde(rename = "2015-07-07")]
     V2015_07_07,
-    #[serde(rename = "2015-07-13")]
     V2015_07_13,
-    #[serde(rename = "2015-07-28")]
     V2015_07_28,
-    #[serde(rename = "2015-08-07")]
     V2015_08_07,
-    #[serde(rename = "2015-08-19")]
     V2015_08_19,
-    #[serde(rename = "2015-09-03")]
     V2015_09_03,
-    #[serde(rename = "2015-09-08")]
     V2015_09_08,
-    #[serde(rename = "2015-09-23")]
     V2015_09_23,
-    #[serde(rename = "2015-10-01")]
     V2015_10_01,
-    #[serde(rename = "2015-10-12")]
     V2015_10_12,
-    #[serde(rename = "2015-10-16")]
     V2015_10_16,
-    #[serde(rename = "2016-02-03")]
     V2016_02_03,
-    #[serde(rename = "2016-02-19")]
     V2016_02_19,
-    #[serde(rename = "2016-02-22")]
     V2016_02_22,
-    #[serde(rename = "2016-02-23")]
     V2016_02_23,
-    #[serde(rename = "2016-02-29")]
     V2016_02_29,
-    #[serde(rename = "2016-03-07")]
     V2016_03_07,
-    #[serde(rename = "2016-06-15")]
     V2016_06_15,
-    #[serde(rename = "2016-07-06")]
     V2016_07_06,
-    #[serde(rename = "2016-10-19")]
     V2016_10_19,
-    #[serde(rename = "2017-01-27")]
     V2017_01_27,
-    #[serde(rename = "2017-02-14")]
     V2017_02_14,
-    #[serde(rename = "2017-04-06")]
     V2017_04_06,
-    #[serde(rename = "2017-05-25")]
     V2017_05_25,
-    #[serde(rename = "2017-06-05")]
     V2017_06_05,
-    #[serde(rename = "2017-08-15")]
     V2017_08_15,
-    #[serde(rename = "2017-12-14")]
     V2017_12_14,
-    #[serde(rename = "2018-01-23")]
     V2018_01_23,
-    #[serde(rename = "2018-02-05")]
     V2018_02_05,
-    #[serde(rename = "2018-02-06")]
     V2018_02_06,
-    #[serde(rename = "2018-02-28")]
     V2018_02_28,
-    #[serde(rename = "2018-05-21")]
     V2018_05_21,
-    #[serde(rename = "2018-07-27")]
     V2018_07_27,
-    #[serde(rename = "2018-08-23")]
     V2018_08_23,
-    #[serde(rename = "2018-09-06")]
     V2018_09_06,
-    #[serde(rename = "2018-09-24")]
     V2018_09_24,
-    #[serde(rename = "2018-10-31")]
     V2018_10_31,
-    #[serde(rename = "2018-11-08")]
     V2018_11_08,
-    #[serde(rename = "2019-02-11")]
     V2019_02_11,
-    #[serde(rename = "2019-02-19")]
     V2019_02_19,
-    #[serde(rename = "2019-03-14")]
     V2019_03_14,
-    #[serde(rename = "2019-05-16")]
     V2019_05_16,
-    #[serde(rename = "2019-08-14")]
     V2019_08_14,
-    #[serde(rename = "2019-09-09")]
     V2019_09_09,
+    V2019_10_08,
+    V2019_10_17,
+    V2019_11_05,
+    V2019_12_03,
+    V2020_03_02,
+    V2020_08_27,
+    V2022_08_01,
+    V2022_11_15,
+    V2023_08_16,
+    V2023_10_16,
+    V2024_04_10,
+    V2024_06_20,
+
+    /// An api version not yet supported by the library.
+    Other(String),
 }
 
 impl ApiVersion {
-    pub fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             ApiVersion::V2011_01_01 => "2011-01-01",
             ApiVersion::V2011_06_21 => "2011-06-21",
@@ -281,6 +207,129 @@ impl ApiVersion {
             ApiVersion::V2019_05_16 => "2019-05-16",
             ApiVersion::V2019_08_14 => "2019-08-14",
             ApiVersion::V2019_09_09 => "2019-09-09",
+            ApiVersion::V2019_10_08 => "2019-10-08",
+            ApiVersion::V2019_10_17 => "2019-10-17",
+            ApiVersion::V2019_11_05 => "2019-11-05",
+            ApiVersion::V2019_12_03 => "2019-12-03",
+            ApiVersion::V2020_03_02 => "2020-03-02",
+            ApiVersion::V2020_08_27 => "2020-08-27",
+            ApiVersion::V2022_08_01 => "2022-08-01",
+            ApiVersion::V2022_11_15 => "2022-11-15",
+            ApiVersion::V2023_08_16 => "2023-08-16",
+            ApiVersion::V2023_10_16 => "2023-10-16",
+            ApiVersion::V2024_04_10 => "2024-04-10",
+            ApiVersion::V2024_06_20 => "2024-06-20",
+            ApiVersion::Other(version) => version,
+        }
+    }
+}
+
+impl From<&str> for ApiVersion {
+    fn from(s: &str) -> Self {
+        match s {
+            "2011-01-01" => ApiVersion::V2011_01_01,
+            "2011-06-21" => ApiVersion::V2011_06_21,
+            "2011-06-28" => ApiVersion::V2011_06_28,
+            "2011-08-01" => ApiVersion::V2011_08_01,
+            "2011-09-15" => ApiVersion::V2011_09_15,
+            "2011-11-17" => ApiVersion::V2011_11_17,
+            "2012-02-23" => ApiVersion::V2012_02_23,
+            "2012-03-25" => ApiVersion::V2012_03_25,
+            "2012-06-18" => ApiVersion::V2012_06_18,
+            "2012-06-28" => ApiVersion::V2012_06_28,
+            "2012-07-09" => ApiVersion::V2012_07_09,
+            "2012-09-24" => ApiVersion::V2012_09_24,
+            "2012-10-26" => ApiVersion::V2012_10_26,
+            "2012-11-07" => ApiVersion::V2012_11_07,
+            "2013-02-11" => ApiVersion::V2013_02_11,
+            "2013-02-13" => ApiVersion::V2013_02_13,
+            "2013-07-05" => ApiVersion::V2013_07_05,
+            "2013-08-12" => ApiVersion::V2013_08_12,
+            "2013-08-13" => ApiVersion::V2013_08_13,
+            "2013-10-29" => ApiVersion::V2013_10_29,
+            "2013-12-03" => ApiVersion::V2013_12_03,
+            "2014-01-31" => ApiVersion::V2014_01_31,
+            "2014-03-13" => ApiVersion::V2014_03_13,
+            "2014-03-28" => ApiVersion::V2014_03_28,
+            "2014-05-19" => ApiVersion::V2014_05_19,
+            "2014-06-13" => ApiVersion::V2014_06_13,
+            "2014-06-17" => ApiVersion::V2014_06_17,
+            "2014-07-22" => ApiVersion::V2014_07_22,
+            "2014-07-26" => ApiVersion::V2014_07_26,
+            "2014-08-04" => ApiVersion::V2014_08_04,
+            "2014-08-20" => ApiVersion::V2014_08_20,
+            "2014-09-08" => ApiVersion::V2014_09_08,
+            "2014-10-07" => ApiVersion::V2014_10_07,
+            "2014-11-05" => ApiVersion::V2014_11_05,
+            "2014-11-20" => ApiVersion::V2014_11_20,
+            "2014-12-08" => ApiVersion::V2014_12_08,
+            "2014-12-17" => ApiVersion::V2014_12_17,
+            "2014-12-22" => ApiVersion::V2014_12_22,
+            "2015-01-11" => ApiVersion::V2015_01_11,
+            "2015-01-26" => ApiVersion::V2015_01_26,
+            "2015-02-10" => ApiVersion::V2015_02_10,
+            "2015-02-16" => ApiVersion::V2015_02_16,
+            "2015-02-18" => ApiVersion::V2015_02_18,
+            "2015-03-24" => ApiVersion::V2015_03_24,
+            "2015-04-07" => ApiVersion::V2015_04_07,
+            "2015-06-15" => ApiVersion::V2015_06_15,
+            "2015-07-07" => ApiVersion::V2015_07_07,
+            "2015-07-13" => ApiVersion::V2015_07_13,
+            "2015-07-28" => ApiVersion::V2015_07_28,
+            "2015-08-07" => ApiVersion::V2015_08_07,
+            "2015-08-19" => ApiVersion::V2015_08_19,
+            "2015-09-03" => ApiVersion::V2015_09_03,
+            "2015-09-08" => ApiVersion::V2015_09_08,
+            "2015-09-23" => ApiVersion::V2015_09_23,
+            "2015-10-01" => ApiVersion::V2015_10_01,
+            "2015-10-12" => ApiVersion::V2015_10_12,
+            "2015-10-16" => ApiVersion::V2015_10_16,
+            "2016-02-03" => ApiVersion::V2016_02_03,
+            "2016-02-19" => ApiVersion::V2016_02_19,
+            "2016-02-22" => ApiVersion::V2016_02_22,
+            "2016-02-23" => ApiVersion::V2016_02_23,
+            "2016-02-29" => ApiVersion::V2016_02_29,
+            "2016-03-07" => ApiVersion::V2016_03_07,
+            "2016-06-15" => ApiVersion::V2016_06_15,
+            "2016-07-06" => ApiVersion::V2016_07_06,
+            "2016-10-19" => ApiVersion::V2016_10_19,
+            "2017-01-27" => ApiVersion::V2017_01_27,
+            "2017-02-14" => ApiVersion::V2017_02_14,
+            "2017-04-06" => ApiVersion::V2017_04_06,
+            "2017-05-25" => ApiVersion::V2017_05_25,
+            "2017-06-05" => ApiVersion::V2017_06_05,
+            "2017-08-15" => ApiVersion::V2017_08_15,
+            "2017-12-14" => ApiVersion::V2017_12_14,
+            "2018-01-23" => ApiVersion::V2018_01_23,
+            "2018-02-05" => ApiVersion::V2018_02_05,
+            "2018-02-06" => ApiVersion::V2018_02_06,
+            "2018-02-28" => ApiVersion::V2018_02_28,
+            "2018-05-21" => ApiVersion::V2018_05_21,
+            "2018-07-27" => ApiVersion::V2018_07_27,
+            "2018-08-23" => ApiVersion::V2018_08_23,
+            "2018-09-06" => ApiVersion::V2018_09_06,
+            "2018-09-24" => ApiVersion::V2018_09_24,
+            "2018-10-31" => ApiVersion::V2018_10_31,
+            "2018-11-08" => ApiVersion::V2018_11_08,
+            "2019-02-11" => ApiVersion::V2019_02_11,
+            "2019-02-19" => ApiVersion::V2019_02_19,
+            "2019-03-14" => ApiVersion::V2019_03_14,
+            "2019-05-16" => ApiVersion::V2019_05_16,
+            "2019-08-14" => ApiVersion::V2019_08_14,
+            "2019-09-09" => ApiVersion::V2019_09_09,
+            "2019-10-08" => ApiVersion::V2019_10_08,
+            "2019-10-17" => ApiVersion::V2019_10_17,
+            "2019-11-05" => ApiVersion::V2019_11_05,
+            "2019-12-03" => ApiVersion::V2019_12_03,
+            "2020-03-02" => ApiVersion::V2020_03_02,
+            "2020-08-27" => ApiVersion::V2020_08_27,
+            "2022-08-01" => ApiVersion::V2022_08_01,
+            "2022-11-15" => ApiVersion::V2022_11_15,
+            "2023-08-16" => ApiVersion::V2023_08_16,
+            "2023-10-16" => ApiVersion::V2023_10_16,
+            "2024-04-10" => ApiVersion::V2024_04_10,
+            "2024-06-20" => ApiVersion::V2024_06_20,
+            other => ApiVersion::Other(other.to_string()),
         }
     }
 }
@@ -297,6 +346,25 @@ impl std::fmt::Display for ApiVersion {
     }
 }
 
+impl serde::Serialize for ApiVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ApiVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(ApiVersion::from(s.as_str()))
+    }
+}
+
 /// An enum representing the possible values of a `BankAccount`'s `account_holder_type` field.
 ///
 /// For more details see [https://stripe.com/docs/api/customer_bank_accounts/object#customer_bank_account_object-account_holder_type](https://stripe.com/docs/api/customer_bank_accounts/object#customer_bank_account_object-account_holder_type)
@@ -305,6 +373,11 @@ impl std::fmt::Display for ApiVersion {
 pub enum AccountHolderType {
     Individual,
     Company,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -346,6 +419,47 @@ pub struct CustomField {
     pub value: String,
 }
 
+/// The estimated range for how long shipping will take.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ShippingRateDeliveryEstimate {
+    /// The upper bound of the estimated range.
+    ///
+    /// If empty, represents no upper bound i.e., infinite.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<ShippingRateDeliveryEstimateBound>,
+
+    /// The lower bound of the estimated range.
+    ///
+    /// If empty, represents no lower bound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<ShippingRateDeliveryEstimateBound>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShippingRateDeliveryEstimateBound {
+    /// A unit of time.
+    pub unit: ShippingRateDeliveryEstimateUnit,
+
+    /// Must be greater than 0.
+    pub value: i64,
+}
+
+/// An enum representing the possible values of a `ShippingRateDeliveryEstimateBound`'s `unit` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShippingRateDeliveryEstimateUnit {
+    BusinessDay,
+    Day,
+    Hour,
+    Month,
+    Week,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
 /// A date of birth.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Dob {
@@ -360,6 +474,11 @@ pub struct Dob {
 pub enum FraudDetailsReport {
     Fraudulent,
     Safe,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -372,9 +491,11 @@ pub struct PackageDimensions {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PaymentMethodCard {
-    /// Can be `American Express`, `Diners Club`, `Discover`, `JCB`, `MasterCard`, `UnionPay`, `Visa`, or `Unknown`.
+    /// Card brand.
+    ///
+    /// Can be `amex`, `diners`, `discover`, `jcb`, `mastercard`, `unionpay`, `visa`, or `unknown`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub brand: Option<CardBrand>,
+    pub brand: Option<PaymentMethodCardBrand>,
 
     /// Two-letter ISO code representing the country of the card.
     ///
@@ -402,6 +523,55 @@ pub struct PaymentMethodCard {
     pub last4: String,
 }
 
+/// An enum representing the possible values of a `PaymentMethodCard`'s `brand` field.
+///
+/// Unlike a card object's `CardBrand`, these are lowercase.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodCardBrand {
+    Amex,
+    Diners,
+    Discover,
+    Jcb,
+    Mastercard,
+    Unionpay,
+    Unknown,
+    Visa,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl PaymentMethodCardBrand {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentMethodCardBrand::Amex => "amex",
+            PaymentMethodCardBrand::Diners => "diners",
+            PaymentMethodCardBrand::Discover => "discover",
+            PaymentMethodCardBrand::Jcb => "jcb",
+            PaymentMethodCardBrand::Mastercard => "mastercard",
+            PaymentMethodCardBrand::Unionpay => "unionpay",
+            PaymentMethodCardBrand::Unknown => "unknown",
+            PaymentMethodCardBrand::Visa => "visa",
+            PaymentMethodCardBrand::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentMethodCardBrand {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentMethodCardBrand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 // TODO: Implement
 /// This type is a stub that still needs to be implemented.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -464,6 +634,11 @@ pub enum SpendingLimitInterval {
     PerAuthorization,
     Weekly,
     Yearly,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -551,6 +726,11 @@ pub enum Weekday {
     Thursday,
     Friday,
     Saturday,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -590,6 +770,11 @@ pub enum SetupIntentUsage {
     OnSession,
     #[serde(rename = "off_session")]
     OffSession,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -602,6 +787,11 @@ pub struct SubscriptionItemBillingThresholds {
 pub enum BusinessType {
     Individual,
     Company,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -621,4 +811,9 @@ pub enum ApiErrors {
     InvalidRequestError,
     #[serde(rename = "rate_limit_error")]
     RateLimitError,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
@@ -563,6 +563,11 @@ pub enum EventFilter {
     TransferReversed,
     #[serde(rename = "transfer.updated")]
     TransferUpdated,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl EventFilter {
@@ -725,6 +730,7 @@ impl EventFilter {
             EventFilter::TransferPaid => "transfer.paid",
             EventFilter::TransferReversed => "transfer.reversed",
             EventFilter::TransferUpdated => "transfer.updated",
+            EventFilter::Other => "other",
         }
     }
 }
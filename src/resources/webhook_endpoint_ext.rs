@@ -6,6 +6,11 @@ use serde_derive::{Deserialize, Serialize};
 pub enum WebhookEndpointStatus {
     Disabled,
     Enabled,
+
+    /// A variant not yet supported by the library.
+    /// It is an error to send `Other` as part of a request.
+    #[serde(other, skip_serializing)]
+    Other,
 }
 
 impl WebhookEndpointStatus {
@@ -13,6 +18,7 @@ impl WebhookEndpointStatus {
         match self {
             WebhookEndpointStatus::Disabled => "disabled",
             WebhookEndpointStatus::Enabled => "enabled",
+            WebhookEndpointStatus::Other => "other",
         }
     }
 }
@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::error::WebhookError;
+use crate::ids::EventId;
+use crate::resources::{Event, EventType, Webhook};
+
+/// Records which events have been processed, so a `WebhookProcessor` handles each event once
+/// even though Stripe may deliver it more than once.
+///
+/// An event is claimed before its handler runs, then either completed or released depending on
+/// the outcome. A claim which is never resolved, e.g. because the process crashed, should expire
+/// after a lease so that Stripe's retry is processed rather than dropped.
+///
+/// Implement this on top of a shared database or cache when running more than one process.
+pub trait EventStore {
+    /// Claims the event for processing, returning `false` if it has already been processed or
+    /// is still being processed under an unexpired claim.
+    fn claim(&self, id: &EventId) -> bool;
+
+    /// Marks a claimed event as processed, so it is never claimed again.
+    fn complete(&self, id: &EventId);
+
+    /// Forgets a claimed event whose handler failed, so that Stripe's retry is processed.
+    fn release(&self, id: &EventId);
+}
+
+impl<S: EventStore + ?Sized> EventStore for Arc<S> {
+    fn claim(&self, id: &EventId) -> bool {
+        (**self).claim(id)
+    }
+
+    fn complete(&self, id: &EventId) {
+        (**self).complete(id)
+    }
+
+    fn release(&self, id: &EventId) {
+        (**self).release(id)
+    }
+}
+
+/// An `EventStore` which keeps the processed event ids in memory.
+///
+/// The ids are lost on restart and aren't shared between processes.
+#[derive(Debug)]
+pub struct InMemoryEventStore {
+    lease: Duration,
+    events: Mutex<HashMap<EventId, EventState>>,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum EventState {
+    /// Claimed at the given instant, and not yet completed or released.
+    InProgress(Instant),
+    Done,
+}
+
+impl InMemoryEventStore {
+    /// Creates a store whose claims expire after five minutes.
+    pub fn new() -> Self {
+        InMemoryEventStore::with_lease(Duration::from_secs(5 * 60))
+    }
+
+    /// Creates a store whose claims expire after `lease`, which should be longer than any
+    /// handler takes to run.
+    pub fn with_lease(lease: Duration) -> Self {
+        InMemoryEventStore { lease, events: Mutex::new(HashMap::new()) }
+    }
+}
+
+impl Default for InMemoryEventStore {
+    fn default() -> Self {
+        InMemoryEventStore::new()
+    }
+}
+
+impl EventStore for InMemoryEventStore {
+    fn claim(&self, id: &EventId) -> bool {
+        let mut events = self.events.lock().unwrap();
+        match events.get(id) {
+            Some(EventState::Done) => false,
+            Some(EventState::InProgress(claimed)) if claimed.elapsed() < self.lease => false,
+            _ => {
+                events.insert(id.clone(), EventState::InProgress(Instant::now()));
+                true
+            }
+        }
+    }
+
+    fn complete(&self, id: &EventId) {
+        self.events.lock().unwrap().insert(id.clone(), EventState::Done);
+    }
+
+    fn release(&self, id: &EventId) {
+        self.events.lock().unwrap().remove(id);
+    }
+}
+
+/// Releases a claimed event when dropped, unless it was completed first.
+///
+/// This also releases the event if its handler panics.
+struct Claim<'a, S: EventStore> {
+    store: &'a S,
+    id: &'a EventId,
+    completed: bool,
+}
+
+impl<S: EventStore> Claim<'_, S> {
+    fn complete(mut self) {
+        self.store.complete(self.id);
+        self.completed = true;
+    }
+}
+
+impl<S: EventStore> Drop for Claim<'_, S> {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.store.release(self.id);
+        }
+    }
+}
+
+type Handler<E> = Box<dyn Fn(&Event) -> Result<(), E> + Send + Sync>;
+
+/// Verifies, deduplicates and dispatches webhook events.
+///
+/// ```rust,no_run
+/// use stripe::{EventObject, EventType, InMemoryEventStore, WebhookOutcome, WebhookProcessor};
+///
+/// let mut processor: WebhookProcessor<_> =
+///     WebhookProcessor::new("whsec_...", InMemoryEventStore::new());
+/// processor.on(EventType::CheckoutSessionCompleted, |event| {
+///     if let EventObject::CheckoutSession(session) = &event.data.object {
+///         println!("fulfilling {}", session.id);
+///     }
+///     Ok(())
+/// });
+///
+/// # let (payload, signature) = ("", "");
+/// match processor.process(payload, signature) {
+///     Ok(WebhookOutcome::Handled) | Ok(WebhookOutcome::Duplicate) => { /* respond 200 */ }
+///     Ok(WebhookOutcome::Unhandled) => { /* respond 200, nothing to do */ }
+///     Err(err) => { /* respond 400 or 500 so Stripe retries */ }
+/// }
+/// ```
+pub struct WebhookProcessor<S, E = Box<dyn std::error::Error + Send + Sync>> {
+    secret: String,
+    store: S,
+    handlers: HashMap<EventType, Handler<E>>,
+    fallback: Option<Handler<E>>,
+}
+
+impl<S: EventStore, E> WebhookProcessor<S, E> {
+    /// Creates a processor for events signed with the endpoint's `secret`.
+    pub fn new(secret: impl Into<String>, store: S) -> Self {
+        WebhookProcessor { secret: secret.into(), store, handlers: HashMap::new(), fallback: None }
+    }
+
+    /// Handles events of `event_type` with `handler`, replacing any previous handler.
+    pub fn on<F>(&mut self, event_type: EventType, handler: F)
+    where
+        F: Fn(&Event) -> Result<(), E> + Send + Sync + 'static,
+    {
+        self.handlers.insert(event_type, Box::new(handler));
+    }
+
+    /// Handles events which have no handler of their own with `handler`.
+    pub fn on_unhandled<F>(&mut self, handler: F)
+    where
+        F: Fn(&Event) -> Result<(), E> + Send + Sync + 'static,
+    {
+        self.fallback = Some(Box::new(handler));
+    }
+
+    /// Verifies the `Stripe-Signature` header of `payload`, then dispatches the event to its
+    /// handler unless it has already been processed.
+    ///
+    /// The event is marked as processed only once its handler succeeds. If the handler fails
+    /// or panics the event is released from the store (and the error returned or the panic
+    /// resumed), so that responding with an error lets Stripe's retry be processed.
+    pub fn process(
+        &self,
+        payload: &str,
+        sig: &str,
+    ) -> Result<WebhookOutcome, WebhookProcessError<E>> {
+        let event = Webhook::construct_event(payload, sig, &self.secret)
+            .map_err(WebhookProcessError::Webhook)?;
+        let handler = match self.handlers.get(&event.event_type).or(self.fallback.as_ref()) {
+            Some(handler) => handler,
+            None => return Ok(WebhookOutcome::Unhandled),
+        };
+        if !self.store.claim(&event.id) {
+            return Ok(WebhookOutcome::Duplicate);
+        }
+        let claim = Claim { store: &self.store, id: &event.id, completed: false };
+        match handler(&event) {
+            Ok(()) => {
+                claim.complete();
+                Ok(WebhookOutcome::Handled)
+            }
+            Err(err) => {
+                drop(claim);
+                Err(WebhookProcessError::Handler(err))
+            }
+        }
+    }
+}
+
+/// What `WebhookProcessor::process` did with an event.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum WebhookOutcome {
+    /// The event was passed to its handler, which succeeded.
+    Handled,
+    /// The event had already been processed, so its handler wasn't called again.
+    Duplicate,
+    /// There is no handler for the event's type.
+    Unhandled,
+}
+
+/// An error from `WebhookProcessor::process`.
+#[derive(Debug)]
+pub enum WebhookProcessError<E> {
+    /// The event couldn't be verified or parsed.
+    Webhook(WebhookError),
+    /// The event's handler failed.
+    Handler(E),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for WebhookProcessError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            WebhookProcessError::Webhook(ref err) => write!(f, "{}", err),
+            WebhookProcessError::Handler(ref err) => write!(f, "error handling event: {}", err),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for WebhookProcessError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            WebhookProcessError::Webhook(ref err) => Some(err),
+            WebhookProcessError::Handler(ref err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const SECRET: &str = "webhook_secret";
+    const PAYLOAD: &str = r#"{"id": "evt_123", "object": "event", "type": "product.deleted", "data": {"object": {"id": "prod_123", "object": "product", "deleted": true}}}"#;
+
+    fn sign(payload: &str) -> String {
+        Webhook::generate_test_signature(payload, SECRET, chrono::Utc::now().timestamp()).unwrap()
+    }
+
+    #[test]
+    fn test_process_deduplicates_events() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut processor: WebhookProcessor<_, String> =
+            WebhookProcessor::new(SECRET, InMemoryEventStore::new());
+        let counter = calls.clone();
+        processor.on(EventType::ProductDeleted, move |event| {
+            assert_eq!(event.id.as_str(), "evt_123");
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+
+        let signature = sign(PAYLOAD);
+        assert_eq!(processor.process(PAYLOAD, &signature).unwrap(), WebhookOutcome::Handled);
+        assert_eq!(processor.process(PAYLOAD, &signature).unwrap(), WebhookOutcome::Duplicate);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_process_retries_failed_events() {
+        let store = Arc::new(InMemoryEventStore::new());
+        let mut processor = WebhookProcessor::new(SECRET, store.clone());
+        processor.on(EventType::ProductDeleted, |_| Err("database unavailable".to_string()));
+
+        let signature = sign(PAYLOAD);
+        match processor.process(PAYLOAD, &signature) {
+            Err(WebhookProcessError::Handler(err)) => assert_eq!(err, "database unavailable"),
+            other => panic!("expected a handler error; was {:?}", other),
+        }
+        assert!(store.claim(&"evt_123".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_process_retries_panicked_events() {
+        let store = Arc::new(InMemoryEventStore::new());
+        let mut processor: WebhookProcessor<_, String> =
+            WebhookProcessor::new(SECRET, store.clone());
+        processor.on(EventType::ProductDeleted, |_| panic!("handler crashed"));
+
+        let signature = sign(PAYLOAD);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            processor.process(PAYLOAD, &signature)
+        }));
+        assert!(result.is_err());
+        assert!(store.claim(&"evt_123".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_in_memory_store_claims() {
+        let id: EventId = "evt_123".parse().unwrap();
+        let store = InMemoryEventStore::new();
+        assert!(store.claim(&id));
+        assert!(!store.claim(&id));
+        store.complete(&id);
+        assert!(!store.claim(&id));
+
+        // A claim which was never completed, e.g. after a crash, expires after the lease.
+        let store = InMemoryEventStore::with_lease(Duration::from_millis(0));
+        assert!(store.claim(&id));
+        assert!(store.claim(&id));
+        store.complete(&id);
+        assert!(!store.claim(&id));
+    }
+
+    #[test]
+    fn test_process_rejects_bad_signatures() {
+        let mut processor: WebhookProcessor<_, String> =
+            WebhookProcessor::new("other_secret", InMemoryEventStore::new());
+        processor.on_unhandled(|_| panic!("handled an unverified event"));
+
+        match processor.process(PAYLOAD, &sign(PAYLOAD)) {
+            Err(WebhookProcessError::Webhook(WebhookError::BadSignature)) => {}
+            other => panic!("expected a bad signature; was {:?}", other),
+        }
+        let processor: WebhookProcessor<_, String> =
+            WebhookProcessor::new(SECRET, InMemoryEventStore::new());
+        assert_eq!(processor.process(PAYLOAD, &sign(PAYLOAD)).unwrap(), WebhookOutcome::Unhandled);
+    }
+}
@@ -25,7 +25,18 @@ fn customer_create_and_delete_with_account() {
             client_id: Some("ca_123".into()),
             stripe_version: Some(stripe::ApiVersion::V2019_03_14),
             user_agent: None,
+            idempotency_key: None,
         });
         customer_create_and_delete(&client);
     });
 }
+
+#[test]
+fn customer_create_and_delete_with_options() {
+    mock::with_client(|client| {
+        let mut options = stripe::RequestOptions::new();
+        options.stripe_account = Some("TEST".into());
+        options.idempotency_key = Some("customer-create-and-delete".into());
+        customer_create_and_delete(&client.with_options(options));
+    });
+}
@@ -35,23 +35,23 @@ fn deserialize_currency() {
 
 #[test]
 fn serialize_range_query() {
-    use stripe::{ListCustomers, RangeBounds, RangeQuery};
+    use stripe::{ListCustomers, RangeBounds, RangeQuery, Timestamp};
 
     let query = RangeQuery::Bounds(RangeBounds {
         gt: None,
-        gte: Some(1501598702),
-        lt: Some(1504233902),
+        gte: Some(Timestamp::from(1501598702)),
+        lt: Some(Timestamp::from(1504233902)),
         lte: None,
     });
     assert_eq!(urldecode(serde_qs::to_string(&query).unwrap()), "gte=1501598702&lt=1504233902");
 
     let mut params = ListCustomers::new();
-    params.created = Some(RangeQuery::eq(1501598702));
+    params.created = Some(RangeQuery::eq(Timestamp::from(1501598702)));
     params.limit = Some(3);
     assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "created=1501598702&limit=3");
 
     let mut params = ListCustomers::new();
-    params.created = Some(RangeQuery::gte(1501598702));
+    params.created = Some(RangeQuery::gte(Timestamp::from(1501598702)));
     params.limit = Some(3);
     assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "created[gte]=1501598702&limit=3");
 
@@ -66,6 +66,20 @@ fn urldecode(input: String) -> String {
     input.replace("%5B", "[").replace("%5D", "]")
 }
 
+#[test]
+fn serialize_list_customers_filters() {
+    use stripe::{ListCustomers, RangeQuery, Timestamp};
+
+    let mut params = ListCustomers::new();
+    params.email = Some("jenny.rosen@example.com");
+    params.created = Some(RangeQuery::gte(Timestamp::from(1680000000)));
+    params.test_clock = Some("clock_123".parse().unwrap());
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "created[gte]=1680000000&email=jenny.rosen%40example.com&test_clock=clock_123"
+    );
+}
+
 #[test]
 fn deserialize_payment_source_params() {
     use stripe::{PaymentSourceParams, SourceId, TokenId};
@@ -310,3 +324,993 @@ fn deserialize_checkout_event() {
     let result = serde_json::from_value::<Event>(example);
     assert!(result.is_ok(), "expected ok; was {:?}", result);
 }
+
+#[test]
+fn deserialize_checkout_payment_method_type() {
+    use stripe::CheckoutSessionPaymentMethodType;
+
+    let types: Vec<CheckoutSessionPaymentMethodType> =
+        serde_json::from_value(json!(["card", "us_bank_account", "some_new_method"])).unwrap();
+    assert_eq!(
+        types,
+        vec![
+            CheckoutSessionPaymentMethodType::Card,
+            CheckoutSessionPaymentMethodType::UsBankAccount,
+            CheckoutSessionPaymentMethodType::Other("some_new_method".into()),
+        ]
+    );
+    assert_eq!(
+        serde_json::to_string(&types).unwrap(),
+        "[\"card\",\"us_bank_account\",\"some_new_method\"]"
+    );
+}
+
+#[test]
+fn deserialize_api_version() {
+    use stripe::ApiVersion;
+
+    let versions: Vec<ApiVersion> =
+        serde_json::from_value(json!(["2019-09-09", "2099-01-01"])).unwrap();
+    assert_eq!(versions, vec![ApiVersion::V2019_09_09, ApiVersion::Other("2099-01-01".into())]);
+    assert_eq!(serde_json::to_string(&versions).unwrap(), "[\"2019-09-09\",\"2099-01-01\"]");
+}
+
+#[test]
+fn serialize_oauth_authorize_url() {
+    use stripe::{AuthorizeUrlParams, OAuth, OAuthScope, OAuthStripeUser};
+
+    let mut params = AuthorizeUrlParams::new("ca_123");
+    params.scope = Some(OAuthScope::ReadWrite);
+    params.state = Some("csrf_token");
+    params.stripe_user =
+        Some(OAuthStripeUser { email: Some("jenny@example.com"), ..Default::default() });
+    assert_eq!(
+        urldecode(OAuth::authorize_url(&params).unwrap()),
+        "https://connect.stripe.com/oauth/authorize?client_id=ca_123&response_type=code&scope=read_write&state=csrf_token&stripe_user[email]=jenny%40example.com"
+    );
+}
+
+#[test]
+fn serialize_checkout_session_line_items() {
+    use stripe::CreateCheckoutSession;
+
+    let params: CreateCheckoutSession = serde_json::from_str(
+        r#"{
+          "payment_method_types": ["card"],
+          "line_items": [
+            {
+              "price_data": {
+                "currency": "usd",
+                "product_data": {
+                  "name": "T-shirt",
+                  "images": ["a.png", "b.png"],
+                  "metadata": { "sku": "ts_1" }
+                },
+                "unit_amount": 2000
+              },
+              "quantity": 2
+            },
+            { "price": "price_123", "quantity": 1, "tax_rates": ["txr_123"] }
+          ],
+          "mode": "payment"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "payment_method_types[0]=card\
+         &line_items[0][price_data][currency]=usd\
+         &line_items[0][price_data][product_data][images][0]=a.png\
+         &line_items[0][price_data][product_data][images][1]=b.png\
+         &line_items[0][price_data][product_data][metadata][sku]=ts_1\
+         &line_items[0][price_data][product_data][name]=T-shirt\
+         &line_items[0][price_data][unit_amount]=2000\
+         &line_items[0][quantity]=2\
+         &line_items[1][price]=price_123\
+         &line_items[1][quantity]=1\
+         &line_items[1][tax_rates][0]=txr_123\
+         &mode=payment"
+    );
+}
+
+#[test]
+fn serialize_update_field() {
+    use stripe::{UpdateCustomer, UpdateField};
+
+    let mut params = UpdateCustomer::new();
+    params.description = UpdateField::Set("VIP");
+    params.email = UpdateField::Clear;
+    params.name = UpdateField::Keep;
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "description=VIP&email=");
+}
+
+#[test]
+fn serialize_metadata() {
+    use stripe::{metadata, Metadata, MetadataError, UpdateCustomer};
+
+    let mut metadata = metadata! { "order_id" => "6735" };
+    metadata.unset("channel");
+    let mut params = UpdateCustomer::new();
+    params.metadata = metadata.into();
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert!(encoded.contains("metadata[order_id]=6735"), "{}", encoded);
+    assert!(encoded.contains("metadata[channel]="), "{}", encoded);
+
+    let metadata: Metadata = (0..51).map(|i| (i.to_string(), "value")).collect();
+    assert_eq!(metadata.validate(), Err(MetadataError::TooManyKeys(51)));
+    let metadata = metadata! { "note" => "x".repeat(501) };
+    assert_eq!(metadata.validate(), Err(MetadataError::ValueTooLong("note".into())));
+    assert!(serde_qs::to_string(&metadata).is_err());
+}
+
+#[test]
+fn deserialize_timestamp() {
+    use stripe::Timestamp;
+
+    let timestamp: Timestamp = serde_json::from_str("1501598702").unwrap();
+    assert_eq!(timestamp.as_secs(), 1501598702);
+    assert_eq!(serde_json::to_string(&timestamp).unwrap(), "1501598702");
+
+    #[cfg(feature = "chrono")]
+    {
+        let datetime = timestamp.to_datetime().unwrap();
+        assert_eq!(datetime.to_rfc3339(), "2017-08-01T14:45:02+00:00");
+        assert_eq!(Timestamp::from(datetime), timestamp);
+    }
+
+    #[cfg(feature = "time")]
+    {
+        let datetime = timestamp.to_offset_datetime().unwrap();
+        assert_eq!((datetime.year(), u8::from(datetime.month()), datetime.day()), (2017, 8, 1));
+        assert_eq!((datetime.hour(), datetime.minute(), datetime.second()), (14, 45, 2));
+        assert_eq!(datetime.offset(), time::UtcOffset::UTC);
+        assert_eq!(Timestamp::from(datetime), timestamp);
+        assert_eq!(Timestamp::from_secs(i64::MAX).to_offset_datetime(), None);
+    }
+}
+
+#[test]
+fn deserialize_unknown_enum_values() {
+    use stripe::{
+        CancellationDetailsFeedback, CheckoutSessionLocale, Currency, ErrorCode, EventType,
+        PaymentMethodCardBrand,
+    };
+
+    assert_eq!(serde_json::from_str::<Currency>("\"xts\"").unwrap(), Currency::Other);
+    assert_eq!(
+        serde_json::from_str::<CheckoutSessionLocale>("\"tlh\"").unwrap(),
+        CheckoutSessionLocale::Other
+    );
+    assert_eq!(serde_json::from_str::<EventType>("\"some.new.event\"").unwrap(), EventType::Other);
+    assert_eq!(serde_json::from_str::<ErrorCode>("\"some_new_code\"").unwrap(), ErrorCode::Other);
+    assert_eq!(
+        serde_json::from_str::<PaymentMethodCardBrand>("\"eftpos_au\"").unwrap(),
+        PaymentMethodCardBrand::Other
+    );
+    assert_eq!(
+        serde_json::from_str::<PaymentMethodCardBrand>("\"visa\"").unwrap(),
+        PaymentMethodCardBrand::Visa
+    );
+    assert_eq!(
+        serde_json::from_str::<CancellationDetailsFeedback>("\"some_new_feedback\"").unwrap(),
+        CancellationDetailsFeedback::Other
+    );
+    assert_eq!(
+        serde_json::from_str::<CancellationDetailsFeedback>("\"other\"").unwrap(),
+        CancellationDetailsFeedback::Other
+    );
+    assert!(serde_json::to_string(&Currency::Other).is_err());
+}
+
+#[test]
+fn deserialize_event_previous_attributes() {
+    use stripe::{Event, EventObject, EventType};
+
+    let example = json!({
+      "id": "evt_00000000000000",
+      "object": "event",
+      "type": "product.updated",
+      "data": {
+        "object": {
+          "id": "prod_00000000000000",
+          "object": "product",
+          "active": true,
+          "created": 1326853478,
+          "livemode": false,
+          "metadata": {},
+          "name": "T-shirt",
+          "updated": 1326853478
+        },
+        "previous_attributes": {
+          "name": "Shirt"
+        }
+      }
+    });
+    let event = serde_json::from_value::<Event>(example).unwrap();
+    assert_eq!(event.event_type, EventType::ProductUpdated);
+    assert!(matches!(event.data.object, EventObject::Product(_)));
+    assert_eq!(event.data.previous_attributes.unwrap()["name"], "Shirt");
+}
+
+#[test]
+fn serialize_list_plans_filters() {
+    let mut params = stripe::ListPlans::new();
+    params.active = Some(true);
+    params.product = Some("prod_123".parse().unwrap());
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "active=true&product=prod_123");
+}
+
+#[test]
+fn deserialize_source_transaction() {
+    let transaction = json!({
+        "id": "srctxn_123",
+        "object": "source_transaction",
+        "ach_credit_transfer": { "last4": "6789", "routing_number": "110000000" },
+        "amount": 1000,
+        "created": 1591000000,
+        "currency": "usd",
+        "livemode": false,
+        "source": "src_123",
+        "status": "succeeded",
+        "type": "ach_credit_transfer"
+    });
+    let transaction: stripe::SourceTransaction = serde_json::from_value(transaction).unwrap();
+    assert_eq!(transaction.source.as_str(), "src_123");
+    assert_eq!(transaction.type_, stripe::SourceType::AchCreditTransfer);
+    assert_eq!(transaction.ach_credit_transfer.unwrap().last4.as_deref(), Some("6789"));
+}
+
+#[test]
+fn serialize_payment_intent_card_feature_requests() {
+    use stripe::{CardFeatureRequest, CreatePaymentIntent, Currency};
+
+    let mut params = CreatePaymentIntent::new(1000, Currency::USD);
+    let card = stripe::CreatePaymentIntentPaymentMethodOptionsCard {
+        request_incremental_authorization: Some(CardFeatureRequest::IfAvailable),
+        request_multicapture: Some(CardFeatureRequest::IfAvailable),
+        ..Default::default()
+    };
+    params.payment_method_options = Some(stripe::CreatePaymentIntentPaymentMethodOptions {
+        card: Some(card),
+        ..Default::default()
+    });
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert!(encoded.contains(
+        "payment_method_options[card][request_incremental_authorization]=if_available\
+         &payment_method_options[card][request_multicapture]=if_available"
+    ));
+
+    let params = stripe::IncrementAuthorizationPaymentIntent::new(1500);
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "amount=1500");
+}
+
+#[test]
+fn serialize_payment_intent_customer_balance_options() {
+    use stripe::{CustomerBalanceBankTransferParams, CustomerBalanceBankTransferType};
+
+    let mut bank_transfer =
+        CustomerBalanceBankTransferParams::new(CustomerBalanceBankTransferType::EuBankTransfer);
+    bank_transfer.eu_bank_transfer =
+        Some(stripe::CustomerBalanceEuBankTransferParams { country: "NL".into() });
+    let options = stripe::CreatePaymentIntentPaymentMethodOptions {
+        customer_balance: Some(stripe::CreatePaymentIntentPaymentMethodOptionsCustomerBalance {
+            bank_transfer: Some(bank_transfer),
+            funding_type: Some(stripe::CustomerBalanceFundingType::BankTransfer),
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(serde_qs::to_string(&options).unwrap()),
+        "customer_balance[bank_transfer][eu_bank_transfer][country]=NL\
+         &customer_balance[bank_transfer][type]=eu_bank_transfer\
+         &customer_balance[funding_type]=bank_transfer"
+    );
+}
+
+#[test]
+fn serialize_payment_intent_automatic_payment_methods() {
+    use stripe::{
+        AutomaticPaymentMethodsAllowRedirects, CreatePaymentIntent,
+        CreatePaymentIntentAutomaticPaymentMethods, Currency, PaymentIntentConfirmParams,
+    };
+
+    let mut params = CreatePaymentIntent::new(1000, Currency::USD);
+    params.automatic_payment_methods = Some(CreatePaymentIntentAutomaticPaymentMethods {
+        enabled: true,
+        allow_redirects: Some(AutomaticPaymentMethodsAllowRedirects::Never),
+    });
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert!(encoded.contains(
+        "automatic_payment_methods[enabled]=true&automatic_payment_methods[allow_redirects]=never"
+    ));
+
+    let params = PaymentIntentConfirmParams {
+        payment_method_options: Some(stripe::CreatePaymentIntentPaymentMethodOptions {
+            us_bank_account: Some(stripe::CreatePaymentIntentPaymentMethodOptionsUsBankAccount {
+                verification_method: Some(stripe::UsBankAccountVerificationMethod::Instant),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "payment_method_options[us_bank_account][verification_method]=instant"
+    );
+}
+
+#[test]
+fn deserialize_next_action() {
+    use stripe::{PaymentIntentNextAction, SetupIntentNextAction};
+
+    let action: PaymentIntentNextAction = serde_json::from_value(json!({
+        "type": "redirect_to_url",
+        "redirect_to_url": { "return_url": "https://example.com", "url": "https://hooks.stripe.com" }
+    }))
+    .unwrap();
+    match action {
+        PaymentIntentNextAction::RedirectToUrl { redirect_to_url } => {
+            assert_eq!(redirect_to_url.url.as_deref(), Some("https://hooks.stripe.com"))
+        }
+        other => panic!("unexpected next action {:?}", other),
+    }
+
+    let action: PaymentIntentNextAction = serde_json::from_value(json!({
+        "type": "display_bank_transfer_instructions",
+        "display_bank_transfer_instructions": {
+            "amount_remaining": 1000,
+            "currency": "eur",
+            "financial_addresses": [{
+                "iban": {
+                    "account_holder_name": "Stripe",
+                    "bic": "SOGEDEFFXXX",
+                    "country": "DE",
+                    "iban": "DE00000000000000000000"
+                },
+                "supported_networks": ["sepa"],
+                "type": "iban"
+            }],
+            "hosted_instructions_url": null,
+            "reference": "REF123",
+            "type": "eu_bank_transfer"
+        }
+    }))
+    .unwrap();
+    match action {
+        PaymentIntentNextAction::DisplayBankTransferInstructions {
+            display_bank_transfer_instructions: instructions,
+        } => {
+            assert_eq!(instructions.type_, stripe::CustomerBalanceBankTransferType::EuBankTransfer);
+            assert_eq!(
+                instructions.financial_addresses[0].iban.as_ref().unwrap().bic,
+                "SOGEDEFFXXX"
+            );
+        }
+        other => panic!("unexpected next action {:?}", other),
+    }
+
+    let action: SetupIntentNextAction = serde_json::from_value(json!({
+        "type": "verify_with_microdeposits",
+        "verify_with_microdeposits": {
+            "arrival_date": 1700000000,
+            "hosted_verification_url": "https://payments.stripe.com/microdeposit",
+            "microdeposit_type": "descriptor_code"
+        }
+    }))
+    .unwrap();
+    match action {
+        SetupIntentNextAction::VerifyWithMicrodeposits { verify_with_microdeposits } => assert_eq!(
+            verify_with_microdeposits.microdeposit_type,
+            Some(stripe::MicrodepositType::DescriptorCode)
+        ),
+        other => panic!("unexpected next action {:?}", other),
+    }
+
+    let action: PaymentIntentNextAction =
+        serde_json::from_value(json!({ "type": "swish_handle_redirect_or_display_qr_code" }))
+            .unwrap();
+    assert!(matches!(action, PaymentIntentNextAction::Other));
+}
+
+#[test]
+fn serialize_payment_intent_off_session_confirmation() {
+    use stripe::{PaymentIntentConfirmParams, PaymentIntentMandateData, PaymentIntentOffSession};
+
+    let params = PaymentIntentConfirmParams {
+        error_on_requires_action: Some(true),
+        mandate_data: Some(PaymentIntentMandateData::online("127.0.0.1", "Mozilla/5.0")),
+        off_session: Some(PaymentIntentOffSession::exists(true)),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "error_on_requires_action=true\
+         &mandate_data[customer_acceptance][online][ip_address]=127.0.0.1\
+         &mandate_data[customer_acceptance][online][user_agent]=Mozilla%2F5.0\
+         &mandate_data[customer_acceptance][type]=online\
+         &off_session=true"
+    );
+}
+
+#[test]
+fn serialize_refund_connect_flags() {
+    let params = stripe::CreateRefund {
+        instructions_email: Some("jenny@example.com"),
+        refund_application_fee: Some(true),
+        reverse_transfer: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "instructions_email=jenny%40example.com&refund_application_fee=true&reverse_transfer=true"
+    );
+
+    let refund: stripe::Refund = serde_json::from_value(json!({
+        "id": "re_123",
+        "object": "refund",
+        "amount": 1000,
+        "created": 1700000000,
+        "currency": "usd",
+        "destination_details": {
+            "card": {
+                "reference": "123456789012",
+                "reference_status": "available",
+                "reference_type": "acquirer_reference_number",
+                "type": "refund"
+            },
+            "type": "card"
+        },
+        "metadata": {}
+    }))
+    .unwrap();
+    let card = refund.destination_details.unwrap().card.unwrap();
+    assert_eq!(card.type_, stripe::RefundDestinationDetailsCardType::Refund);
+    assert_eq!(card.reference.as_deref(), Some("123456789012"));
+}
+
+#[test]
+fn statement_descriptor_rules() {
+    use stripe::{CreatePaymentIntent, Currency, StatementDescriptor, StatementDescriptorError};
+
+    assert_eq!(StatementDescriptor::new("ACME"), Err(StatementDescriptorError::TooShort(4)));
+    assert_eq!(
+        StatementDescriptor::new("ACME WIDGETS INTERNATIONAL"),
+        Err(StatementDescriptorError::TooLong(26))
+    );
+    assert_eq!(
+        StatementDescriptor::new("ACME <WIDGETS>"),
+        Err(StatementDescriptorError::InvalidCharacter('<'))
+    );
+    assert_eq!(StatementDescriptor::new("12345"), Err(StatementDescriptorError::NoLetters));
+    assert_eq!(StatementDescriptor::suffix("A").unwrap().as_str(), "A");
+
+    let mut params = CreatePaymentIntent::new(1000, Currency::USD);
+    params.statement_descriptor = Some("ACME WIDGETS".parse().unwrap());
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert!(encoded.contains("statement_descriptor=ACME+WIDGETS"), "{}", encoded);
+}
+
+#[test]
+fn money_minor_units() {
+    use stripe::{CreatePaymentIntent, Currency, Money};
+
+    assert_eq!(Money::from_major(10, Currency::USD).unwrap().amount_minor, 1000);
+    assert_eq!(Money::from_major(1000, Currency::JPY).unwrap().amount_minor, 1000);
+    assert_eq!(Money::from_major(i64::MAX, Currency::USD), None);
+    assert_eq!(Money::from_major(10, Currency::Other), None);
+    assert_eq!(Currency::Other.decimal_places(), None);
+    assert!(Money::from_decimal_str("12.34", Currency::Other).is_err());
+    assert_eq!(Money::from_decimal_str("12.3", Currency::EUR).unwrap().amount_minor, 1230);
+    assert_eq!(Money::from_decimal_str("-0.05", Currency::USD).unwrap().amount_minor, -5);
+    assert!(Money::from_decimal_str("1000.5", Currency::JPY).is_err());
+    assert!(Money::from_decimal_str("1.234", Currency::USD).is_err());
+    assert!(Money::from_decimal_str(".5", Currency::USD).is_err());
+    assert_eq!(Money::new(1234, Currency::USD).to_string(), "12.34 usd");
+    assert_eq!(Money::new(-5, Currency::USD).to_string(), "-0.05 usd");
+    assert_eq!(Money::new(1234, Currency::KRW).to_string(), "1234 krw");
+    assert_eq!(Money::new(1234, Currency::KRW).major_amount(), Some(1234));
+    assert_eq!(Money::new(1234, Currency::Other).major_amount(), None);
+
+    let params = CreatePaymentIntent::from_money(Money::from_major(500, Currency::JPY).unwrap());
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert!(encoded.contains("amount=500&currency=jpy"), "{}", encoded);
+}
+
+#[test]
+fn deserialize_card_error_codes() {
+    use stripe::{DeclineCode, ErrorCode, ErrorType, RequestError};
+
+    let error: RequestError = serde_json::from_value(json!({
+        "type": "card_error",
+        "code": "card_declined",
+        "decline_code": "insufficient_funds",
+        "message": "Your card has insufficient funds.",
+        "charge": "ch_123"
+    }))
+    .unwrap();
+    assert_eq!(error.error_type, ErrorType::Card);
+    assert_eq!(error.code, Some(ErrorCode::CardDeclined));
+    assert_eq!(error.decline_code, Some(DeclineCode::InsufficientFunds));
+    assert_eq!(DeclineCode::DoNotHonor.to_string(), "do_not_honor");
+    assert_eq!(
+        serde_json::from_str::<DeclineCode>("\"some_new_reason\"").unwrap(),
+        DeclineCode::Other
+    );
+}
+
+#[test]
+fn deserialize_balance_transaction_fee_details() {
+    use stripe::{BalanceTransaction, BalanceTransactionType, FeeType, ListBalanceTransactions};
+
+    let transaction: BalanceTransaction = serde_json::from_value(json!({
+        "id": "txn_123",
+        "object": "balance_transaction",
+        "amount": 10000,
+        "available_on": 1700000000,
+        "created": 1700000000,
+        "currency": "usd",
+        "fee": 620,
+        "fee_details": [
+            { "amount": 320, "currency": "usd", "type": "stripe_fee" },
+            { "amount": 200, "application": "ca_123", "currency": "usd", "type": "application_fee" },
+            { "amount": 100, "currency": "usd", "type": "stripe_fee" }
+        ],
+        "net": 9380,
+        "reporting_category": "charge",
+        "status": "pending",
+        "type": "payment_network_reserve_hold"
+    }))
+    .unwrap();
+    assert_eq!(transaction.type_, BalanceTransactionType::PaymentNetworkReserveHold);
+    assert_eq!(transaction.fee_for(FeeType::StripeFee), 420);
+    assert_eq!(transaction.fee_for(FeeType::ApplicationFee), 200);
+    assert_eq!(transaction.fee_for(FeeType::Tax), 0);
+    assert_eq!(transaction.net_from_fee_details(), transaction.net);
+    assert_eq!(transaction.to_original_currency(transaction.amount), None);
+
+    let params = ListBalanceTransactions {
+        type_: Some(BalanceTransactionType::StripeFxFee),
+        ..Default::default()
+    };
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "type=stripe_fx_fee");
+}
+
+#[test]
+fn serialize_invoice_rendering() {
+    use stripe::{
+        CreateInvoiceAutomaticTax, CreateInvoiceRendering, CreateInvoiceRenderingPdf, CustomField,
+        InvoiceAmountTaxDisplay, InvoiceRenderingPdfPageSize, UpdateInvoice,
+    };
+
+    let params = UpdateInvoice {
+        account_tax_ids: Some(vec!["txi_123".parse().unwrap()]),
+        automatic_tax: Some(CreateInvoiceAutomaticTax { enabled: true }),
+        custom_fields: Some(vec![CustomField { name: "PO".into(), value: "1234".into() }]),
+        footer: Some("Thanks"),
+        rendering: Some(CreateInvoiceRendering {
+            amount_tax_display: Some(InvoiceAmountTaxDisplay::IncludeInclusiveTax),
+            pdf: Some(CreateInvoiceRenderingPdf {
+                page_size: Some(InvoiceRenderingPdfPageSize::A4),
+            }),
+            template: Some("inrtem_123".parse().unwrap()),
+            template_version: Some(2),
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "account_tax_ids[0]=txi_123\
+         &automatic_tax[enabled]=true\
+         &custom_fields[0][name]=PO&custom_fields[0][value]=1234\
+         &footer=Thanks\
+         &rendering[amount_tax_display]=include_inclusive_tax\
+         &rendering[pdf][page_size]=a4\
+         &rendering[template]=inrtem_123\
+         &rendering[template_version]=2"
+    );
+}
+
+#[test]
+fn serialize_subscription_trial() {
+    use stripe::{
+        CreateSubscription, CreateSubscriptionTrialSettings,
+        CreateSubscriptionTrialSettingsEndBehavior,
+        CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod, Scheduled,
+        UpdateSubscription,
+    };
+
+    let mut params = CreateSubscription::new("cus_123".parse().unwrap());
+    params.trial_period_days = Some(14);
+    params.trial_settings = Some(CreateSubscriptionTrialSettings {
+        end_behavior: CreateSubscriptionTrialSettingsEndBehavior {
+            missing_payment_method:
+                CreateSubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Pause,
+        },
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "customer=cus_123\
+         &trial_period_days=14\
+         &trial_settings[end_behavior][missing_payment_method]=pause"
+    );
+
+    let params = UpdateSubscription { trial_end: Some(Scheduled::now()), ..Default::default() };
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "trial_end=now");
+    let params = UpdateSubscription {
+        trial_end: Some(Scheduled::at(1_700_000_000.into())),
+        ..Default::default()
+    };
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "trial_end=1700000000");
+}
+
+#[test]
+fn serialize_subscription_payment_settings() {
+    use stripe::{
+        CollectionMethod, CreateSubscription, CreateSubscriptionPaymentSettings,
+        SubscriptionPaymentMethodType, SubscriptionSaveDefaultPaymentMethod,
+    };
+
+    let mut params = CreateSubscription::new("cus_123".parse().unwrap());
+    params.collection_method = Some(CollectionMethod::SendInvoice);
+    params.days_until_due = Some(30);
+    params.payment_settings = Some(CreateSubscriptionPaymentSettings {
+        payment_method_types: Some(vec![
+            SubscriptionPaymentMethodType::UsBankAccount,
+            SubscriptionPaymentMethodType::Card,
+        ]),
+        save_default_payment_method: Some(SubscriptionSaveDefaultPaymentMethod::OnSubscription),
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "collection_method=send_invoice\
+         &customer=cus_123\
+         &days_until_due=30\
+         &payment_settings[payment_method_types][0]=us_bank_account\
+         &payment_settings[payment_method_types][1]=card\
+         &payment_settings[save_default_payment_method]=on_subscription"
+    );
+}
+
+#[test]
+fn serialize_billing_meter_params() {
+    use stripe::{
+        BillingMeterCustomerMapping, BillingMeterCustomerMappingType,
+        BillingMeterDefaultAggregation, BillingMeterDefaultAggregationFormula, CreateBillingMeter,
+        CreateBillingMeterEvent, CreateBillingMeterEventAdjustment,
+    };
+
+    let mut params = CreateBillingMeter::new(
+        BillingMeterDefaultAggregation { formula: BillingMeterDefaultAggregationFormula::Sum },
+        "API calls",
+        "api_call",
+    );
+    params.customer_mapping = Some(BillingMeterCustomerMapping {
+        event_payload_key: "stripe_customer_id".into(),
+        type_: BillingMeterCustomerMappingType::ById,
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "customer_mapping[event_payload_key]=stripe_customer_id&customer_mapping[type]=by_id\
+         &default_aggregation[formula]=sum&display_name=API+calls&event_name=api_call"
+    );
+
+    let payload = vec![("value".to_string(), "25".to_string())].into_iter().collect();
+    let mut params = CreateBillingMeterEvent::new("api_call", payload);
+    params.identifier = Some("req_123");
+    params.timestamp = Some(1_700_000_000.into());
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "event_name=api_call&identifier=req_123&payload[value]=25&timestamp=1700000000"
+    );
+
+    let params = CreateBillingMeterEventAdjustment::cancel("api_call", "req_123");
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "cancel[identifier]=req_123&event_name=api_call&type=cancel"
+    );
+}
+
+#[test]
+fn serialize_billing_alert_params() {
+    use stripe::{
+        BillingAlertFilterType, BillingAlertRecurrence, BillingAlertType, CreateBillingAlert,
+        CreateBillingAlertUsageThreshold, CreateBillingAlertUsageThresholdFilters,
+    };
+
+    let mut params = CreateBillingAlert::new(BillingAlertType::UsageThreshold, "Spend");
+    params.usage_threshold = Some(CreateBillingAlertUsageThreshold {
+        filters: Some(vec![CreateBillingAlertUsageThresholdFilters {
+            customer: Some("cus_123".parse().unwrap()),
+            type_: BillingAlertFilterType::Customer,
+        }]),
+        gte: 1000,
+        meter: Some("mtr_123".parse().unwrap()),
+        recurrence: BillingAlertRecurrence::OneTime,
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "alert_type=usage_threshold&title=Spend\
+         &usage_threshold[filters][0][customer]=cus_123&usage_threshold[filters][0][type]=customer\
+         &usage_threshold[gte]=1000&usage_threshold[meter]=mtr_123\
+         &usage_threshold[recurrence]=one_time"
+    );
+}
+
+#[test]
+fn serialize_forwarding_request() {
+    use stripe::{
+        CreateForwardingRequest, CreateForwardingRequestRequest, ForwardingRequestHeader,
+        ForwardingRequestReplacement,
+    };
+
+    let mut params = CreateForwardingRequest::new(
+        "pm_123".parse().unwrap(),
+        vec![ForwardingRequestReplacement::CardNumber, ForwardingRequestReplacement::CardCvc],
+        "https://example.com/pay",
+    );
+    params.request = Some(CreateForwardingRequestRequest {
+        body: Some(r#"{"amount":100}"#.into()),
+        headers: Some(vec![ForwardingRequestHeader {
+            name: "Destination-Header".into(),
+            value: "value".into(),
+        }]),
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "payment_method=pm_123\
+         &replacements[0]=card_number&replacements[1]=card_cvc\
+         &request[body]=%7B%22amount%22%3A100%7D\
+         &request[headers][0][name]=Destination-Header&request[headers][0][value]=value\
+         &url=https%3A%2F%2Fexample.com%2Fpay"
+    );
+}
+
+#[test]
+fn account_requirements_summary() {
+    use stripe::{Account, AccountDisabledReason};
+
+    let account: Account = serde_json::from_value(json!({
+        "id": "acct_123",
+        "object": "account",
+        "requirements": {
+            "currently_due": ["external_account"],
+            "disabled_reason": "requirements.past_due",
+            "past_due": ["external_account"],
+        },
+    }))
+    .unwrap();
+    let summary = account.requirements_summary();
+    assert_eq!(summary.currently_due, vec!["external_account"]);
+    assert!(summary.eventually_due.is_empty());
+    assert_eq!(summary.disabled_reason, Some(AccountDisabledReason::RequirementsPastDue));
+    assert!(!summary.is_complete());
+
+    let account: Account =
+        serde_json::from_value(json!({"id": "acct_123", "object": "account"})).unwrap();
+    assert!(account.requirements_summary().is_complete());
+    assert_eq!(AccountDisabledReason::from("something_new"), AccountDisabledReason::Other);
+}
+
+#[test]
+fn deserialize_checkout_session_status() {
+    use stripe::{CheckoutSession, CheckoutSessionPaymentStatus, CheckoutSessionStatus, Currency};
+
+    let session: CheckoutSession = serde_json::from_str(
+        r#"{
+            "id": "cs_test_123",
+            "object": "checkout.session",
+            "amount_subtotal": 2000,
+            "amount_total": 2200,
+            "currency": "usd",
+            "livemode": false,
+            "payment_method_types": ["card"],
+            "payment_status": "paid",
+            "status": "complete",
+            "total_details": {"amount_discount": 0, "amount_shipping": 0, "amount_tax": 200}
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(session.status, Some(CheckoutSessionStatus::Complete));
+    assert_eq!(session.payment_status, Some(CheckoutSessionPaymentStatus::Paid));
+    assert_eq!(session.currency, Some(Currency::USD));
+    assert_eq!(session.amount_total, Some(2200));
+    assert_eq!(session.total_details.unwrap().amount_tax, 200);
+}
+
+#[test]
+fn serialize_params_builder() {
+    use stripe::{
+        CheckoutSessionMode, CheckoutSessionPaymentMethodType, CollectionMethod,
+        CreateCheckoutSession, CreateSubscription, CreateSubscriptionItems,
+    };
+
+    let mut item = CreateSubscriptionItems::new();
+    item.price = Some("price_123".into());
+    let params = CreateSubscription::new("cus_123".parse().unwrap())
+        .collection_method(CollectionMethod::SendInvoice)
+        .days_until_due(30)
+        .items(vec![item]);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "collection_method=send_invoice&customer=cus_123&days_until_due=30&items[0][price]=price_123"
+    );
+
+    let params = CreateCheckoutSession::new()
+        .mode(CheckoutSessionMode::Payment)
+        .success_url("https://example.com/success")
+        .payment_method_types(vec![CheckoutSessionPaymentMethodType::Card])
+        .expand(&["line_items"]);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "expand[0]=line_items&payment_method_types[0]=card\
+         &success_url=https%3A%2F%2Fexample.com%2Fsuccess&mode=payment"
+    );
+}
+
+#[test]
+fn serialize_owned_checkout_session_params() {
+    use stripe::{CheckoutSessionMode, CreateCheckoutSession};
+
+    fn build(order: u64) -> CreateCheckoutSession<'static> {
+        CreateCheckoutSession::new()
+            .mode(CheckoutSessionMode::Payment)
+            .client_reference_id(format!("order_{}", order))
+            .success_url(format!("https://example.com/orders/{}", order))
+    }
+
+    let params = std::thread::spawn(|| build(42)).join().unwrap();
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "success_url=https%3A%2F%2Fexample.com%2Forders%2F42&client_reference_id=order_42&mode=payment"
+    );
+}
+
+#[test]
+fn serialize_partially_initialized_params() {
+    use stripe::{
+        CreateTokenPerson, ListPrices, ListPricesRecurring, ListPricesRecurringInterval,
+        UpdateSubscriptionItems,
+    };
+
+    let params = ListPrices {
+        recurring: Some(ListPricesRecurring {
+            interval: Some(ListPricesRecurringInterval::Month),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "recurring[interval]=month");
+
+    let item = UpdateSubscriptionItems {
+        id: Some("si_123".into()),
+        deleted: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(urldecode(serde_qs::to_string(&item).unwrap()), "deleted=true&id=si_123");
+
+    let person = CreateTokenPerson { first_name: Some("Jenny".into()), ..Default::default() };
+    assert_eq!(urldecode(serde_qs::to_string(&person).unwrap()), "first_name=Jenny");
+}
+
+#[test]
+fn deserialize_cash_balance() {
+    use stripe::{
+        CashBalance, CashBalanceSettingsReconciliationMode, Currency,
+        CustomerCashBalanceTransaction, CustomerCashBalanceTransactionType,
+    };
+
+    let balance: CashBalance = serde_json::from_str(
+        r#"{
+            "object": "cash_balance",
+            "available": {"eur": 10000},
+            "customer": "cus_123",
+            "livemode": false,
+            "settings": {"reconciliation_mode": "automatic", "using_merchant_default": true}
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(balance.available.unwrap()[&Currency::EUR], 10000);
+    assert_eq!(
+        balance.settings.reconciliation_mode,
+        CashBalanceSettingsReconciliationMode::Automatic
+    );
+
+    let transaction: CustomerCashBalanceTransaction = serde_json::from_str(
+        r#"{
+            "id": "ccsbtxn_123",
+            "object": "customer_cash_balance_transaction",
+            "created": 1680000000,
+            "currency": "eur",
+            "customer": "cus_123",
+            "ending_balance": 10000,
+            "funded": {"bank_transfer": {"reference": "ORDER-42", "type": "eu_bank_transfer"}},
+            "livemode": false,
+            "net_amount": 5000,
+            "type": "funded"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(transaction.type_, CustomerCashBalanceTransactionType::Funded);
+    assert_eq!(transaction.funded.unwrap().bank_transfer.reference.as_deref(), Some("ORDER-42"));
+}
+
+#[test]
+fn serialize_list_events_filters() {
+    use stripe::{EventType, ListEvents};
+
+    let mut params = ListEvents::new();
+    params.delivery_success = Some(false);
+    params.types = Some(vec![EventType::ChargeSucceeded, EventType::ChargeFailed]);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "delivery_success=false&types[0]=charge.succeeded&types[1]=charge.failed"
+    );
+
+    let mut params = ListEvents::new();
+    params.type_ = Some("invoice.*");
+    assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "type=invoice.*");
+}
+
+#[test]
+fn deserialize_event_destination_list() {
+    use stripe::{EventDestination, EventDestinationStatus, EventDestinationType, V2List};
+
+    let list: V2List<EventDestination> = serde_json::from_str(
+        r#"{
+            "data": [{
+                "id": "ed_123",
+                "object": "v2.core.event_destination",
+                "created": "2024-10-22T16:20:09.931Z",
+                "description": "",
+                "enabled_events": ["v1.billing.meter.error_report_triggered"],
+                "event_payload": "thin",
+                "events_from": ["self"],
+                "livemode": false,
+                "metadata": {},
+                "name": "meters",
+                "snapshot_api_version": null,
+                "status": "enabled",
+                "status_details": null,
+                "type": "webhook_endpoint",
+                "updated": "2024-10-22T16:20:09.937Z",
+                "webhook_endpoint": {"signing_secret": null, "url": null}
+            }],
+            "next_page_url": "/v2/core/event_destinations?page=page_2",
+            "previous_page_url": null
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(list.data[0].status, EventDestinationStatus::Enabled);
+    assert_eq!(list.data[0].type_, EventDestinationType::WebhookEndpoint);
+    assert_eq!(list.next_page_url.as_deref(), Some("/v2/core/event_destinations?page=page_2"));
+}
+
+#[test]
+fn balance_transaction_currency_conversion() {
+    use stripe::{BalanceTransaction, FeeType};
+
+    // A charge of 10.00 EUR, settled as 12.34 USD.
+    let transaction: BalanceTransaction = serde_json::from_value(json!({
+        "id": "txn_123",
+        "object": "balance_transaction",
+        "amount": 1234,
+        "available_on": 1700000000,
+        "created": 1700000000,
+        "currency": "usd",
+        "exchange_rate": 1.234,
+        "fee": 66,
+        "fee_details": [
+            { "amount": 66, "currency": "usd", "type": "stripe_fee" }
+        ],
+        "net": 1168,
+        "reporting_category": "charge",
+        "status": "available",
+        "type": "charge"
+    }))
+    .unwrap();
+    assert_eq!(transaction.net_from_fee_details(), 1168);
+    assert_eq!(transaction.to_original_currency(transaction.amount), Some(1000));
+    assert_eq!(transaction.to_original_currency(transaction.fee_for(FeeType::StripeFee)), Some(53));
+    assert_eq!(transaction.to_original_currency(transaction.net), Some(947));
+}
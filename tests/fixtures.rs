@@ -0,0 +1,221 @@
+//! Roundtrips the example objects in `tests/fixtures` through the resource types.
+//!
+//! Each fixture is an object as shown in Stripe's API reference. It must deserialize, and
+//! every field the library serializes back out must match the fixture, so a renamed field,
+//! a misspelled enum variant or a mistyped value fails here instead of in production.
+//! Fields the library doesn't model yet are ignored.
+//!
+//! The fixtures are copied by hand and only cover the resources listed below, one example
+//! object each, so they catch drift in the fields those examples happen to include and
+//! nothing else. Add a fixture when changing a resource that isn't covered.
+//!
+//! `spec_fixtures` checks every object in the spec's `fixtures3.json` the same way, but the
+//! file isn't vendored yet, so it's ignored until it's copied to `openapi/fixtures3.json`.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+fn roundtrip<T: DeserializeOwned + Serialize>(name: &str) {
+    let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    let raw = std::fs::read_to_string(&path).unwrap();
+    let fixture: Value = serde_json::from_str(&raw).unwrap();
+    let mismatches = check::<T>(&fixture, name);
+    assert!(mismatches.is_empty(), "`{}` did not roundtrip:\n{}", name, mismatches.join("\n"));
+}
+
+/// Deserializes `fixture` as a `T` and lists the ways serializing it again doesn't match.
+fn check<T: DeserializeOwned + Serialize>(fixture: &Value, name: &str) -> Vec<String> {
+    let object: T = match serde_json::from_value(fixture.clone()) {
+        Ok(object) => object,
+        Err(err) => return vec![format!("{}: failed to deserialize: {}", name, err)],
+    };
+    let output = serde_json::to_value(&object).unwrap();
+    mismatches(fixture, &output, name)
+}
+
+/// Lists the paths at which `output` has a value that differs from `fixture`.
+fn mismatches(fixture: &Value, output: &Value, path: &str) -> Vec<String> {
+    match (fixture, output) {
+        (Value::Object(fixture), Value::Object(output)) => output
+            .iter()
+            .flat_map(|(key, value)| {
+                let path = format!("{}.{}", path, key);
+                match fixture.get(key) {
+                    Some(expected) => mismatches(expected, value, &path),
+                    None if is_default(value) => vec![],
+                    None => vec![format!("{}: not in fixture, got {}", path, value)],
+                }
+            })
+            .collect(),
+        (Value::Array(fixture), Value::Array(output)) if fixture.len() == output.len() => fixture
+            .iter()
+            .zip(output)
+            .enumerate()
+            .flat_map(|(i, (expected, value))| {
+                mismatches(expected, value, &format!("{}[{}]", path, i))
+            })
+            .collect(),
+        (Value::Number(expected), Value::Number(value)) if expected.as_f64() == value.as_f64() => {
+            vec![]
+        }
+        (expected, value) if expected == value => vec![],
+        (expected, value) => vec![format!("{}: expected {}, got {}", path, expected, value)],
+    }
+}
+
+/// Whether `value` is what a field defaults to when it's missing from the payload.
+fn is_default(value: &Value) -> bool {
+    match value {
+        Value::Null | Value::Bool(false) => true,
+        Value::Array(items) => items.is_empty(),
+        Value::Object(list) => matches!(list.get("data"), Some(data) if is_default(data)),
+        _ => false,
+    }
+}
+
+macro_rules! fixtures {
+    ($($name:ident: $ty:ty,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                roundtrip::<$ty>(stringify!($name));
+            }
+        )*
+    };
+}
+
+fixtures! {
+    balance_transaction: stripe::BalanceTransaction,
+    charge: stripe::Charge,
+    checkout_session: stripe::CheckoutSession,
+    coupon: stripe::Coupon,
+    customer: stripe::Customer,
+    event: stripe::Event,
+    invoice: stripe::Invoice,
+    payment_intent: stripe::PaymentIntent,
+    payment_method: stripe::PaymentMethod,
+    price: stripe::Price,
+    product: stripe::Product,
+    refund: stripe::Refund,
+    setup_intent: stripe::SetupIntent,
+    subscription: stripe::Subscription,
+}
+
+macro_rules! spec_objects {
+    ($($object:literal: $ty:ty,)*) => {
+        /// Checks `fixture` as the resource its `object` names, or `None` if there's no such type.
+        fn check_object(fixture: &Value, name: &str) -> Option<Vec<String>> {
+            match fixture["object"].as_str()? {
+                $($object => Some(check::<$ty>(fixture, name)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+spec_objects! {
+    "account": stripe::Account,
+    "account_link": stripe::AccountLink,
+    "account_session": stripe::AccountSession,
+    "alipay_account": stripe::AlipayAccount,
+    "apple_pay_domain": stripe::ApplePayDomain,
+    "application": stripe::Application,
+    "application_fee": stripe::ApplicationFee,
+    "balance": stripe::Balance,
+    "balance_transaction": stripe::BalanceTransaction,
+    "bank_account": stripe::BankAccount,
+    "billing.alert": stripe::BillingAlert,
+    "billing.meter": stripe::BillingMeter,
+    "billing.meter_event_summary": stripe::BillingMeterEventSummary,
+    "billing_portal.session": stripe::BillingPortalSession,
+    "capability": stripe::Capability,
+    "card": stripe::Card,
+    "cash_balance": stripe::CashBalance,
+    "charge": stripe::Charge,
+    "checkout.session": stripe::CheckoutSession,
+    "confirmation_token": stripe::ConfirmationToken,
+    "connect_collection_transfer": stripe::ConnectCollectionTransfer,
+    "country_spec": stripe::CountrySpec,
+    "coupon": stripe::Coupon,
+    "customer": stripe::Customer,
+    "customer_cash_balance_transaction": stripe::CustomerCashBalanceTransaction,
+    "customer_session": stripe::CustomerSession,
+    "discount": stripe::Discount,
+    "dispute": stripe::Dispute,
+    "entitlements.active_entitlement": stripe::EntitlementsActiveEntitlement,
+    "entitlements.feature": stripe::EntitlementsFeature,
+    "event": stripe::Event,
+    "exchange_rate": stripe::ExchangeRate,
+    "fee_refund": stripe::ApplicationFeeRefund,
+    "file": stripe::File,
+    "file_link": stripe::FileLink,
+    "forwarding.request": stripe::ForwardingRequest,
+    "invoice": stripe::Invoice,
+    "invoice_rendering_template": stripe::InvoiceRenderingTemplate,
+    "invoiceitem": stripe::InvoiceItem,
+    "issuing.authorization": stripe::IssuingAuthorization,
+    "issuing.card": stripe::IssuingCard,
+    "issuing.cardholder": stripe::IssuingCardholder,
+    "issuing.dispute": stripe::IssuingDispute,
+    "issuing.transaction": stripe::IssuingTransaction,
+    "item": stripe::CheckoutSessionItem,
+    "line_item": stripe::InvoiceLineItem,
+    "login_link": stripe::LoginLink,
+    "mandate": stripe::Mandate,
+    "order": stripe::Order,
+    "order_item": stripe::OrderItem,
+    "order_return": stripe::OrderReturn,
+    "payment_intent": stripe::PaymentIntent,
+    "payment_method": stripe::PaymentMethod,
+    "payment_method_configuration": stripe::PaymentMethodConfiguration,
+    "payment_method_domain": stripe::PaymentMethodDomain,
+    "payout": stripe::Payout,
+    "person": stripe::Person,
+    "plan": stripe::Plan,
+    "platform_tax_fee": stripe::PlatformTaxFee,
+    "price": stripe::Price,
+    "product": stripe::Product,
+    "product_feature": stripe::ProductFeature,
+    "recipient": stripe::Recipient,
+    "refund": stripe::Refund,
+    "reserve_transaction": stripe::ReserveTransaction,
+    "review": stripe::Review,
+    "scheduled_query_run": stripe::ScheduledQueryRun,
+    "setup_attempt": stripe::SetupAttempt,
+    "setup_intent": stripe::SetupIntent,
+    "shipping_rate": stripe::ShippingRate,
+    "sku": stripe::Sku,
+    "source": stripe::Source,
+    "source_transaction": stripe::SourceTransaction,
+    "subscription": stripe::Subscription,
+    "subscription_item": stripe::SubscriptionItem,
+    "subscription_schedule": stripe::SubscriptionSchedule,
+    "tax.registration": stripe::TaxRegistration,
+    "tax.settings": stripe::TaxSettings,
+    "tax_deducted_at_source": stripe::TaxDeductedAtSource,
+    "tax_id": stripe::TaxId,
+    "tax_rate": stripe::TaxRate,
+    "token": stripe::Token,
+    "topup": stripe::Topup,
+    "transfer": stripe::Transfer,
+    "transfer_reversal": stripe::TransferReversal,
+    "v2.core.event": stripe::ThinEvent,
+    "v2.core.event_destination": stripe::EventDestination,
+    "webhook_endpoint": stripe::WebhookEndpoint,
+}
+
+#[test]
+#[ignore = "needs openapi/fixtures3.json"]
+fn spec_fixtures() {
+    let path = format!("{}/openapi/fixtures3.json", env!("CARGO_MANIFEST_DIR"));
+    let raw = std::fs::read_to_string(&path).unwrap();
+    let fixtures: Value = serde_json::from_str(&raw).unwrap();
+    let mismatches: Vec<_> = fixtures["resources"]
+        .as_object()
+        .unwrap()
+        .iter()
+        .flat_map(|(name, fixture)| check_object(fixture, name).unwrap_or_default())
+        .collect();
+    assert!(mismatches.is_empty(), "fixtures did not roundtrip:\n{}", mismatches.join("\n"));
+}
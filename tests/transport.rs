@@ -0,0 +1,1110 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A transport which records requests and responds with a canned body instead of calling Stripe.
+///
+/// Requests to a path given a body with `respond` get that body, and any others get a deleted customer.
+#[derive(Clone, Default)]
+struct StubClient {
+    requests: Arc<Mutex<Vec<http::Request<Vec<u8>>>>>,
+    bodies: HashMap<String, serde_json::Value>,
+}
+
+impl StubClient {
+    fn respond(mut self, path: &str, body: serde_json::Value) -> Self {
+        self.bodies.insert(path.to_string(), body);
+        self
+    }
+}
+
+impl stripe::HttpClient for StubClient {
+    fn execute(&self, request: http::Request<Vec<u8>>) -> stripe::HttpResponse {
+        let body = match self.bodies.get(request.uri().path()) {
+            Some(body) => serde_json::to_vec(body).unwrap(),
+            None => br#"{"id": "cus_123", "object": "customer", "deleted": true}"#.to_vec(),
+        };
+        self.requests.lock().unwrap().push(request);
+        let response = http::Response::builder().header("request-id", "req_123").body(body);
+        Box::pin(futures_util::future::ready(Ok(response.unwrap())))
+    }
+}
+
+/// Reads an example object from `tests/fixtures`, to use as a stubbed response body.
+fn fixture(name: &str) -> serde_json::Value {
+    let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn customer_delete_with_stub_transport() {
+    let stub = StubClient::default();
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+    let mut options = stripe::RequestOptions::new();
+    options.stripe_account = Some("acct_123".into());
+
+    let id = "cus_123".parse().unwrap();
+    let deleted = stripe::Customer::delete(&client.with_options(options), &id).unwrap();
+    assert!(deleted.deleted);
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method(), "DELETE");
+    assert_eq!(requests[0].uri(), "http://localhost/v1/customers/cus_123");
+    assert_eq!(requests[0].headers()["authorization"], "Bearer sk_test_123");
+    assert_eq!(requests[0].headers()["stripe-account"], "acct_123");
+}
+
+/// A middleware which tags requests with a header and counts responses.
+#[derive(Clone, Default)]
+struct CountingMiddleware {
+    responses: Arc<Mutex<usize>>,
+}
+
+impl stripe::Middleware for CountingMiddleware {
+    fn on_request(&self, request: &mut http::Request<Vec<u8>>) {
+        request.headers_mut().insert("x-test", http::HeaderValue::from_static("middleware"));
+    }
+
+    fn on_response(&self, _response: &http::Response<Vec<u8>>) {
+        *self.responses.lock().unwrap() += 1;
+    }
+}
+
+#[test]
+fn customer_delete_with_middleware() {
+    let stub = StubClient::default();
+    let middleware = CountingMiddleware::default();
+    let mut client =
+        stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+    client.add_middleware(middleware.clone());
+
+    let id = "cus_123".parse().unwrap();
+    stripe::Customer::delete(&client, &id).unwrap();
+
+    assert_eq!(stub.requests.lock().unwrap()[0].headers()["x-test"], "middleware");
+    assert_eq!(*middleware.responses.lock().unwrap(), 1);
+}
+
+#[test]
+fn customer_delete_reports_telemetry() {
+    let stub = StubClient::default();
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let id = "cus_123".parse().unwrap();
+    stripe::Customer::delete(&client, &id).unwrap();
+    stripe::Customer::delete(&client, &id).unwrap();
+
+    let requests = stub.requests.lock().unwrap();
+    assert!(requests[0].headers().get("x-stripe-client-telemetry").is_none());
+    let telemetry = requests[1].headers()["x-stripe-client-telemetry"].to_str().unwrap();
+    let telemetry: serde_json::Value = serde_json::from_str(telemetry).unwrap();
+    assert_eq!(telemetry["last_request_metrics"]["request_id"], "req_123");
+}
+
+#[test]
+fn customer_delete_without_telemetry() {
+    let stub = StubClient::default();
+    let mut client =
+        stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+    client.set_telemetry(false);
+
+    let id = "cus_123".parse().unwrap();
+    stripe::Customer::delete(&client, &id).unwrap();
+    stripe::Customer::delete(&client, &id).unwrap();
+
+    let requests = stub.requests.lock().unwrap();
+    assert!(requests[1].headers().get("x-stripe-client-telemetry").is_none());
+}
+
+#[test]
+fn customer_delete_with_app_info() {
+    let stub = StubClient::default();
+    let mut client =
+        stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+    client.set_app_info("MyPlugin".into(), Some("1.2.3".into()), None);
+
+    let id = "cus_123".parse().unwrap();
+    stripe::Customer::delete(&client, &id).unwrap();
+
+    let requests = stub.requests.lock().unwrap();
+    let user_agent = requests[0].headers()["user-agent"].to_str().unwrap();
+    assert!(user_agent.ends_with(" MyPlugin/1.2.3"));
+    let client_user_agent = requests[0].headers()["x-stripe-client-user-agent"].to_str().unwrap();
+    let client_user_agent: serde_json::Value = serde_json::from_str(client_user_agent).unwrap();
+    assert_eq!(client_user_agent["lang"], "rust");
+    assert_eq!(client_user_agent["application"]["name"], "MyPlugin");
+    assert_eq!(client_user_agent["application"]["version"], "1.2.3");
+}
+
+#[test]
+fn customer_delete_with_envelope() {
+    let stub = StubClient::default();
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub);
+
+    let id = "cus_123".parse().unwrap();
+    let deleted = client.with_envelope(|client| stripe::Customer::delete(client, &id)).unwrap();
+    assert!(deleted.data.deleted);
+    assert_eq!(deleted.request_id(), Some("req_123"));
+}
+
+#[test]
+fn raw_request_as_json_value() {
+    let stub = StubClient::default();
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let params = serde_json::json!({ "limit": 3 });
+    let value: serde_json::Value = client.get_query("/customers/cus_123", &params).unwrap();
+    assert_eq!(value["id"], "cus_123");
+    assert_eq!(
+        stub.requests.lock().unwrap()[0].uri(),
+        "http://localhost/v1/customers/cus_123?limit=3"
+    );
+}
+
+/// A transport which fails the first request with the given error status.
+#[derive(Clone)]
+struct FlakyClient {
+    status: u16,
+    requests: Arc<Mutex<Vec<http::Request<Vec<u8>>>>>,
+}
+
+impl FlakyClient {
+    fn new(status: u16) -> Self {
+        FlakyClient { status, requests: Default::default() }
+    }
+}
+
+impl stripe::HttpClient for FlakyClient {
+    fn execute(&self, request: http::Request<Vec<u8>>) -> stripe::HttpResponse {
+        let mut requests = self.requests.lock().unwrap();
+        requests.push(request);
+        let response = if requests.len() == 1 {
+            let body = br#"{"error": {"type": "api_error"}}"#.to_vec();
+            http::Response::builder().status(self.status).body(body)
+        } else {
+            let body = br#"{"id": "cus_123", "object": "customer"}"#.to_vec();
+            http::Response::builder().body(body)
+        };
+        Box::pin(futures_util::future::ready(Ok(response.unwrap())))
+    }
+}
+
+#[test]
+fn customer_create_with_retries() {
+    let stub = FlakyClient::new(500);
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let params = stripe::CreateCustomer::new();
+    let err = stripe::Customer::create(&client, params.clone()).unwrap_err();
+    assert!(matches!(err, stripe::Error::Stripe(_)));
+
+    stub.requests.lock().unwrap().clear();
+    let client = client.with_strategy(stripe::RequestStrategy::Idempotent { max_retries: 2 });
+    let customer = stripe::Customer::create(&client, params).unwrap();
+    assert_eq!(customer.id.as_str(), "cus_123");
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    let key = &requests[0].headers()["idempotency-key"];
+    assert_eq!(&requests[1].headers()["idempotency-key"], key);
+}
+
+#[test]
+fn customer_create_with_throttle() {
+    let stub = FlakyClient::new(429);
+    let mut client =
+        stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+    client.set_throttle(Some(4));
+    assert_eq!(client.concurrency_limit(), Some(4));
+
+    let client = client.with_strategy(stripe::RequestStrategy::Idempotent { max_retries: 1 });
+    stripe::Customer::create(&client, stripe::CreateCustomer::new()).unwrap();
+    assert_eq!(client.rate_limited_count(), 1);
+    assert_eq!(client.concurrency_limit(), Some(2));
+
+    // The retried request counts towards the two successes needed to raise the limit.
+    stripe::Customer::create(&client, stripe::CreateCustomer::new()).unwrap();
+    assert_eq!(client.concurrency_limit(), Some(3));
+}
+
+#[test]
+fn customer_delete_discount() {
+    let stub = StubClient::default();
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let id = "cus_123".parse().unwrap();
+    let discount = stripe::Customer::delete_discount(&client, &id).unwrap();
+    assert!(discount.deleted);
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].method(), "DELETE");
+    assert_eq!(requests[0].uri(), "http://localhost/v1/customers/cus_123/discount");
+}
+
+#[test]
+fn card_list_filters_customer_sources() {
+    let stub = StubClient::default().respond(
+        "/v1/customers/cus_123/sources",
+        serde_json::json!({
+            "object": "list",
+            "url": "/v1/customers/cus_123/sources",
+            "has_more": false,
+            "data": [{
+                "id": "card_123",
+                "object": "card",
+                "brand": "Visa",
+                "country": "US",
+                "customer": "cus_123",
+                "cvc_check": "pass",
+                "exp_month": 8,
+                "exp_year": 2030,
+                "fingerprint": "Xt5EWLLDS7FJjR1c",
+                "funding": "credit",
+                "last4": "4242",
+                "metadata": {}
+            }]
+        }),
+    );
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let id = "cus_123".parse().unwrap();
+    let mut params = stripe::ListCards::new();
+    params.limit = Some(3);
+    let cards = stripe::Card::list(&client, &id, params).unwrap();
+    assert!(!cards.has_more);
+    assert_eq!(cards.data.len(), 1);
+    assert_eq!(cards.data[0].id, "card_123");
+    assert_eq!(cards.data[0].last4.as_deref(), Some("4242"));
+    assert_eq!(cards.data[0].exp_year, Some(2030));
+
+    assert_eq!(
+        stub.requests.lock().unwrap()[0].uri(),
+        "http://localhost/v1/customers/cus_123/sources?object=card&limit=3"
+    );
+}
+
+#[test]
+fn customer_payment_method_requests() {
+    let stub = StubClient::default()
+        .respond(
+            "/v1/customers/cus_123/payment_methods",
+            serde_json::json!({
+                "object": "list",
+                "url": "/v1/customers/cus_123/payment_methods",
+                "has_more": false,
+                "data": [fixture("payment_method")]
+            }),
+        )
+        .respond("/v1/customers/cus_123/payment_methods/pm_123", fixture("payment_method"));
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let id = "cus_123".parse().unwrap();
+    let mut params = stripe::ListCustomerPaymentMethods::new();
+    params.type_ = Some(stripe::PaymentMethodTypeFilter::Card);
+    let methods = stripe::Customer::list_payment_methods(&client, &id, params).unwrap();
+    assert_eq!(methods.data.len(), 1);
+    assert_eq!(methods.data[0].id, "pm_1MqLiJLkdIwHu7ixUEgbFdYF");
+    let method =
+        stripe::Customer::retrieve_payment_method(&client, &id, &"pm_123".parse().unwrap(), &[])
+            .unwrap();
+    assert_eq!(method.type_, stripe::PaymentMethodType::Card);
+    assert_eq!(method.card.unwrap().last4, "4242");
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(
+        requests[0].uri(),
+        "http://localhost/v1/customers/cus_123/payment_methods?type=card"
+    );
+    assert_eq!(requests[1].uri(), "http://localhost/v1/customers/cus_123/payment_methods/pm_123");
+}
+
+#[test]
+fn customer_cash_balance_requests() {
+    let stub = StubClient::default()
+        .respond(
+            "/v1/customers/cus_123/cash_balance",
+            serde_json::json!({
+                "object": "cash_balance",
+                "available": {"eur": 10000},
+                "customer": "cus_123",
+                "livemode": false,
+                "settings": {"reconciliation_mode": "manual", "using_merchant_default": false}
+            }),
+        )
+        .respond(
+            "/v1/customers/cus_123/cash_balance_transactions",
+            serde_json::json!({
+                "object": "list",
+                "url": "/v1/customers/cus_123/cash_balance_transactions",
+                "has_more": false,
+                "data": [{
+                    "id": "ccsbtxn_123",
+                    "object": "customer_cash_balance_transaction",
+                    "created": 1680000000,
+                    "currency": "eur",
+                    "customer": "cus_123",
+                    "ending_balance": 10000,
+                    "funded": {"bank_transfer": {"reference": "REF-123", "type": "eu_bank_transfer"}},
+                    "livemode": false,
+                    "net_amount": 5000,
+                    "type": "funded"
+                }]
+            }),
+        );
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let id = "cus_123".parse().unwrap();
+    let mut params = stripe::UpdateCashBalance::new();
+    params.settings = Some(stripe::UpdateCashBalanceSettings {
+        reconciliation_mode: Some(stripe::UpdateCashBalanceSettingsReconciliationMode::Manual),
+    });
+    let balance = stripe::CashBalance::update(&client, &id, params).unwrap();
+    assert_eq!(balance.available.unwrap()[&stripe::Currency::EUR], 10000);
+    assert_eq!(
+        balance.settings.reconciliation_mode,
+        stripe::CashBalanceSettingsReconciliationMode::Manual
+    );
+    let mut params = stripe::ListCustomerCashBalanceTransactions::new();
+    params.limit = Some(3);
+    let transactions = stripe::CustomerCashBalanceTransaction::list(&client, &id, params).unwrap();
+    assert_eq!(transactions.data.len(), 1);
+    let transaction = &transactions.data[0];
+    assert_eq!(transaction.type_, stripe::CustomerCashBalanceTransactionType::Funded);
+    assert_eq!(transaction.net_amount, 5000);
+    assert_eq!(transaction.ending_balance, 10000);
+    let funded = transaction.funded.as_ref().unwrap();
+    assert_eq!(funded.bank_transfer.reference.as_deref(), Some("REF-123"));
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].method(), "POST");
+    assert_eq!(requests[0].uri(), "http://localhost/v1/customers/cus_123/cash_balance");
+    let body = String::from_utf8(requests[0].body().clone()).unwrap();
+    assert_eq!(
+        body.replace("%5B", "[").replace("%5D", "]"),
+        "settings[reconciliation_mode]=manual"
+    );
+    assert_eq!(
+        requests[1].uri(),
+        "http://localhost/v1/customers/cus_123/cash_balance_transactions?limit=3"
+    );
+}
+
+#[test]
+fn event_destination_requests() {
+    let destination = serde_json::json!({
+        "id": "ed_123",
+        "object": "v2.core.event_destination",
+        "created": "2024-10-22T16:20:09.931Z",
+        "description": "",
+        "enabled_events": ["v1.billing.meter.error_report_triggered"],
+        "event_payload": "thin",
+        "events_from": ["self"],
+        "livemode": false,
+        "metadata": {},
+        "name": "meters",
+        "snapshot_api_version": null,
+        "status": "enabled",
+        "status_details": null,
+        "type": "webhook_endpoint",
+        "updated": "2024-10-22T16:20:09.931Z",
+        "webhook_endpoint": {"signing_secret": null, "url": "https://example.com/hook"}
+    });
+    let stub = StubClient::default()
+        .respond("/v2/core/event_destinations", destination.clone())
+        .respond("/v2/core/event_destinations/ed_123", destination);
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let mut params = stripe::CreateEventDestination::new(
+        vec!["v1.billing.meter.error_report_triggered"],
+        stripe::EventDestinationEventPayload::Thin,
+        "meters",
+        stripe::EventDestinationType::WebhookEndpoint,
+    );
+    params.webhook_endpoint =
+        Some(stripe::CreateEventDestinationWebhookEndpoint { url: "https://example.com/hook" });
+    let destination = stripe::EventDestination::create(&client, params).unwrap();
+    assert_eq!(destination.id, "ed_123");
+    assert_eq!(destination.status, stripe::EventDestinationStatus::Enabled);
+    assert_eq!(destination.event_payload, stripe::EventDestinationEventPayload::Thin);
+    let id = "ed_123".parse().unwrap();
+    let include = [stripe::EventDestinationInclude::WebhookEndpointUrl];
+    let destination = stripe::EventDestination::retrieve(&client, &id, &include).unwrap();
+    let webhook_endpoint = destination.webhook_endpoint.unwrap();
+    assert_eq!(webhook_endpoint.url.as_deref(), Some("https://example.com/hook"));
+    assert_eq!(webhook_endpoint.signing_secret, None);
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].method(), "POST");
+    assert_eq!(requests[0].uri(), "http://localhost/v2/core/event_destinations");
+    assert_eq!(requests[0].headers()["content-type"], "application/json");
+    assert_eq!(requests[0].headers()["stripe-version"], "2024-09-30.acacia");
+    let body: serde_json::Value = serde_json::from_slice(requests[0].body()).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "enabled_events": ["v1.billing.meter.error_report_triggered"],
+            "event_payload": "thin",
+            "name": "meters",
+            "type": "webhook_endpoint",
+            "webhook_endpoint": {"url": "https://example.com/hook"},
+        })
+    );
+    assert_eq!(
+        requests[1].uri(),
+        "http://localhost/v2/core/event_destinations/ed_123?include=webhook_endpoint.url"
+    );
+}
+
+#[test]
+fn thin_event_pull_related_object() {
+    let stub = StubClient::default();
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let event: stripe::ThinEvent = serde_json::from_value(serde_json::json!({
+        "id": "evt_123",
+        "object": "v2.core.event",
+        "type": "v1.billing.meter.no_meter_found",
+        "created": "2024-09-30T12:00:00.000Z",
+        "related_object": {
+            "id": "cus_123",
+            "type": "customer",
+            "url": "/v1/customers/cus_123",
+        },
+    }))
+    .unwrap();
+    let customer: stripe::Customer = event.pull_related_object(&client).unwrap();
+    assert_eq!(customer.id.as_str(), "cus_123");
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].uri(), "http://localhost/v1/customers/cus_123");
+    assert!(requests[0].headers().get("stripe-account").is_none());
+}
+
+#[test]
+fn thin_event_requests_use_context() {
+    let stub = StubClient::default().respond(
+        "/v2/core/events/evt_123",
+        serde_json::json!({
+            "id": "evt_123",
+            "object": "v2.core.event",
+            "type": "v1.billing.meter.no_meter_found",
+            "context": "acct_123",
+            "created": "2024-09-30T12:00:00.000Z",
+            "data": {
+                "developer_message_summary": "There is no meter for this event name",
+                "reason": {"error_count": 1}
+            },
+            "livemode": false,
+            "related_object": null
+        }),
+    );
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let event: stripe::ThinEvent = serde_json::from_value(serde_json::json!({
+        "id": "evt_123",
+        "object": "v2.core.event",
+        "type": "v1.billing.meter.no_meter_found",
+        "context": "acct_123",
+        "created": "2024-09-30T12:00:00.000Z",
+        "related_object": {
+            "id": "cus_123",
+            "type": "customer",
+            "url": "/v1/customers/cus_123",
+        },
+    }))
+    .unwrap();
+    let _: stripe::Customer = event.pull_related_object(&client).unwrap();
+    let fetched = event.fetch(&client).unwrap();
+    assert_eq!(fetched.id, event.id);
+    assert_eq!(fetched.context.as_deref(), Some("acct_123"));
+    assert_eq!(fetched.data.unwrap()["reason"]["error_count"], 1);
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].uri(), "http://localhost/v1/customers/cus_123");
+    assert_eq!(requests[0].headers()["stripe-account"], "acct_123");
+    assert_eq!(requests[1].uri(), "http://localhost/v2/core/events/evt_123");
+    assert_eq!(requests[1].headers()["stripe-account"], "acct_123");
+}
+
+#[test]
+fn balance_requests_on_behalf_of_connected_account() {
+    let stub = StubClient::default()
+        .respond(
+            "/v1/balance",
+            serde_json::json!({
+                "object": "balance",
+                "available": [
+                    {"amount": 666670, "currency": "usd", "source_types": {"card": 666670}}
+                ],
+                "connect_reserved": [{"amount": 0, "currency": "usd"}],
+                "livemode": false,
+                "pending": [
+                    {"amount": 61414, "currency": "usd", "source_types": {"card": 61414}}
+                ]
+            }),
+        )
+        .respond(
+            "/v1/balance_transactions",
+            serde_json::json!({
+                "object": "list",
+                "url": "/v1/balance_transactions",
+                "has_more": false,
+                "data": [fixture("balance_transaction")]
+            }),
+        )
+        .respond(
+            "/v1/payouts",
+            serde_json::json!({
+                "object": "list",
+                "url": "/v1/payouts",
+                "has_more": false,
+                "data": [{
+                    "id": "po_123",
+                    "object": "payout",
+                    "amount": 1100,
+                    "arrival_date": 1680652800,
+                    "automatic": false,
+                    "balance_transaction": "txn_123",
+                    "created": 1680648691,
+                    "currency": "usd",
+                    "description": null,
+                    "destination": "ba_123",
+                    "failure_balance_transaction": null,
+                    "failure_code": null,
+                    "failure_message": null,
+                    "livemode": false,
+                    "metadata": {},
+                    "method": "standard",
+                    "source_type": "card",
+                    "statement_descriptor": null,
+                    "status": "pending",
+                    "type": "bank_account"
+                }]
+            }),
+        );
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+    let mut options = stripe::RequestOptions::new();
+    options.stripe_account = Some("acct_123".into());
+    let connected = client.with_options(options);
+
+    let balance = stripe::Balance::retrieve(&connected, &[]).unwrap();
+    assert_eq!(balance.available.len(), 1);
+    assert_eq!(balance.available[0].amount, 666670);
+    assert_eq!(balance.available[0].currency, stripe::Currency::USD);
+    assert_eq!(balance.available[0].source_types.as_ref().unwrap().card, Some(666670));
+    assert_eq!(balance.pending.len(), 1);
+    assert_eq!(balance.pending[0].amount, 61414);
+    assert_eq!(balance.pending[0].currency, stripe::Currency::USD);
+    let mut params = stripe::ListBalanceTransactions::new();
+    params.payout = Some("po_123".parse().unwrap());
+    let transactions = stripe::BalanceTransaction::list(&connected, params).unwrap();
+    assert_eq!(transactions.data.len(), 1);
+    assert_eq!(transactions.data[0].amount, -400);
+    assert_eq!(transactions.data[0].net, -400);
+    let payouts = stripe::Payout::list(&connected, stripe::ListPayouts::new()).unwrap();
+    assert_eq!(payouts.data.len(), 1);
+    assert_eq!(payouts.data[0].id, "po_123");
+    assert_eq!(payouts.data[0].amount, 1100);
+    assert_eq!(payouts.data[0].status, "pending");
+    stripe::Balance::retrieve(&client, &[]).unwrap();
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].method(), "GET");
+    assert_eq!(requests[0].uri(), "http://localhost/v1/balance");
+    assert_eq!(requests[1].uri(), "http://localhost/v1/balance_transactions?payout=po_123");
+    assert_eq!(requests[2].uri(), "http://localhost/v1/payouts");
+    for request in &requests[..3] {
+        assert_eq!(request.headers()["stripe-account"], "acct_123");
+    }
+    assert!(requests[3].headers().get("stripe-account").is_none());
+}
+
+#[test]
+fn list_by_transfer_group() {
+    let mut charge = fixture("charge");
+    charge["transfer_group"] = "ORDER_42".into();
+    let stub = StubClient::default()
+        .respond(
+            "/v1/charges",
+            serde_json::json!({
+                "object": "list",
+                "url": "/v1/charges",
+                "has_more": false,
+                "data": [charge]
+            }),
+        )
+        .respond(
+            "/v1/transfers",
+            serde_json::json!({
+                "object": "list",
+                "url": "/v1/transfers",
+                "has_more": false,
+                "data": [{
+                    "id": "tr_123",
+                    "object": "transfer",
+                    "amount": 400,
+                    "amount_reversed": 0,
+                    "balance_transaction": "txn_123",
+                    "created": 1680652800,
+                    "currency": "usd",
+                    "description": null,
+                    "destination": "acct_123",
+                    "livemode": false,
+                    "metadata": {},
+                    "reversals": {
+                        "object": "list",
+                        "data": [],
+                        "has_more": false,
+                        "url": "/v1/transfers/tr_123/reversals"
+                    },
+                    "reversed": false,
+                    "source_transaction": null,
+                    "source_type": "card",
+                    "transfer_group": "ORDER_42"
+                }]
+            }),
+        );
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let mut params = stripe::ListCharges::new();
+    params.limit = Some(100);
+    let charges = stripe::Charge::list_by_transfer_group(&client, "ORDER_42", params).unwrap();
+    assert_eq!(charges.data.len(), 1);
+    assert_eq!(charges.data[0].amount, 1099);
+    assert_eq!(charges.data[0].transfer_group.as_deref(), Some("ORDER_42"));
+    let transfers =
+        stripe::Transfer::list_by_transfer_group(&client, "ORDER_42", stripe::ListTransfers::new())
+            .unwrap();
+    assert_eq!(transfers.data.len(), 1);
+    assert_eq!(transfers.data[0].id, "tr_123");
+    assert_eq!(transfers.data[0].amount, 400);
+    assert_eq!(transfers.data[0].transfer_group.as_deref(), Some("ORDER_42"));
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].uri(), "http://localhost/v1/charges?limit=100&transfer_group=ORDER_42");
+    assert_eq!(requests[1].uri(), "http://localhost/v1/transfers?transfer_group=ORDER_42");
+}
+
+/// A transport which redirects the first request to `location`.
+#[derive(Clone)]
+struct RedirectClient {
+    location: &'static str,
+    requests: Arc<Mutex<Vec<http::Request<Vec<u8>>>>>,
+}
+
+impl RedirectClient {
+    fn to(location: &'static str) -> Self {
+        RedirectClient { location, requests: Default::default() }
+    }
+}
+
+impl stripe::HttpClient for RedirectClient {
+    fn execute(&self, request: http::Request<Vec<u8>>) -> stripe::HttpResponse {
+        let mut requests = self.requests.lock().unwrap();
+        requests.push(request);
+        let response = if requests.len() == 1 {
+            http::Response::builder().status(302).header("location", self.location).body(Vec::new())
+        } else {
+            http::Response::builder().body(b"%PDF-1.4".to_vec())
+        };
+        Box::pin(futures_util::future::ready(Ok(response.unwrap())))
+    }
+}
+
+#[test]
+fn get_bytes_follows_redirects() {
+    let stub = RedirectClient::to("http://files.localhost/invoice.pdf");
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let pdf = client.get_bytes("http://localhost/v1/invoices/in_123/pdf").unwrap();
+    assert_eq!(pdf, b"%PDF-1.4");
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].headers()["authorization"], "Bearer sk_test_123");
+    assert_eq!(requests[1].uri(), "http://files.localhost/invoice.pdf");
+    assert!(requests[1].headers().get("authorization").is_none());
+}
+
+#[test]
+fn redirects_to_another_scheme_strip_credentials() {
+    let stub = RedirectClient::to("https://localhost/invoice.pdf");
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    client.get_bytes("http://localhost/v1/invoices/in_123/pdf").unwrap();
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[1].uri(), "https://localhost/invoice.pdf");
+    assert!(requests[1].headers().get("authorization").is_none());
+}
+
+#[test]
+fn redirects_from_https_to_http_are_not_followed() {
+    let stub = RedirectClient::to("http://localhost/invoice.pdf");
+    let client = stripe::Client::from_http_client("https://localhost", "sk_test_123", stub.clone());
+
+    assert!(client.get_bytes("https://localhost/v1/invoices/in_123/pdf").is_err());
+    assert_eq!(stub.requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn invoice_list_lines() {
+    let stub = StubClient::default().respond(
+        "/v1/invoices/in_123/lines",
+        serde_json::json!({
+            "object": "list",
+            "url": "/v1/invoices/in_123/lines",
+            "has_more": true,
+            "data": [{
+                "id": "il_123",
+                "object": "line_item",
+                "amount": 1099,
+                "currency": "usd",
+                "description": "T-shirt",
+                "discountable": true,
+                "invoice_item": "ii_123",
+                "livemode": false,
+                "metadata": {},
+                "period": {"end": 1680644467, "start": 1680644467},
+                "price": fixture("price"),
+                "proration": false,
+                "quantity": 1,
+                "subscription": null,
+                "tax_amounts": [],
+                "tax_rates": [],
+                "type": "invoiceitem"
+            }]
+        }),
+    );
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let id = "in_123".parse().unwrap();
+    let params = stripe::ListInvoiceLines { limit: Some(100), ..Default::default() };
+    let lines = stripe::Invoice::list_lines(&client, &id, params).unwrap();
+    assert!(lines.has_more);
+    assert_eq!(lines.data.len(), 1);
+    let line = &lines.data[0];
+    assert_eq!(line.id, "il_123");
+    assert_eq!(line.amount, 1099);
+    assert_eq!(line.type_, stripe::InvoiceLineItemType::InvoiceItem);
+    assert_eq!(line.period.as_ref().unwrap().end, stripe::Timestamp::from(1680644467));
+    assert!(line.price.is_some());
+
+    assert_eq!(
+        stub.requests.lock().unwrap()[0].uri(),
+        "http://localhost/v1/invoices/in_123/lines?limit=100"
+    );
+}
+
+#[test]
+fn subscription_cancel_with_details() {
+    let mut subscription = fixture("subscription");
+    subscription["status"] = "canceled".into();
+    subscription["canceled_at"] = 1680000000.into();
+    subscription["ended_at"] = 1680000000.into();
+    subscription["cancellation_details"] = serde_json::json!({
+        "comment": "moving",
+        "feedback": "too_expensive",
+        "reason": "cancellation_requested"
+    });
+    let stub = StubClient::default().respond("/v1/subscriptions/sub_123", subscription);
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let id = "sub_123".parse().unwrap();
+    let params = stripe::CancelSubscription {
+        cancellation_details: Some(stripe::CancelSubscriptionCancellationDetails {
+            comment: Some("moving".into()),
+            feedback: Some(stripe::CancellationDetailsFeedback::TooExpensive),
+        }),
+        invoice_now: Some(true),
+        prorate: Some(true),
+        ..Default::default()
+    };
+    let subscription = stripe::Subscription::cancel(&client, &id, params).unwrap();
+    assert_eq!(subscription.status, stripe::SubscriptionStatus::Canceled);
+    assert_eq!(subscription.canceled_at, Some(stripe::Timestamp::from(1680000000)));
+    let details = subscription.cancellation_details.unwrap();
+    assert_eq!(details.comment.as_deref(), Some("moving"));
+    assert_eq!(details.feedback, Some(stripe::CancellationDetailsFeedback::TooExpensive));
+    assert_eq!(details.reason, Some(stripe::CancellationDetailsReason::CancellationRequested));
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].method(), "DELETE");
+    assert_eq!(
+        requests[0].uri(),
+        "http://localhost/v1/subscriptions/sub_123?cancellation_details[comment]=moving\
+         &cancellation_details[feedback]=too_expensive&invoice_now=true&prorate=true"
+    );
+}
+
+#[test]
+fn entitlements_requests() {
+    let stub = StubClient::default()
+        .respond(
+            "/v1/products/prod_123/features",
+            serde_json::json!({
+                "id": "prodft_123",
+                "object": "product_feature",
+                "entitlement_feature": {
+                    "id": "feat_123",
+                    "object": "entitlements.feature",
+                    "active": true,
+                    "livemode": false,
+                    "lookup_key": "black-and-white-dogs",
+                    "metadata": {},
+                    "name": "Black and white dogs"
+                },
+                "livemode": false
+            }),
+        )
+        .respond(
+            "/v1/entitlements/active_entitlements",
+            serde_json::json!({
+                "object": "list",
+                "url": "/v1/entitlements/active_entitlements",
+                "has_more": false,
+                "data": [{
+                    "id": "ent_123",
+                    "object": "entitlements.active_entitlement",
+                    "feature": "feat_123",
+                    "livemode": false,
+                    "lookup_key": "black-and-white-dogs"
+                }]
+            }),
+        );
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let product = "prod_123".parse().unwrap();
+    let feature = "feat_123".parse().unwrap();
+    let attached = stripe::ProductFeature::attach(
+        &client,
+        &product,
+        stripe::AttachProductFeature::new(feature),
+    )
+    .unwrap();
+    assert_eq!(attached.id, "prodft_123");
+    assert_eq!(attached.entitlement_feature.id, "feat_123");
+    assert_eq!(attached.entitlement_feature.lookup_key, "black-and-white-dogs");
+    let params = stripe::ListEntitlementsActiveEntitlements::new("cus_123".parse().unwrap());
+    let entitlements = stripe::EntitlementsActiveEntitlement::list(&client, params).unwrap();
+    assert_eq!(entitlements.data.len(), 1);
+    assert_eq!(entitlements.data[0].feature.id(), "feat_123");
+    assert_eq!(entitlements.data[0].lookup_key, "black-and-white-dogs");
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests[0].method(), "POST");
+    assert_eq!(requests[0].uri(), "http://localhost/v1/products/prod_123/features");
+    assert_eq!(requests[0].body(), b"entitlement_feature=feat_123");
+    assert_eq!(
+        requests[1].uri(),
+        "http://localhost/v1/entitlements/active_entitlements?customer=cus_123"
+    );
+}
+
+#[test]
+fn capability_update_requests_capability() {
+    let requirements = serde_json::json!({
+        "current_deadline": null,
+        "currently_due": ["business_profile.url"],
+        "disabled_reason": "requirements.past_due",
+        "errors": [],
+        "eventually_due": ["business_profile.url"],
+        "past_due": ["business_profile.url"],
+        "pending_verification": []
+    });
+    let stub = StubClient::default().respond(
+        "/v1/accounts/acct_123/capabilities/card_payments",
+        serde_json::json!({
+            "id": "card_payments",
+            "object": "capability",
+            "account": "acct_123",
+            "future_requirements": requirements,
+            "requested": true,
+            "requested_at": 1692637230,
+            "requirements": requirements,
+            "status": "inactive"
+        }),
+    );
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let account = "acct_123".parse().unwrap();
+    let capability = "card_payments".parse().unwrap();
+    let params = stripe::UpdateCapability { requested: Some(true), ..Default::default() };
+    let capability = stripe::Capability::update(&client, &account, &capability, params).unwrap();
+    assert_eq!(capability.id, "card_payments");
+    assert!(capability.requested);
+    assert_eq!(capability.status, stripe::CapabilityStatus::Inactive);
+    let requirements = capability.requirements.unwrap();
+    assert_eq!(requirements.past_due.unwrap(), ["business_profile.url"]);
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(
+        requests[0].uri(),
+        "http://localhost/v1/accounts/acct_123/capabilities/card_payments"
+    );
+    assert_eq!(requests[0].body(), b"requested=true");
+}
+
+#[test]
+fn dispute_submit_evidence_with_files() {
+    let stub = StubClient::default()
+        .respond(
+            "/v1/files",
+            serde_json::json!({
+                "id": "file_123",
+                "object": "file",
+                "created": 1680000000,
+                "expires_at": null,
+                "filename": "receipt.pdf",
+                "links": {"object": "list", "data": [], "has_more": false, "url": "/v1/file_links"},
+                "purpose": "dispute_evidence",
+                "size": 8,
+                "title": null,
+                "type": "pdf",
+                "url": "https://files.stripe.com/v1/files/file_123/contents"
+            }),
+        )
+        .respond(
+            "/v1/disputes/dp_123",
+            serde_json::json!({
+                "id": "dp_123",
+                "object": "dispute",
+                "amount": 1000,
+                "balance_transactions": [],
+                "charge": "ch_123",
+                "created": 1680000000,
+                "currency": "usd",
+                "evidence": {"customer_name": "Jenny Rosen", "receipt": "file_123"},
+                "evidence_details": {
+                    "due_by": 1680863999,
+                    "has_evidence": true,
+                    "past_due": false,
+                    "submission_count": 1
+                },
+                "is_charge_refundable": false,
+                "livemode": false,
+                "metadata": {},
+                "payment_intent": null,
+                "reason": "general",
+                "status": "under_review"
+            }),
+        );
+    let mut client =
+        stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+    client.set_files_url("http://files.localhost/");
+
+    let id = "dp_123".parse().unwrap();
+    let evidence = stripe::UpdateDisputeEvidence {
+        customer_name: Some("Jenny Rosen".into()),
+        ..Default::default()
+    };
+    let files = [stripe::DisputeEvidenceFile::new(
+        stripe::DisputeEvidenceFileKind::Receipt,
+        "receipt.pdf",
+        b"%PDF-1.4",
+    )];
+    let dispute =
+        stripe::Dispute::submit_evidence_with_files(&client, &id, evidence, &files).unwrap();
+    assert_eq!(dispute.status, stripe::DisputeStatus::UnderReview);
+    assert_eq!(dispute.evidence.customer_name.as_deref(), Some("Jenny Rosen"));
+    assert_eq!(dispute.evidence.receipt.unwrap().id(), "file_123");
+    assert!(dispute.evidence_details.has_evidence);
+    assert_eq!(dispute.evidence_details.submission_count, 1);
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].uri(), "http://files.localhost/v1/files");
+    let content_type = requests[0].headers()["content-type"].to_str().unwrap();
+    let boundary = content_type.strip_prefix("multipart/form-data; boundary=").unwrap();
+    let expected = format!(
+        "--{b}\r\nContent-Disposition: form-data; name=\"purpose\"\r\n\r\ndispute_evidence\r\n\
+         --{b}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"receipt.pdf\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n%PDF-1.4\r\n--{b}--\r\n",
+        b = boundary
+    );
+    assert_eq!(String::from_utf8_lossy(requests[0].body()), expected);
+    assert_eq!(requests[1].uri(), "http://localhost/v1/disputes/dp_123");
+    // N.B. Older versions of `serde_qs` percent-encode the brackets.
+    let body = String::from_utf8_lossy(requests[1].body()).replace("%5B", "[").replace("%5D", "]");
+    assert_eq!(body, "evidence[customer_name]=Jenny+Rosen&evidence[receipt]=file_123&submit=true");
+}
+
+#[test]
+fn checkout_session_retrieve_expanded() {
+    let mut session = fixture("checkout_session");
+    session["line_items"] = serde_json::json!({
+        "object": "list",
+        "url": "/v1/checkout/sessions/cs_test_123/line_items",
+        "has_more": false,
+        "data": [{
+            "id": "li_123",
+            "object": "item",
+            "amount_subtotal": 2000,
+            "amount_total": 2000,
+            "currency": "usd",
+            "description": "T-shirt",
+            "price": fixture("price"),
+            "quantity": 2
+        }]
+    });
+    session["payment_intent"] = fixture("payment_intent");
+    let stub = StubClient::default().respond("/v1/checkout/sessions/cs_test_123", session);
+    let client = stripe::Client::from_http_client("http://localhost", "sk_test_123", stub.clone());
+
+    let id = "cs_test_123".parse().unwrap();
+    let session =
+        stripe::CheckoutSession::retrieve(&client, &id, &["line_items", "payment_intent"]).unwrap();
+    assert_eq!(session.line_items.data.len(), 1);
+    assert_eq!(session.line_items.data[0].id, "li_123");
+    assert_eq!(session.line_items.data[0].quantity, Some(2));
+    let payment_intent = session.payment_intent.unwrap().into_object().unwrap();
+    assert_eq!(payment_intent.id, "pi_3MtwBwLkdIwHu7ix28a3tqPa");
+    assert_eq!(payment_intent.amount, 2000);
+
+    let requests = stub.requests.lock().unwrap();
+    assert_eq!(
+        requests[0].uri(),
+        "http://localhost/v1/checkout/sessions/cs_test_123?expand[0]=line_items&expand[1]=payment_intent"
+    );
+}
+
+#[cfg(feature = "reqwest")]
+#[test]
+fn customer_delete_with_reqwest_transport() {
+    use std::io::{Read, Write};
+
+    // A server which answers a single request with a deleted customer.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let read = stream.read(&mut buffer).unwrap();
+            request.extend_from_slice(&buffer[..read]);
+        }
+        let body = r#"{"id": "cus_123", "object": "customer", "deleted": true}"#;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nrequest-id: req_123\r\ncontent-length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+        String::from_utf8(request).unwrap()
+    });
+
+    let client = stripe::Client::from_http_client(
+        format!("http://{}", address),
+        "sk_test_123",
+        stripe::ReqwestClient::new(),
+    );
+    let id = "cus_123".parse().unwrap();
+    let deleted = client.with_envelope(|client| stripe::Customer::delete(client, &id)).unwrap();
+    assert!(deleted.data.deleted);
+    assert_eq!(deleted.request_id(), Some("req_123"));
+
+    let request = server.join().unwrap().to_lowercase();
+    assert!(request.starts_with("delete /v1/customers/cus_123 http/1.1\r\n"));
+    assert!(request.contains("authorization: bearer sk_test_123\r\n"));
+}